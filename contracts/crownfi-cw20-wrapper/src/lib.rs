@@ -131,9 +131,14 @@ pub fn query(_deps: Deps, env: Env, msg: CW20WrapperQueryMsg) -> Result<Binary,
 	match msg {
 		CW20WrapperQueryMsg::UnwrappedAddrOf { denom } => {
 			let known_tokens = StoredMap::<String, SeiCanonicalAddr>::new(KNOWN_TOKENS_NAMESPACE);
-			let subdenom = denom[denom.len() - 44..].to_string();
-			let cw20_canon_addr = known_tokens.get(&subdenom)?.ok_or(Cw20WrapperError::TokenDoesntBelongToContract)?;
-			let cw20_addr: cosmwasm_std::Addr = cw20_canon_addr.as_ref().try_into()?;
+			let cw20_canon_addr = match denom.strip_prefix(&format!("factory/{}/", env.contract.address)) {
+				Some(subdenom) => known_tokens.get(&subdenom.to_string())?,
+				None => None,
+			};
+			let cw20_addr: Option<cosmwasm_std::Addr> = match cw20_canon_addr {
+				Some(cw20_canon_addr) => Some(cw20_canon_addr.as_ref().try_into()?),
+				None => None,
+			};
 			Ok(to_json_binary(&cw20_addr)?)
 		},
 		CW20WrapperQueryMsg::WrappedDenomOf { cw20 } => {
@@ -172,4 +177,35 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn unwrapped_addr_of_unknown_denom_is_none() -> Result<(), Cw20WrapperError> {
+		crownfi_cw_common::storage::base::set_global_storage(Box::new(MockStorage::new()));
+		let deps = mock_dependencies();
+		let env = mock_env();
+		let cw20_addr = Addr::unchecked(RANDOM_ADDRESS);
+		let cw20_canon_addr = SeiCanonicalAddr::try_from(&cw20_addr)?;
+		let subdenom = token_contract_addr_to_subdenom(&cw20_canon_addr);
+		StoredMap::new(KNOWN_TOKENS_NAMESPACE).set(&subdenom, &cw20_canon_addr)?;
+
+		let unwrapped_addr_of = |denom: String| -> Result<Option<Addr>, Cw20WrapperError> {
+			Ok(cosmwasm_std::from_json(query(
+				deps.as_ref(),
+				env.clone(),
+				CW20WrapperQueryMsg::UnwrappedAddrOf { denom },
+			)?)?)
+		};
+		assert_eq!(
+			unwrapped_addr_of(format!("factory/{}/{subdenom}", env.contract.address))?,
+			Some(cw20_addr)
+		);
+		assert_eq!(
+			unwrapped_addr_of(format!("factory/{}/{}", env.contract.address, "a".repeat(44)))?,
+			None
+		);
+		assert_eq!(unwrapped_addr_of(format!("factory/{RANDOM_ADDRESS}/{subdenom}"))?, None);
+		assert_eq!(unwrapped_addr_of("usei".into())?, None);
+
+		Ok(())
+	}
 }
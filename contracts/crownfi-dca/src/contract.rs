@@ -0,0 +1,462 @@
+use cosmwasm_std::{
+	attr, coin, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, Fraction,
+	MessageInfo, QuerierWrapper, Reply, ReplyOn, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_pool_pair_contract::contract::pool::MAX_ALLOWED_TOLERANCE;
+use crownfi_swap_router_contract::msg::{
+	SwapReceiver, SwapRouterExecuteMsg, SwapRouterQueryMsg, SwapRouterSimulateSwapsResponse,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use cw2::set_contract_version;
+use cw_utils::{nonpayable, one_coin, ParseReplyError};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::{
+	error::DcaContractError,
+	msg::{DcaExecuteMsg, DcaInstantiateMsg, DcaPlanInfo, DcaQueryMsg},
+	state::{
+		get_owner_plans_store, get_plans_store, DcaConfig, DcaConfigJsonable, DcaExecution, DcaPlan, DcaPlanKey,
+		DcaState,
+	},
+};
+
+const CONTRACT_NAME: &str = "crownfi-dca";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// `reply` call code IDs used in a sub-message.
+pub(crate) const PLAN_EXECUTED_REPLY_ID: u64 = 0x6463615f65786563;
+
+/// Keeps `PlansByOwner` and the owner index cheap to load
+pub const MAX_PLANS_PER_OWNER: usize = 64;
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn instantiate(
+	deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+	msg: DcaInstantiateMsg,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	nonpayable(&msg_info)?;
+	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	DcaConfig::try_from(&msg.config)?.save()?;
+	DcaState { next_plan_id: 1 }.save()?;
+	Ok(Response::new())
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn execute(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	msg: DcaExecuteMsg,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	match msg {
+		DcaExecuteMsg::UpdateConfig { admin, router } => process_update_config(deps, msg_info, admin, router),
+		DcaExecuteMsg::CreatePlan {
+			route,
+			amount_per_execution,
+			interval_seconds,
+			slippage_tolerance,
+			min_price,
+		} => process_create_plan(
+			deps,
+			env,
+			msg_info,
+			route,
+			amount_per_execution,
+			interval_seconds,
+			slippage_tolerance,
+			min_price,
+		),
+		DcaExecuteMsg::ExecutePlan { plan_id } => process_execute_plan(deps, env, msg_info, plan_id),
+		DcaExecuteMsg::Withdraw { plan_id } => process_withdraw(deps, msg_info, plan_id),
+		DcaExecuteMsg::ClosePlan { plan_id } => process_close_plan(deps, msg_info, plan_id),
+	}
+}
+
+fn process_update_config(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	admin: Option<Addr>,
+	router: Option<Addr>,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	nonpayable(&msg_info)?;
+	let mut config = DcaConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if let Some(admin) = admin {
+		config.admin = admin.try_into()?;
+	}
+	if let Some(router) = router {
+		config.router = router.try_into()?;
+	}
+	config.save()?;
+	Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+fn simulate_route(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	offer: Coin,
+	route: &[String],
+) -> Result<SwapRouterSimulateSwapsResponse, DcaContractError> {
+	let router = Addr::try_from(DcaConfig::load_non_empty()?.router)?;
+	Ok(querier.query_wasm_smart(
+		router,
+		&SwapRouterQueryMsg::SimulateSwaps {
+			offer,
+			swappers: route.iter().map(Addr::unchecked).collect(),
+			referral_fee_bps: None,
+		},
+	)?)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_create_plan(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	route: Vec<Addr>,
+	amount_per_execution: Uint128,
+	interval_seconds: u64,
+	slippage_tolerance: Decimal,
+	min_price: Decimal,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	let deposit = one_coin(&msg_info)?;
+	if route.is_empty() {
+		return Err(DcaContractError::RouteEmpty);
+	}
+	if amount_per_execution.is_zero() || interval_seconds == 0 {
+		return Err(DcaContractError::InvalidSchedule);
+	}
+	// Also passed to each swapper as the intermediate slippage tolerance, so it can't be more than what they allow.
+	if slippage_tolerance > MAX_ALLOWED_TOLERANCE {
+		return Err(DcaContractError::SlippageToleranceTooHigh(MAX_ALLOWED_TOLERANCE));
+	}
+	// Plans without a price floor can be executed by anyone at whatever price they can manipulate the route to.
+	if min_price.is_zero() {
+		return Err(DcaContractError::MinPriceZero);
+	}
+	let route = route
+		.into_iter()
+		.map(|swapper| Ok(deps.api.addr_validate(swapper.as_str())?.into_string()))
+		.collect::<Result<Vec<_>, DcaContractError>>()?;
+	// Validates the route, and tells us what the output will be.
+	let output_denom = simulate_route(
+		&deps.querier,
+		coin(amount_per_execution.min(deposit.amount).u128(), &deposit.denom),
+		&route,
+	)?
+	.result_denom;
+	// The output received is determined by this contract's balance, which is also affected by the deposit if the
+	// denoms are the same.
+	if output_denom == deposit.denom {
+		return Err(DcaContractError::RouteIsCircular);
+	}
+
+	let owner_plans = get_owner_plans_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut owner_plan_ids = owner_plans
+		.get(&owner_addr)?
+		.map(|plan_ids| plan_ids.into_inner())
+		.unwrap_or_default();
+	if owner_plan_ids.plan_ids.len() >= MAX_PLANS_PER_OWNER {
+		return Err(DcaContractError::TooManyPlans);
+	}
+
+	let mut state = DcaState::load_non_empty()?;
+	let plan_id = state.next_plan_id;
+	state.next_plan_id += 1;
+	state.save()?;
+
+	get_plans_store().set(
+		&plan_id.into(),
+		&DcaPlan {
+			owner: msg_info.sender.to_string(),
+			route,
+			input_denom: deposit.denom.clone(),
+			remaining_amount: deposit.amount.u128(),
+			amount_per_execution: amount_per_execution.u128(),
+			output_denom,
+			output_amount: 0,
+			interval_seconds,
+			slippage_tolerance: slippage_tolerance.numerator().u128(),
+			min_price: min_price.numerator().u128(),
+			last_execution: 0,
+			executions: 0,
+			created_at: env.block.time.seconds(),
+		},
+	)?;
+	owner_plan_ids.plan_ids.push(plan_id);
+	owner_plans.set(&owner_addr, &owner_plan_ids)?;
+
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "create_plan"),
+		attr("plan_id", plan_id.to_string()),
+		attr("owner", msg_info.sender),
+		attr("deposit", deposit.to_string()),
+		attr("amount_per_execution", amount_per_execution),
+		attr("interval_seconds", interval_seconds.to_string()),
+		attr("min_price", min_price.to_string()),
+	]))
+}
+
+fn load_plan(plan_id: u64) -> Result<DcaPlan, DcaContractError> {
+	Ok(get_plans_store()
+		.get(&plan_id.into())?
+		.ok_or(DcaContractError::PlanNotFound(plan_id))?
+		.into_inner())
+}
+
+fn process_execute_plan(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	plan_id: u64,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	nonpayable(&msg_info)?;
+	if DcaExecution::load()?.is_some() {
+		return Err(DcaContractError::AlreadyExecutingPlan);
+	}
+	let mut plan = load_plan(plan_id)?;
+	let now = env.block.time.seconds();
+	match plan.next_execution() {
+		None => return Err(DcaContractError::PlanFinished),
+		Some(next_execution) if now < next_execution => return Err(DcaContractError::TooEarly(next_execution)),
+		_ => {}
+	}
+
+	let offer = coin(plan.remaining_amount.min(plan.amount_per_execution), &plan.input_denom);
+	let simulation = simulate_route(&deps.querier, offer.clone(), &plan.route)?;
+	if simulation.result_amount.is_zero() {
+		return Err(DcaContractError::ExpectingNothing);
+	}
+	// The simulation happens in the same transaction as the swap, so it can't protect against the price having been
+	// moved beforehand. The owner's minimum price is what's actually enforced, once the output has been received.
+	let min_result = offer.amount.checked_mul_floor(Decimal::raw(plan.min_price))?;
+	if simulation.result_amount < min_result {
+		return Err(DcaContractError::MinPriceNotReached);
+	}
+
+	plan.remaining_amount -= offer.amount.u128();
+	plan.last_execution = now;
+	plan.executions += 1;
+	get_plans_store().set(&plan_id.into(), &plan)?;
+	DcaExecution::new(
+		plan_id,
+		deps.querier
+			.query_balance(&env.contract.address, &plan.output_denom)?
+			.amount
+			.u128(),
+		min_result.u128(),
+	)
+	.save()?;
+
+	let config = DcaConfig::load_non_empty()?;
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "execute_plan"),
+			attr("plan_id", plan_id.to_string()),
+			attr("keeper", msg_info.sender),
+			attr("offer", offer.to_string()),
+		])
+		.add_submessage(SubMsg {
+			id: PLAN_EXECUTED_REPLY_ID,
+			msg: CosmosMsg::from(WasmMsg::Execute {
+				contract_addr: Addr::try_from(config.router)?.into_string(),
+				msg: to_json_binary(&SwapRouterExecuteMsg::ExecuteSwaps {
+					swappers: plan.route.iter().map(Addr::unchecked).collect(),
+					intermediate_slippage_tolerance: Some(Decimal::raw(plan.slippage_tolerance)),
+					expectation: None,
+					receiver: SwapReceiver::Direct(env.contract.address),
+					referral: None,
+				})?,
+				funds: vec![offer],
+			}),
+			gas_limit: None,
+			reply_on: ReplyOn::Success,
+		}))
+}
+
+fn process_withdraw(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	plan_id: u64,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	nonpayable(&msg_info)?;
+	let mut plan = load_plan(plan_id)?;
+	if msg_info.sender.as_str() != plan.owner {
+		return Err(CrownfiSwapsCommonError::Unauthorized("Only the plan's owner can withdraw from it".into()).into());
+	}
+	if plan.output_amount == 0 {
+		return Err(DcaContractError::NothingToWithdraw);
+	}
+	let withdrawn = coin(plan.output_amount, &plan.output_denom);
+	plan.output_amount = 0;
+	get_plans_store().set(&plan_id.into(), &plan)?;
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "withdraw"),
+			attr("plan_id", plan_id.to_string()),
+			attr("owner", plan.owner.clone()),
+			attr("withdrawn", withdrawn.to_string()),
+		])
+		.add_message(BankMsg::Send {
+			to_address: plan.owner,
+			amount: vec![withdrawn],
+		}))
+}
+
+fn process_close_plan(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	plan_id: u64,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	nonpayable(&msg_info)?;
+	let plan = load_plan(plan_id)?;
+	if msg_info.sender.as_str() != plan.owner {
+		return Err(CrownfiSwapsCommonError::Unauthorized("Only the plan's owner can close it".into()).into());
+	}
+
+	get_plans_store().remove(&plan_id.into());
+	let owner_plans = get_owner_plans_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut owner_plan_ids = owner_plans
+		.get(&owner_addr)?
+		.map(|plan_ids| plan_ids.into_inner())
+		.unwrap_or_default();
+	owner_plan_ids.plan_ids.retain(|id| *id != plan_id);
+	if owner_plan_ids.plan_ids.is_empty() {
+		owner_plans.remove(&owner_addr);
+	} else {
+		owner_plans.set(&owner_addr, &owner_plan_ids)?;
+	}
+
+	let withdrawn: Vec<Coin> = [
+		coin(plan.remaining_amount, plan.input_denom),
+		coin(plan.output_amount, plan.output_denom),
+	]
+	.into_iter()
+	.filter(|coin| !coin.amount.is_zero())
+	.collect();
+	let mut response = Response::new().add_attributes(vec![
+		attr("action", "close_plan"),
+		attr("plan_id", plan_id.to_string()),
+		attr("owner", plan.owner.clone()),
+		attr(
+			"withdrawn",
+			withdrawn
+				.iter()
+				.map(|coin| coin.to_string())
+				.collect::<Vec<_>>()
+				.join(", "),
+		),
+	]);
+	if !withdrawn.is_empty() {
+		response = response.add_message(BankMsg::Send {
+			to_address: plan.owner,
+			amount: withdrawn,
+		});
+	}
+	Ok(response)
+}
+
+/// Credits the plan with whatever the router sent us
+fn process_plan_executed(deps: DepsMut<SeiQueryWrapper>, env: Env) -> Result<Response<SeiMsg>, DcaContractError> {
+	let execution = DcaExecution::load_non_empty()?;
+	DcaExecution::remove();
+	let mut plan = load_plan(execution.plan_id)?;
+	let output_balance_after = deps
+		.querier
+		.query_balance(&env.contract.address, &plan.output_denom)?
+		.amount;
+	let received = output_balance_after.checked_sub(execution.output_balance_before.into())?;
+	if received.u128() < execution.min_result {
+		return Err(DcaContractError::MinPriceNotReached);
+	}
+	plan.output_amount = Uint128::new(plan.output_amount).checked_add(received)?.u128();
+	get_plans_store().set(&execution.plan_id.into(), &plan)?;
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "plan_executed"),
+		attr("plan_id", execution.plan_id.to_string()),
+		attr("received", coin(received.u128(), plan.output_denom).to_string()),
+	]))
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn reply(deps: DepsMut<SeiQueryWrapper>, env: Env, msg: Reply) -> Result<Response<SeiMsg>, DcaContractError> {
+	match msg.id {
+		PLAN_EXECUTED_REPLY_ID => {
+			if let SubMsgResult::Err(err_msg) = msg.result {
+				return Err(DcaContractError::FailedReply(ParseReplyError::SubMsgFailure(err_msg)));
+			}
+			process_plan_executed(deps, env)
+		}
+		_ => Err(DcaContractError::FailedReply(ParseReplyError::ParseFailure(format!(
+			"Reply ID {0} is unknown",
+			msg.id
+		)))),
+	}
+}
+
+fn plan_info(plan_id: u64, plan: DcaPlan) -> DcaPlanInfo {
+	DcaPlanInfo {
+		plan_id,
+		owner: Addr::unchecked(&plan.owner),
+		remaining: coin(plan.remaining_amount, &plan.input_denom),
+		amount_per_execution: plan.amount_per_execution.into(),
+		output: coin(plan.output_amount, &plan.output_denom),
+		interval_seconds: plan.interval_seconds,
+		slippage_tolerance: Decimal::raw(plan.slippage_tolerance),
+		min_price: Decimal::raw(plan.min_price),
+		last_execution: if plan.executions == 0 {
+			None
+		} else {
+			Some(plan.last_execution)
+		},
+		next_execution: plan.next_execution(),
+		executions: plan.executions,
+		created_at: plan.created_at,
+		route: plan.route.into_iter().map(Addr::unchecked).collect(),
+	}
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(_deps: Deps<SeiQueryWrapper>, _env: Env, msg: DcaQueryMsg) -> Result<Binary, DcaContractError> {
+	Ok(match msg {
+		DcaQueryMsg::Config => to_json_binary(&DcaConfigJsonable::try_from(DcaConfig::load_non_empty()?.as_ref())?)?,
+		DcaQueryMsg::Plan { plan_id } => to_json_binary(
+			&get_plans_store()
+				.get(&plan_id.into())?
+				.map(|plan| plan_info(plan_id, plan.into_inner())),
+		)?,
+		DcaQueryMsg::ActivePlans { after, limit } => to_json_binary(
+			&get_plans_store()
+				.iter_range(after.map(DcaPlanKey::from), None)?
+				.map(|(plan_id, plan)| (plan_id, plan.into_inner()))
+				.filter(|(_, plan)| plan.is_active())
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.map(|(plan_id, plan)| plan_info(plan_id.into(), plan))
+				.collect::<Vec<_>>(),
+		)?,
+		DcaQueryMsg::PlansByOwner { owner } => {
+			let owner_plan_ids = get_owner_plans_store()
+				.get(&(&owner).try_into()?)?
+				.map(|plan_ids| plan_ids.into_inner())
+				.unwrap_or_default();
+			to_json_binary(
+				&owner_plan_ids
+					.plan_ids
+					.into_iter()
+					.map(|plan_id| Ok(plan_info(plan_id, load_plan(plan_id)?)))
+					.collect::<Result<Vec<_>, DcaContractError>>()?,
+			)?
+		}
+	})
+}
@@ -0,0 +1,46 @@
+use cosmwasm_std::{Decimal, StdError};
+use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_std_error_common};
+use cw_utils::{ParseReplyError, PaymentError};
+use thiserror::Error;
+
+use crate::contract::MAX_PLANS_PER_OWNER;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DcaContractError {
+	#[error("StdError: {0}")]
+	Std(#[from] StdError),
+	#[error("{0}")]
+	SwapsCommon(#[from] CrownfiSwapsCommonError),
+	#[error("Payment error: {0}")]
+	PaymentError(#[from] PaymentError),
+	#[error("Failed reply: {0}")]
+	FailedReply(#[from] ParseReplyError),
+	#[error("Route cannot be empty")]
+	RouteEmpty,
+	#[error("Route must result in a different denom than the one deposited")]
+	RouteIsCircular,
+	#[error("The amount per execution and the interval must be greater than 0")]
+	InvalidSchedule,
+	#[error("Slippage tolerance cannot exceed {0}")]
+	SlippageToleranceTooHigh(Decimal),
+	#[error("Accounts cannot have more than {} plans", MAX_PLANS_PER_OWNER)]
+	TooManyPlans,
+	#[error("Plan {0} does not exist")]
+	PlanNotFound(u64),
+	#[error("Plan has nothing left to swap")]
+	PlanFinished,
+	#[error("Plan cannot be executed until {0}")]
+	TooEarly(u64),
+	#[error("Refusing to execute a trade where it's expected to lose everything")]
+	ExpectingNothing,
+	#[error("The minimum price must be greater than 0")]
+	MinPriceZero,
+	#[error("The plan's minimum price has not been reached")]
+	MinPriceNotReached,
+	#[error("Plan has no output to withdraw")]
+	NothingToWithdraw,
+	#[error("Cannot execute a plan while the previous one is incomplete")]
+	AlreadyExecutingPlan,
+}
+
+impl_from_cosmwasm_std_error_common!(DcaContractError);
@@ -0,0 +1,87 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+
+use crate::state::DcaConfigJsonable;
+
+/// This structure stores the basic settings for creating a new DCA contract.
+#[cw_serde]
+pub struct DcaInstantiateMsg {
+	pub config: DcaConfigJsonable,
+}
+
+/// This structure describes the execute messages of the contract.
+#[cw_serde]
+pub enum DcaExecuteMsg {
+	/// Updates the contract's config
+	UpdateConfig {
+		/// The head honcho
+		admin: Option<Addr>,
+		/// The swap router used to execute each plan's route
+		router: Option<Addr>,
+	},
+	/// Creates a plan which swaps the coin sent along with this message in installments. The coin is held by this
+	/// contract until it has all been swapped or the plan is withdrawn.
+	CreatePlan {
+		/// The swappers the router will use, in order
+		route: Vec<Addr>,
+		/// The amount swapped each time the plan is executed. The last execution swaps whatever is left.
+		amount_per_execution: Uint128,
+		/// The minimum amount of time (in seconds) between executions
+		interval_seconds: u64,
+		/// A value between 0 and 1 determining how much worse the result of each step of the route can be compared to
+		/// its result assuming infinite liquidity. e.g. 0.01 means a 1% slippage tolerance.
+		slippage_tolerance: Decimal,
+		/// The minimum amount of the output to receive per unit of the deposit swapped. Executions which would receive
+		/// less than this fail, so the plan waits until the price recovers. Must be greater than 0.
+		min_price: Decimal,
+	},
+	/// Swaps the next installment of a plan if its interval has passed. Anyone can do this.
+	ExecutePlan { plan_id: u64 },
+	/// Sends the output received so far to the plan's owner, the plan keeps running. Only the plan's owner may do
+	/// this.
+	Withdraw { plan_id: u64 },
+	/// Closes a plan, sending the deposit which has yet to be swapped along with all the output received to the plan's
+	/// owner. Only the plan's owner may do this.
+	ClosePlan { plan_id: u64 },
+}
+
+/// This structure describes the available query messages for the DCA contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum DcaQueryMsg {
+	/// Returns the contract's config.
+	#[returns(DcaConfigJsonable)]
+	Config,
+	/// Returns the specified plan, if it hasn't been closed.
+	#[returns(Option<DcaPlanInfo>)]
+	Plan { plan_id: u64 },
+	/// Returns all plans which still have something left to swap, in the order they were created.
+	#[returns(Vec<DcaPlanInfo>)]
+	ActivePlans { after: Option<u64>, limit: Option<u32> },
+	/// Returns the specified account's plans which haven't been closed, in the order they were created.
+	#[returns(Vec<DcaPlanInfo>)]
+	PlansByOwner { owner: Addr },
+}
+
+#[cw_serde]
+pub struct DcaPlanInfo {
+	pub plan_id: u64,
+	pub owner: Addr,
+	pub route: Vec<Addr>,
+	/// The deposit which has yet to be swapped
+	pub remaining: Coin,
+	pub amount_per_execution: Uint128,
+	/// The output received which hasn't been withdrawn yet
+	pub output: Coin,
+	pub interval_seconds: u64,
+	pub slippage_tolerance: Decimal,
+	/// The minimum amount of the output received per unit of the deposit swapped
+	pub min_price: Decimal,
+	/// Unix timestamp (in seconds) of the last execution
+	pub last_execution: Option<u64>,
+	/// Unix timestamp (in seconds) of when the plan may next be executed, `None` if there's nothing left to swap.
+	pub next_execution: Option<u64>,
+	pub executions: u64,
+	/// Unix timestamp (in seconds) of when the plan was created
+	pub created_at: u64,
+}
@@ -0,0 +1,213 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use cosmwasm_std::{Addr, StdError};
+use crownfi_cw_common::{
+	data_types::canonical_addr::SeiCanonicalAddr,
+	impl_serializable_as_ref, impl_serializable_borsh,
+	storage::{item::StoredItem, map::StoredMap, OZeroCopy, SerializableItem},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG_NAMESPACE: &str = "app_cfg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct DcaConfig {
+	/// The head honcho
+	pub admin: SeiCanonicalAddr,
+	/// The swap router used to execute each plan's route
+	pub router: SeiCanonicalAddr,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct DcaConfigJsonable {
+	/// The head honcho
+	pub admin: Addr,
+	/// The swap router used to execute each plan's route
+	pub router: Addr,
+}
+
+impl_serializable_as_ref!(DcaConfig);
+impl StoredItem for DcaConfig {
+	fn namespace() -> &'static [u8] {
+		CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl DcaConfig {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "DcaConfig".into(),
+			}),
+		}
+	}
+}
+impl TryFrom<&DcaConfigJsonable> for DcaConfig {
+	type Error = StdError;
+	fn try_from(value: &DcaConfigJsonable) -> Result<Self, Self::Error> {
+		Ok(DcaConfig {
+			admin: (&value.admin).try_into()?,
+			router: (&value.router).try_into()?,
+		})
+	}
+}
+impl TryFrom<&DcaConfig> for DcaConfigJsonable {
+	type Error = StdError;
+	fn try_from(value: &DcaConfig) -> Result<Self, Self::Error> {
+		Ok(DcaConfigJsonable {
+			admin: value.admin.try_into()?,
+			router: value.router.try_into()?,
+		})
+	}
+}
+
+pub const STATE_NAMESPACE: &str = "state";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct DcaState {
+	/// The ID which will be given to the next plan created. IDs start at 1.
+	pub next_plan_id: u64,
+}
+impl_serializable_as_ref!(DcaState);
+impl StoredItem for DcaState {
+	fn namespace() -> &'static [u8] {
+		STATE_NAMESPACE.as_bytes()
+	}
+}
+impl DcaState {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "DcaState".into(),
+			}),
+		}
+	}
+}
+
+pub const EXECUTION_NAMESPACE: &str = "executing";
+
+/// Exists only while a plan's route is being executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct DcaExecution {
+	/// This contract's balance of the plan's output denom before the route was executed
+	pub output_balance_before: u128,
+	/// The least the plan must receive from the route, according to its minimum price
+	pub min_result: u128,
+	pub plan_id: u64,
+	_unused: [u8; 8],
+}
+impl_serializable_as_ref!(DcaExecution);
+impl StoredItem for DcaExecution {
+	fn namespace() -> &'static [u8] {
+		EXECUTION_NAMESPACE.as_bytes()
+	}
+}
+impl DcaExecution {
+	pub fn new(plan_id: u64, output_balance_before: u128, min_result: u128) -> Self {
+		Self {
+			output_balance_before,
+			min_result,
+			plan_id,
+			_unused: Zeroable::zeroed(),
+		}
+	}
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "DcaExecution".into(),
+			}),
+		}
+	}
+}
+
+/// Plan IDs as stored, big-endian so plans are iterated in the order they were created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(transparent)]
+pub struct DcaPlanKey([u8; 8]);
+impl_serializable_as_ref!(DcaPlanKey);
+impl From<u64> for DcaPlanKey {
+	fn from(value: u64) -> Self {
+		Self(value.to_be_bytes())
+	}
+}
+impl From<DcaPlanKey> for u64 {
+	fn from(value: DcaPlanKey) -> Self {
+		u64::from_be_bytes(value.0)
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct DcaPlan {
+	pub owner: String,
+	/// The swappers passed to the router, in order
+	pub route: Vec<String>,
+	pub input_denom: String,
+	/// The deposit which has yet to be swapped
+	pub remaining_amount: u128,
+	/// The amount swapped each time the plan is executed
+	pub amount_per_execution: u128,
+	pub output_denom: String,
+	/// The output received which hasn't been withdrawn yet
+	pub output_amount: u128,
+	/// The minimum amount of time (in seconds) between executions
+	pub interval_seconds: u64,
+	/// The maximum difference between each step's result and its result assuming infinite liquidity, converted
+	/// verbatim from a `Decimal`
+	pub slippage_tolerance: u128,
+	/// The minimum amount of the output received per unit of the deposit swapped, converted verbatim from a `Decimal`
+	pub min_price: u128,
+	/// Unix timestamp (in seconds) of the last execution, 0 if it has never been executed
+	pub last_execution: u64,
+	pub executions: u64,
+	/// Unix timestamp (in seconds) of when the plan was created
+	pub created_at: u64,
+}
+impl_serializable_borsh!(DcaPlan);
+impl DcaPlan {
+	/// Returns true if there's still something left to swap
+	pub fn is_active(&self) -> bool {
+		self.remaining_amount > 0
+	}
+	/// Unix timestamp (in seconds) of when the plan may next be executed, or `None` if it's no longer active.
+	pub fn next_execution(&self) -> Option<u64> {
+		if !self.is_active() {
+			None
+		} else if self.executions == 0 {
+			Some(self.created_at)
+		} else {
+			Some(self.last_execution.saturating_add(self.interval_seconds))
+		}
+	}
+}
+
+const PLANS_NAMESPACE: &str = "plans";
+pub fn get_plans_store() -> StoredMap<DcaPlanKey, DcaPlan> {
+	StoredMap::new(PLANS_NAMESPACE.as_ref())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct DcaPlanIds {
+	pub plan_ids: Vec<u64>,
+}
+impl_serializable_borsh!(DcaPlanIds);
+
+const OWNER_PLANS_NAMESPACE: &str = "owner_plans";
+/// The IDs of each account's plans, in the order they were created.
+pub fn get_owner_plans_store() -> StoredMap<SeiCanonicalAddr, DcaPlanIds> {
+	StoredMap::new(OWNER_PLANS_NAMESPACE.as_ref())
+}
@@ -0,0 +1,89 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, BankMsg, SubMsg,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{execute, query},
+	error::DcaContractError,
+	msg::{DcaExecuteMsg, DcaPlanInfo, DcaQueryMsg},
+	tests::{create_plan, deps, execute_plan, init, plan_executed, plan_info, AddressFactory, PAIR_DENOMS},
+};
+
+#[test]
+fn refunds_the_deposit_and_output() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+	execute_plan(&mut deps, mock_env()).unwrap();
+	plan_executed(&mut deps, 2100).unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::OWNER, &[]),
+		DcaExecuteMsg::ClosePlan { plan_id: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::OWNER.into(),
+			amount: vec![coin(2000, PAIR_DENOMS[0]), coin(2100, PAIR_DENOMS[1])]
+		})]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "close_plan"),
+			attr("plan_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr(
+				"withdrawn",
+				format!("{}, {}", coin(2000, PAIR_DENOMS[0]), coin(2100, PAIR_DENOMS[1]))
+			),
+		]
+	);
+
+	assert_eq!(plan_info(&deps, mock_env()), None);
+	let owner_plans: Vec<DcaPlanInfo> = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			DcaQueryMsg::PlansByOwner {
+				owner: Addr::unchecked(AddressFactory::OWNER),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(owner_plans, vec![]);
+}
+
+#[test]
+fn only_the_owner_can_close() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::KEEPER, &[]),
+			DcaExecuteMsg::ClosePlan { plan_id: 1 },
+		),
+		Err(CrownfiSwapsCommonError::Unauthorized("Only the plan's owner can close it".into()).into())
+	);
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::OWNER, &[]),
+			DcaExecuteMsg::ClosePlan { plan_id: 2 },
+		),
+		Err(DcaContractError::PlanNotFound(2))
+	);
+}
@@ -0,0 +1,111 @@
+use cosmwasm_std::{
+	attr, coin,
+	testing::{mock_env, mock_info},
+	Addr, Decimal, Response, Uint128,
+};
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::execute,
+	error::DcaContractError,
+	msg::{DcaExecuteMsg, DcaPlanInfo},
+	tests::{create_plan, deps, init, plan_info, AddressFactory, TestDeps, INTERVAL_SECONDS, PAIR_DENOMS},
+};
+
+#[test]
+fn holds_the_deposit() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	let res = create_plan(&mut deps).unwrap();
+	// The deposit stays with this contract until it's swapped or the plan is closed
+	assert_eq!(res.messages, vec![]);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "create_plan"),
+			attr("plan_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr("deposit", coin(3000, PAIR_DENOMS[0]).to_string()),
+			attr("amount_per_execution", "1000"),
+			attr("interval_seconds", INTERVAL_SECONDS.to_string()),
+			attr("min_price", "2"),
+		]
+	);
+	let created_at = mock_env().block.time.seconds();
+	assert_eq!(
+		plan_info(&deps, mock_env()),
+		Some(DcaPlanInfo {
+			plan_id: 1,
+			owner: Addr::unchecked(AddressFactory::OWNER),
+			route: vec![Addr::unchecked(AddressFactory::POOL)],
+			remaining: coin(3000, PAIR_DENOMS[0]),
+			amount_per_execution: Uint128::new(1000),
+			output: coin(0, PAIR_DENOMS[1]),
+			interval_seconds: INTERVAL_SECONDS,
+			slippage_tolerance: Decimal::percent(1),
+			min_price: Decimal::from_ratio(2u8, 1u8),
+			last_execution: None,
+			// Can be executed straight away
+			next_execution: Some(created_at),
+			executions: 0,
+			created_at,
+		})
+	);
+}
+
+fn create_plan_with(
+	deps: &mut TestDeps,
+	deposit_denom: &str,
+	route: Vec<Addr>,
+	amount_per_execution: u128,
+) -> Result<Response<SeiMsg>, DcaContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::OWNER, &[coin(3000, deposit_denom)]),
+		DcaExecuteMsg::CreatePlan {
+			route,
+			amount_per_execution: Uint128::new(amount_per_execution),
+			interval_seconds: INTERVAL_SECONDS,
+			slippage_tolerance: Decimal::percent(1),
+			min_price: Decimal::percent(1),
+		},
+	)
+}
+
+#[test]
+fn rejects_invalid_plans() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	let route = vec![Addr::unchecked(AddressFactory::POOL)];
+	assert_eq!(
+		create_plan_with(&mut deps, PAIR_DENOMS[0], vec![], 1000),
+		Err(DcaContractError::RouteEmpty)
+	);
+	assert_eq!(
+		create_plan_with(&mut deps, PAIR_DENOMS[0], route.clone(), 0),
+		Err(DcaContractError::InvalidSchedule)
+	);
+	// The route results in the deposited denom
+	assert_eq!(
+		create_plan_with(&mut deps, PAIR_DENOMS[1], route.clone(), 1000),
+		Err(DcaContractError::RouteIsCircular)
+	);
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::OWNER, &[coin(3000, PAIR_DENOMS[0])]),
+			DcaExecuteMsg::CreatePlan {
+				route,
+				amount_per_execution: Uint128::new(1000),
+				interval_seconds: INTERVAL_SECONDS,
+				slippage_tolerance: Decimal::percent(1),
+				min_price: Decimal::zero(),
+			},
+		),
+		Err(DcaContractError::MinPriceZero)
+	);
+}
@@ -0,0 +1,169 @@
+use cosmwasm_std::{
+	attr, coin,
+	testing::{mock_env, mock_info},
+	to_json_binary, Addr, Decimal, ReplyOn, WasmMsg,
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_swap_router_contract::msg::{SwapReceiver, SwapRouterExecuteMsg};
+
+use crate::{
+	contract::{execute, PLAN_EXECUTED_REPLY_ID},
+	error::DcaContractError,
+	msg::DcaExecuteMsg,
+	state::DcaExecution,
+	tests::{
+		create_plan, deps, env_after, execute_plan, init, plan_executed, plan_info, set_route_result, AddressFactory,
+		INTERVAL_SECONDS, PAIR_DENOMS,
+	},
+};
+
+#[test]
+fn swaps_an_installment() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	let res = execute_plan(&mut deps, mock_env()).unwrap();
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "execute_plan"),
+			attr("plan_id", "1"),
+			attr("keeper", AddressFactory::KEEPER),
+			attr("offer", coin(1000, PAIR_DENOMS[0]).to_string()),
+		]
+	);
+	assert_eq!(res.messages.len(), 1);
+	assert_eq!(res.messages[0].id, PLAN_EXECUTED_REPLY_ID);
+	assert_eq!(res.messages[0].reply_on, ReplyOn::Success);
+	assert_eq!(
+		res.messages[0].msg,
+		WasmMsg::Execute {
+			contract_addr: AddressFactory::ROUTER.into(),
+			msg: to_json_binary(&SwapRouterExecuteMsg::ExecuteSwaps {
+				swappers: vec![Addr::unchecked(AddressFactory::POOL)],
+				intermediate_slippage_tolerance: Some(Decimal::percent(1)),
+				// The minimum price is checked once the output is received
+				expectation: None,
+				receiver: SwapReceiver::Direct(mock_env().contract.address),
+				referral: None,
+			})
+			.unwrap(),
+			funds: vec![coin(1000, PAIR_DENOMS[0])],
+		}
+		.into()
+	);
+
+	let plan = plan_info(&deps, mock_env()).unwrap();
+	assert_eq!(plan.remaining, coin(2000, PAIR_DENOMS[0]));
+	assert_eq!(plan.executions, 1);
+	assert_eq!(plan.last_execution, Some(mock_env().block.time.seconds()));
+	assert_eq!(
+		plan.next_execution,
+		Some(mock_env().block.time.seconds() + INTERVAL_SECONDS)
+	);
+	let execution = DcaExecution::load().unwrap().unwrap();
+	assert_eq!(execution.plan_id, 1);
+	assert_eq!(execution.min_result, 2000);
+}
+
+#[test]
+fn credits_what_was_received() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+	// Output which isn't from this execution, e.g. other plans' output
+	deps.querier
+		.update_balance(mock_env().contract.address, vec![coin(500, PAIR_DENOMS[1])]);
+
+	execute_plan(&mut deps, mock_env()).unwrap();
+	let res = plan_executed(&mut deps, 500 + 2100).unwrap();
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "plan_executed"),
+			attr("plan_id", "1"),
+			attr("received", coin(2100, PAIR_DENOMS[1]).to_string()),
+		]
+	);
+	assert_eq!(plan_info(&deps, mock_env()).unwrap().output, coin(2100, PAIR_DENOMS[1]));
+	assert!(DcaExecution::load().unwrap().is_none());
+}
+
+#[test]
+fn fails_below_the_min_price() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	// Less than 2cba per abc
+	set_route_result(&mut deps, 1999);
+	assert_eq!(
+		execute_plan(&mut deps, mock_env()),
+		Err(DcaContractError::MinPriceNotReached)
+	);
+
+	// The price may have moved before the transaction, so what's received is what counts.
+	set_route_result(&mut deps, 2000);
+	execute_plan(&mut deps, mock_env()).unwrap();
+	assert_eq!(
+		plan_executed(&mut deps, 1999),
+		Err(DcaContractError::MinPriceNotReached)
+	);
+}
+
+#[test]
+fn waits_for_the_interval() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	execute_plan(&mut deps, mock_env()).unwrap();
+	plan_executed(&mut deps, 2100).unwrap();
+	let mut env = env_after(1);
+	env.block.time = env.block.time.minus_seconds(1);
+	assert_eq!(
+		execute_plan(&mut deps, env),
+		Err(DcaContractError::TooEarly(env_after(1).block.time.seconds()))
+	);
+	execute_plan(&mut deps, env_after(1)).unwrap();
+}
+
+#[test]
+fn finishes_once_everything_is_swapped() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	for executions in 0..3 {
+		execute_plan(&mut deps, env_after(executions)).unwrap();
+		plan_executed(&mut deps, (executions as u128 + 1) * 2100).unwrap();
+	}
+	let plan = plan_info(&deps, env_after(3)).unwrap();
+	assert_eq!(plan.remaining, coin(0, PAIR_DENOMS[0]));
+	assert_eq!(plan.output, coin(6300, PAIR_DENOMS[1]));
+	assert_eq!(plan.next_execution, None);
+	assert_eq!(
+		execute_plan(&mut deps, env_after(3)),
+		Err(DcaContractError::PlanFinished)
+	);
+}
+
+#[test]
+fn one_plan_at_a_time() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+	create_plan(&mut deps).unwrap();
+
+	execute_plan(&mut deps, mock_env()).unwrap();
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::KEEPER, &[]),
+			DcaExecuteMsg::ExecutePlan { plan_id: 2 },
+		),
+		Err(DcaContractError::AlreadyExecutingPlan)
+	);
+}
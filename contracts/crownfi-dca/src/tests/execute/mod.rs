@@ -0,0 +1,4 @@
+mod close_plan;
+mod create_plan;
+mod execute_plan;
+mod withdraw;
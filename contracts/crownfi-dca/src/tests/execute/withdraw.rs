@@ -0,0 +1,91 @@
+use cosmwasm_std::{
+	attr, coin,
+	testing::{mock_env, mock_info},
+	BankMsg, Response, SubMsg,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::execute,
+	error::DcaContractError,
+	msg::DcaExecuteMsg,
+	tests::{
+		create_plan, deps, env_after, execute_plan, init, plan_executed, plan_info, AddressFactory, TestDeps,
+		PAIR_DENOMS,
+	},
+};
+
+fn withdraw(deps: &mut TestDeps, sender: &str) -> Result<Response<SeiMsg>, DcaContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[]),
+		DcaExecuteMsg::Withdraw { plan_id: 1 },
+	)
+}
+
+#[test]
+fn pays_out_the_output_and_keeps_running() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+	execute_plan(&mut deps, mock_env()).unwrap();
+	plan_executed(&mut deps, 2100).unwrap();
+
+	let res = withdraw(&mut deps, AddressFactory::OWNER).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::OWNER.into(),
+			amount: vec![coin(2100, PAIR_DENOMS[1])]
+		})]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "withdraw"),
+			attr("plan_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr("withdrawn", coin(2100, PAIR_DENOMS[1]).to_string()),
+		]
+	);
+	let plan = plan_info(&deps, mock_env()).unwrap();
+	assert_eq!(plan.output, coin(0, PAIR_DENOMS[1]));
+	assert_eq!(plan.remaining, coin(2000, PAIR_DENOMS[0]));
+
+	// The withdrawn output has left the contract by the next execution
+	deps.querier.update_balance(mock_env().contract.address, vec![]);
+	execute_plan(&mut deps, env_after(1)).unwrap();
+	plan_executed(&mut deps, 2100).unwrap();
+	assert_eq!(
+		plan_info(&deps, env_after(1)).unwrap().output,
+		coin(2100, PAIR_DENOMS[1])
+	);
+}
+
+#[test]
+fn fails_without_output() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+
+	assert_eq!(
+		withdraw(&mut deps, AddressFactory::OWNER),
+		Err(DcaContractError::NothingToWithdraw)
+	);
+}
+
+#[test]
+fn only_the_owner_can_withdraw() {
+	let mut deps = deps();
+	init(&mut deps);
+	create_plan(&mut deps).unwrap();
+	execute_plan(&mut deps, mock_env()).unwrap();
+	plan_executed(&mut deps, 2100).unwrap();
+
+	assert_eq!(
+		withdraw(&mut deps, AddressFactory::KEEPER),
+		Err(CrownfiSwapsCommonError::Unauthorized("Only the plan's owner can withdraw from it".into()).into())
+	);
+}
@@ -0,0 +1,134 @@
+use cosmwasm_std::{
+	coin, from_json, testing::*, to_json_binary, Addr, ContractResult, Decimal, Env, MemoryStorage, OwnedDeps, Reply,
+	Response, SubMsgResponse, SubMsgResult, SystemError, SystemResult, Uint128, WasmQuery,
+};
+use crownfi_swap_router_contract::msg::{SwapRouterQueryMsg, SwapRouterSimulateSwapsResponse};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::contract::*;
+use crate::error::*;
+use crate::msg::*;
+use crate::state::*;
+
+mod execute;
+
+const PAIR_DENOMS: [&str; 2] = ["abc", "cba"];
+const INTERVAL_SECONDS: u64 = 3600;
+
+type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier<SeiQueryWrapper>, SeiQueryWrapper>;
+
+enum AddressFactory {}
+impl AddressFactory {
+	/// supposed to be used as the contract owner/fee receiver etc
+	pub(crate) const ADMIN: &'static str = "sei1zgfgerl8qt9uldlr0y9w7qe97p7zyv5kwg2pge";
+	pub(crate) const ROUTER: &'static str = "sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a";
+	/// A pool trading `PAIR_DENOMS`
+	pub(crate) const POOL: &'static str = "sei1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygskw0yqp";
+	pub(crate) const OWNER: &'static str = "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue";
+	pub(crate) const KEEPER: &'static str = "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft";
+}
+
+fn deps() -> TestDeps {
+	let mem = Box::new(MockStorage::default());
+	let mem_ptr = Box::leak(mem) as *mut MockStorage;
+	crownfi_cw_common::storage::base::set_global_storage(unsafe { Box::from_raw(mem_ptr) });
+
+	let mut deps = OwnedDeps {
+		querier: MockQuerier::<SeiQueryWrapper>::new(&[]),
+		storage: unsafe { mem_ptr.read() },
+		api: MockApi::default(),
+		custom_query_type: Default::default(),
+	};
+	set_route_result(&mut deps, 2100);
+	deps
+}
+
+/// Mocks the router, with `SimulateSwaps` resulting in the specified amount of `PAIR_DENOMS[1]`.
+fn set_route_result(deps: &mut TestDeps, result_amount: u128) {
+	deps.querier.update_wasm(move |query| match query {
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == AddressFactory::ROUTER => {
+			let SwapRouterQueryMsg::SimulateSwaps { .. } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			SystemResult::Ok(ContractResult::Ok(
+				to_json_binary(&SwapRouterSimulateSwapsResponse {
+					result_denom: PAIR_DENOMS[1].into(),
+					result_amount: Uint128::new(result_amount),
+					slip_amount: Uint128::zero(),
+				})
+				.unwrap(),
+			))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+}
+
+fn init(deps: &mut TestDeps) -> Response<SeiMsg> {
+	instantiate(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		DcaInstantiateMsg {
+			config: DcaConfigJsonable {
+				admin: Addr::unchecked(AddressFactory::ADMIN),
+				router: Addr::unchecked(AddressFactory::ROUTER),
+			},
+		},
+	)
+	.unwrap()
+}
+
+/// Has `OWNER` create a plan swapping 3000abc, 1000abc at a time, for at least 2cba each
+fn create_plan(deps: &mut TestDeps) -> Result<Response<SeiMsg>, DcaContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::OWNER, &[coin(3000, PAIR_DENOMS[0])]),
+		DcaExecuteMsg::CreatePlan {
+			route: vec![Addr::unchecked(AddressFactory::POOL)],
+			amount_per_execution: Uint128::new(1000),
+			interval_seconds: INTERVAL_SECONDS,
+			slippage_tolerance: Decimal::percent(1),
+			min_price: Decimal::from_ratio(2u8, 1u8),
+		},
+	)
+}
+
+/// `mock_env()` after the specified amount of executions
+fn env_after(executions: u64) -> Env {
+	let mut env = mock_env();
+	env.block.time = env.block.time.plus_seconds(executions * INTERVAL_SECONDS);
+	env
+}
+
+fn execute_plan(deps: &mut TestDeps, env: Env) -> Result<Response<SeiMsg>, DcaContractError> {
+	execute(
+		deps.as_mut(),
+		env,
+		mock_info(AddressFactory::KEEPER, &[]),
+		DcaExecuteMsg::ExecutePlan { plan_id: 1 },
+	)
+}
+
+/// The router's swap succeeding, leaving this contract with the specified balance of `PAIR_DENOMS[1]`
+fn plan_executed(deps: &mut TestDeps, output_balance_after: u128) -> Result<Response<SeiMsg>, DcaContractError> {
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![coin(output_balance_after, PAIR_DENOMS[1])],
+	);
+	reply(
+		deps.as_mut(),
+		mock_env(),
+		Reply {
+			id: PLAN_EXECUTED_REPLY_ID,
+			result: SubMsgResult::Ok(SubMsgResponse {
+				events: vec![],
+				data: None,
+			}),
+		},
+	)
+}
+
+fn plan_info(deps: &TestDeps, env: Env) -> Option<DcaPlanInfo> {
+	from_json(query(deps.as_ref(), env, DcaQueryMsg::Plan { plan_id: 1 }).unwrap()).unwrap()
+}
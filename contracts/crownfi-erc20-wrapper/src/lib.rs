@@ -1,5 +1,6 @@
 use cosmwasm_std::{
-	BankMsg, Binary, Coin, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Reply, ReplyOn, Response, SubMsg, Uint128,
+	to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply, ReplyOn, Response,
+	SubMsg, Uint128,
 };
 
 use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::map::StoredMap};
@@ -7,7 +8,7 @@ use cw_utils::{nonpayable, ParseReplyError};
 use sei_cosmwasm::{SeiMsg, SeiQuerier, SeiQueryWrapper};
 
 use error::Erc20WrapperError;
-use msg::ERC20WrapperExecMsg;
+use msg::{ERC20WrapperExecMsg, ERC20WrapperQueryMsg};
 
 mod error;
 pub mod msg;
@@ -18,6 +19,8 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 const WRAP_EVM_CALL_ID: u64 = 2571182633660066190;
 const UNWRAP_EVM_CALL_ID: u64 = 13078395618759265986;
 
+const KNOWN_TOKENS_NAMESPACE: &[u8] = b"known_tokens";
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn instantiate(
 	deps: DepsMut<SeiQueryWrapper>,
@@ -72,7 +75,7 @@ pub fn execute(
 	info: MessageInfo,
 	msg: ERC20WrapperExecMsg,
 ) -> Result<Response<SeiMsg>, Erc20WrapperError> {
-	let known_tokens = StoredMap::<[u8; 20], bool>::new(b"known_tokens");
+	let known_tokens = StoredMap::<[u8; 20], bool>::new(KNOWN_TOKENS_NAMESPACE);
 	let querier = SeiQuerier::new(&deps.querier);
 
 	let response = match msg {
@@ -166,6 +169,36 @@ pub fn execute(
 	Ok(response)
 }
 
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(_deps: Deps<SeiQueryWrapper>, env: Env, msg: ERC20WrapperQueryMsg) -> Result<Binary, Erc20WrapperError> {
+	match msg {
+		ERC20WrapperQueryMsg::UnwrappedAddrOf { denom } => {
+			let known_tokens = StoredMap::<[u8; 20], bool>::new(KNOWN_TOKENS_NAMESPACE);
+			let mut token_addr = None;
+			if let Some(hex_addr) = denom.strip_prefix(&format!("factory/{}/crwn", env.contract.address)) {
+				// Denoms are only ever created with upper case addresses
+				let is_upper_case = !hex_addr.bytes().any(|byte| byte.is_ascii_lowercase());
+				if let Ok(bare_addr) = <[u8; 20] as hex::FromHex>::from_hex(hex_addr) {
+					if is_upper_case && known_tokens.has(&bare_addr) {
+						token_addr = Some(format!("0x{hex_addr}"));
+					}
+				}
+			}
+			Ok(to_json_binary(&token_addr)?)
+		}
+		ERC20WrapperQueryMsg::WrappedDenomOf { token_addr } => {
+			if !token_addr.starts_with("0x") {
+				return Err(Erc20WrapperError::InvalidEvmAddress(token_addr));
+			}
+			Ok(to_json_binary(&format!(
+				"factory/{}/crwn{}",
+				env.contract.address,
+				token_addr[2..].to_uppercase()
+			))?)
+		}
+	}
+}
+
 const TRANSFER_SIG: &[u8] = b"\xa9\x05\x9c\xbb";
 const TRANSFER_FROM_SIG: &[u8] = b"\x23\xb8\x72\xdd";
 
@@ -1,3 +1,4 @@
+use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{Addr, Binary, Uint128};
 
 #[cosmwasm_schema::cw_serde]
@@ -12,3 +13,14 @@ pub enum ERC20WrapperExecMsg {
 		evm_recipient: Binary,
 	},
 }
+
+#[cosmwasm_schema::cw_serde]
+#[derive(QueryResponses)]
+pub enum ERC20WrapperQueryMsg {
+	/// Returns the "0x"-prefixed address of the ERC20 token the specified denom wraps, if it was created by this
+	/// contract.
+	#[returns(Option<String>)]
+	UnwrappedAddrOf { denom: String },
+	#[returns(String)]
+	WrappedDenomOf { token_addr: String },
+}
@@ -0,0 +1,440 @@
+use cosmwasm_std::{
+	attr, coin, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+	QuerierWrapper, Reply, ReplyOn, Response, SubMsg, SubMsgResult, Uint128, WasmMsg,
+};
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_pool_factory_contract::msg::{PoolFactoryCreatedPair, PoolFactoryQueryMsg};
+use crownfi_pool_pair_contract::{
+	msg::{PoolPairCalcSwapResult, PoolPairExecuteMsg, PoolPairQueryMsg},
+	raw_query::PoolPairRawState,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use cw2::set_contract_version;
+use cw_utils::{must_pay, nonpayable, one_coin, ParseReplyError};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::{
+	error::LimitOrdersContractError,
+	msg::{
+		LimitOrderInfo, LimitOrderSimulateExecutionResponse, LimitOrdersExecuteMsg, LimitOrdersInstantiateMsg,
+		LimitOrdersQueryMsg,
+	},
+	state::{
+		get_orders_store, get_owner_orders_store, LimitOrder, LimitOrderExecution, LimitOrderKey, LimitOrdersConfig,
+		LimitOrdersConfigJsonable, LimitOrdersState,
+	},
+};
+
+const CONTRACT_NAME: &str = "crownfi-limit-orders";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// `reply` call code IDs used in a sub-message.
+pub(crate) const ORDER_EXECUTED_REPLY_ID: u64 = 0x6c696d69745f6f72;
+
+/// Keeps `OrdersByOwner` and the owner index cheap to load
+pub const MAX_ORDERS_PER_OWNER: usize = 64;
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn instantiate(
+	deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+	msg: LimitOrdersInstantiateMsg,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	nonpayable(&msg_info)?;
+	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	let config = LimitOrdersConfig::try_from(&msg.config)?;
+	if !config.valid_bounty_bps() {
+		return Err(LimitOrdersContractError::InvalidKeeperBounty);
+	}
+	config.save()?;
+	LimitOrdersState { next_order_id: 1 }.save()?;
+	Ok(Response::new())
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn execute(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	msg: LimitOrdersExecuteMsg,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	match msg {
+		LimitOrdersExecuteMsg::UpdateConfig {
+			admin,
+			factory,
+			keeper_bounty_bps,
+		} => process_update_config(deps, msg_info, admin, factory, keeper_bounty_bps),
+		LimitOrdersExecuteMsg::PlaceOrder { pool, limit_price } => {
+			process_place_order(deps, env, msg_info, pool, limit_price)
+		}
+		LimitOrdersExecuteMsg::CancelOrder { order_id } => process_cancel_order(deps, msg_info, order_id),
+		LimitOrdersExecuteMsg::ExecuteOrder { order_id } => process_execute_order(deps, msg_info, order_id),
+		LimitOrdersExecuteMsg::CompleteOrder {} => process_complete_order(deps, msg_info),
+	}
+}
+
+fn process_update_config(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	admin: Option<Addr>,
+	factory: Option<Addr>,
+	keeper_bounty_bps: Option<u16>,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	nonpayable(&msg_info)?;
+	let mut config = LimitOrdersConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if let Some(admin) = admin {
+		config.admin = admin.try_into()?;
+	}
+	if let Some(factory) = factory {
+		config.factory = factory.try_into()?;
+	}
+	if let Some(keeper_bounty_bps) = keeper_bounty_bps {
+		config.keeper_bounty_bps = keeper_bounty_bps;
+	}
+	if !config.valid_bounty_bps() {
+		return Err(LimitOrdersContractError::InvalidKeeperBounty);
+	}
+	config.save()?;
+	Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// Ensures the pool was created by the factory and hasn't been deprecated.
+fn ensure_factory_pool(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	config: &LimitOrdersConfig,
+	pool: &Addr,
+) -> Result<(), LimitOrdersContractError> {
+	let pair: Option<PoolFactoryCreatedPair> = querier.query_wasm_smart(
+		Addr::try_from(config.factory)?,
+		&PoolFactoryQueryMsg::PairByAddr { address: pool.clone() },
+	)?;
+	if !pair.is_some_and(|pair| !pair.deprecated) {
+		return Err(LimitOrdersContractError::UnknownPool(pool.to_string()));
+	}
+	Ok(())
+}
+
+fn process_place_order(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	pool: Addr,
+	limit_price: Decimal,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	let offer = one_coin(&msg_info)?;
+	let config = LimitOrdersConfig::load_non_empty()?;
+	ensure_factory_pool(&deps.querier, &config, &pool)?;
+	let pool = PoolPairRawState::query(&deps.querier, pool)?;
+	let ask_denom = pool
+		.pair_id
+		.other_denom(&offer.denom)
+		.ok_or(LimitOrdersContractError::OfferNotInPool)?
+		.to_owned();
+	let min_result = offer.amount.checked_mul_floor(limit_price)?;
+	if min_result.is_zero() {
+		return Err(LimitOrdersContractError::ExpectingNothing);
+	}
+
+	let owner_orders = get_owner_orders_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut owner_order_ids = owner_orders
+		.get(&owner_addr)?
+		.map(|order_ids| order_ids.into_inner())
+		.unwrap_or_default();
+	if owner_order_ids.order_ids.len() >= MAX_ORDERS_PER_OWNER {
+		return Err(LimitOrdersContractError::TooManyOrders);
+	}
+
+	let mut state = LimitOrdersState::load_non_empty()?;
+	let order_id = state.next_order_id;
+	state.next_order_id += 1;
+	state.save()?;
+
+	get_orders_store().set(
+		&order_id.into(),
+		&LimitOrder {
+			owner: msg_info.sender.to_string(),
+			pool: pool.address.to_string(),
+			offer_denom: offer.denom.clone(),
+			offer_amount: offer.amount.u128(),
+			ask_denom: ask_denom.clone(),
+			min_result: min_result.u128(),
+			keeper_bounty_bps: config.keeper_bounty_bps,
+			created_at: env.block.time.seconds(),
+		},
+	)?;
+	owner_order_ids.order_ids.push(order_id);
+	owner_orders.set(&owner_addr, &owner_order_ids)?;
+
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "place_order"),
+		attr("order_id", order_id.to_string()),
+		attr("owner", msg_info.sender),
+		attr("pool", pool.address),
+		attr("offer", offer.to_string()),
+		attr("min_result", coin(min_result.u128(), ask_denom).to_string()),
+	]))
+}
+
+/// Removes the order from storage, including the owner index
+fn remove_order(order_id: u64, order: &LimitOrder) -> Result<(), LimitOrdersContractError> {
+	get_orders_store().remove(&order_id.into());
+	let owner_orders = get_owner_orders_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&Addr::unchecked(&order.owner))?;
+	let mut owner_order_ids = owner_orders
+		.get(&owner_addr)?
+		.map(|order_ids| order_ids.into_inner())
+		.unwrap_or_default();
+	owner_order_ids.order_ids.retain(|id| *id != order_id);
+	if owner_order_ids.order_ids.is_empty() {
+		owner_orders.remove(&owner_addr);
+	} else {
+		owner_orders.set(&owner_addr, &owner_order_ids)?;
+	}
+	Ok(())
+}
+
+fn load_order(order_id: u64) -> Result<LimitOrder, LimitOrdersContractError> {
+	Ok(get_orders_store()
+		.get(&order_id.into())?
+		.ok_or(LimitOrdersContractError::OrderNotFound(order_id))?
+		.into_inner())
+}
+
+fn process_cancel_order(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	order_id: u64,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	nonpayable(&msg_info)?;
+	let order = load_order(order_id)?;
+	if msg_info.sender.as_str() != order.owner {
+		return Err(CrownfiSwapsCommonError::Unauthorized("Only the order's owner can cancel it".into()).into());
+	}
+	remove_order(order_id, &order)?;
+
+	let refund = coin(order.offer_amount, order.offer_denom);
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "cancel_order"),
+			attr("order_id", order_id.to_string()),
+			attr("owner", order.owner.clone()),
+			attr("refund", refund.to_string()),
+		])
+		.add_message(BankMsg::Send {
+			to_address: order.owner,
+			amount: vec![refund],
+		}))
+}
+
+fn keeper_bounty(result_amount: Uint128, keeper_bounty_bps: u16) -> Result<Uint128, LimitOrdersContractError> {
+	Ok(result_amount.checked_multiply_ratio(keeper_bounty_bps, 10000u16)?)
+}
+
+/// Asks the order's pool what swapping the order's offer would result in.
+fn simulate_execution(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	order: &LimitOrder,
+) -> Result<(PoolPairCalcSwapResult, LimitOrderSimulateExecutionResponse), LimitOrdersContractError> {
+	let swap_result: PoolPairCalcSwapResult = querier.query_wasm_smart(
+		&order.pool,
+		&PoolPairQueryMsg::SimulateSwap {
+			offer: coin(order.offer_amount, &order.offer_denom),
+			referral_fee_bps: None,
+		},
+	)?;
+	let keeper_bounty = keeper_bounty(swap_result.result_amount, order.keeper_bounty_bps)?;
+	let result_amount = swap_result.result_amount - keeper_bounty;
+	Ok((
+		swap_result,
+		LimitOrderSimulateExecutionResponse {
+			executable: result_amount.u128() >= order.min_result,
+			result_amount,
+			keeper_bounty,
+		},
+	))
+}
+
+fn process_execute_order(
+	deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	order_id: u64,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	nonpayable(&msg_info)?;
+	if LimitOrderExecution::load()?.is_some() {
+		return Err(LimitOrdersContractError::AlreadyExecutingOrder);
+	}
+	let order = load_order(order_id)?;
+	let (swap_result, simulation) = simulate_execution(&deps.querier, &order)?;
+	if !simulation.executable {
+		return Err(LimitOrdersContractError::LimitPriceNotReached);
+	}
+	LimitOrderExecution {
+		keeper: (&msg_info.sender).try_into()?,
+		order_id,
+	}
+	.save()?;
+	// The pool's slippage check is against what it pays out before any fees taken from the result.
+	let expected_result = if swap_result.fee_on_input {
+		swap_result.result_amount
+	} else {
+		swap_result.result_amount + swap_result.total_fee_amount
+	};
+
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "execute_order"),
+			attr("order_id", order_id.to_string()),
+			attr("keeper", msg_info.sender),
+		])
+		.add_submessage(SubMsg {
+			id: ORDER_EXECUTED_REPLY_ID,
+			msg: CosmosMsg::from(WasmMsg::Execute {
+				contract_addr: order.pool,
+				msg: to_json_binary(&PoolPairExecuteMsg::Swap {
+					// The swap happens in the same transaction as the simulation, so the result must match exactly.
+					expected_result: Some(expected_result),
+					slippage_tolerance: Some(Decimal::zero()),
+					receiver: None, // self
+					receiver_payload: Some(to_json_binary(&LimitOrdersExecuteMsg::CompleteOrder {})?),
+					referral: None,
+				})?,
+				funds: vec![coin(order.offer_amount, order.offer_denom)],
+			}),
+			gas_limit: None,
+			reply_on: ReplyOn::Success,
+		}))
+}
+
+fn process_complete_order(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	let execution = LimitOrderExecution::load_non_empty()?;
+	let order = load_order(execution.order_id)?;
+	if msg_info.sender.as_str() != order.pool {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Only the order's pool can send the order's result".into()).into(),
+		);
+	}
+	let result_amount = must_pay(&msg_info, &order.ask_denom)?;
+	let keeper_bounty = keeper_bounty(result_amount, order.keeper_bounty_bps)?;
+	let result_amount = result_amount - keeper_bounty;
+	if result_amount.u128() < order.min_result {
+		return Err(LimitOrdersContractError::LimitPriceNotReached);
+	}
+	remove_order(execution.order_id, &order)?;
+	LimitOrderExecution::remove();
+
+	let keeper = Addr::try_from(execution.keeper)?;
+	let mut response = Response::new()
+		.add_attributes(vec![
+			attr("action", "complete_order"),
+			attr("order_id", execution.order_id.to_string()),
+			attr("owner", order.owner.clone()),
+			attr("keeper", keeper.clone()),
+			attr("result", coin(result_amount.u128(), &order.ask_denom).to_string()),
+			attr(
+				"keeper_bounty",
+				coin(keeper_bounty.u128(), &order.ask_denom).to_string(),
+			),
+		])
+		.add_message(BankMsg::Send {
+			to_address: order.owner,
+			amount: vec![coin(result_amount.u128(), &order.ask_denom)],
+		});
+	if !keeper_bounty.is_zero() {
+		response = response.add_message(BankMsg::Send {
+			to_address: keeper.into_string(),
+			amount: vec![coin(keeper_bounty.u128(), order.ask_denom)],
+		});
+	}
+	Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn reply(
+	_deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg: Reply,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	match msg.id {
+		ORDER_EXECUTED_REPLY_ID => {
+			if let SubMsgResult::Err(err_msg) = msg.result {
+				return Err(LimitOrdersContractError::FailedReply(ParseReplyError::SubMsgFailure(
+					err_msg,
+				)));
+			}
+			if LimitOrderExecution::load()?.is_some() {
+				return Err(LimitOrdersContractError::IncompleteExecution);
+			}
+			Ok(Response::new())
+		}
+		_ => Err(LimitOrdersContractError::FailedReply(ParseReplyError::ParseFailure(
+			format!("Reply ID {0} is unknown", msg.id),
+		))),
+	}
+}
+
+fn order_info(order_id: u64, order: LimitOrder) -> LimitOrderInfo {
+	LimitOrderInfo {
+		order_id,
+		owner: Addr::unchecked(order.owner),
+		pool: Addr::unchecked(order.pool),
+		offer: coin(order.offer_amount, order.offer_denom),
+		ask_denom: order.ask_denom,
+		min_result: order.min_result.into(),
+		keeper_bounty_bps: order.keeper_bounty_bps,
+		created_at: order.created_at,
+	}
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(
+	deps: Deps<SeiQueryWrapper>,
+	_env: Env,
+	msg: LimitOrdersQueryMsg,
+) -> Result<Binary, LimitOrdersContractError> {
+	Ok(match msg {
+		LimitOrdersQueryMsg::Config => to_json_binary(&LimitOrdersConfigJsonable::try_from(
+			LimitOrdersConfig::load_non_empty()?.as_ref(),
+		)?)?,
+		LimitOrdersQueryMsg::Order { order_id } => to_json_binary(
+			&get_orders_store()
+				.get(&order_id.into())?
+				.map(|order| order_info(order_id, order.into_inner())),
+		)?,
+		LimitOrdersQueryMsg::Orders { after, limit } => to_json_binary(
+			&get_orders_store()
+				.iter_range(after.map(LimitOrderKey::from), None)?
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.map(|(order_id, order)| order_info(order_id.into(), order.into_inner()))
+				.collect::<Vec<_>>(),
+		)?,
+		LimitOrdersQueryMsg::OrdersByOwner { owner } => {
+			let owner_order_ids = get_owner_orders_store()
+				.get(&(&owner).try_into()?)?
+				.map(|order_ids| order_ids.into_inner())
+				.unwrap_or_default();
+			to_json_binary(
+				&owner_order_ids
+					.order_ids
+					.into_iter()
+					.map(|order_id| Ok(order_info(order_id, load_order(order_id)?)))
+					.collect::<Result<Vec<_>, LimitOrdersContractError>>()?,
+			)?
+		}
+		LimitOrdersQueryMsg::SimulateExecution { order_id } => {
+			to_json_binary(&simulate_execution(&deps.querier, &load_order(order_id)?)?.1)?
+		}
+	})
+}
@@ -0,0 +1,38 @@
+use cosmwasm_std::StdError;
+use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_std_error_common};
+use cw_utils::{ParseReplyError, PaymentError};
+use thiserror::Error;
+
+use crate::{contract::MAX_ORDERS_PER_OWNER, state::MAX_KEEPER_BOUNTY_BPS};
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LimitOrdersContractError {
+	#[error("StdError: {0}")]
+	Std(#[from] StdError),
+	#[error("{0}")]
+	SwapsCommon(#[from] CrownfiSwapsCommonError),
+	#[error("Payment error: {0}")]
+	PaymentError(#[from] PaymentError),
+	#[error("Failed reply: {0}")]
+	FailedReply(#[from] ParseReplyError),
+	#[error("Keeper bounty cannot exceed {} bps", MAX_KEEPER_BOUNTY_BPS)]
+	InvalidKeeperBounty,
+	#[error("{0} is not an active pool created by the factory")]
+	UnknownPool(String),
+	#[error("The specified pool does not trade the offered denom")]
+	OfferNotInPool,
+	#[error("Refusing to place an order where it's expected to lose everything")]
+	ExpectingNothing,
+	#[error("Accounts cannot have more than {} open orders", MAX_ORDERS_PER_OWNER)]
+	TooManyOrders,
+	#[error("Order {0} does not exist")]
+	OrderNotFound(u64),
+	#[error("The order's limit price has not been reached")]
+	LimitPriceNotReached,
+	#[error("Cannot execute an order while the previous one is incomplete")]
+	AlreadyExecutingOrder,
+	#[error("Order was not fully executed")]
+	IncompleteExecution,
+}
+
+impl_from_cosmwasm_std_error_common!(LimitOrdersContractError);
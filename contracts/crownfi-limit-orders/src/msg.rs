@@ -0,0 +1,87 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+
+use crate::state::LimitOrdersConfigJsonable;
+
+/// This structure stores the basic settings for creating a new limit orders contract.
+#[cw_serde]
+pub struct LimitOrdersInstantiateMsg {
+	pub config: LimitOrdersConfigJsonable,
+}
+
+/// This structure describes the execute messages of the contract.
+#[cw_serde]
+pub enum LimitOrdersExecuteMsg {
+	/// Updates the contract's config
+	UpdateConfig {
+		/// The head honcho
+		admin: Option<Addr>,
+		/// The pool factory, orders can only be placed on pools it has created
+		factory: Option<Addr>,
+		/// The portion (in bps) of an order's result which is given to the keeper who executed it.
+		keeper_bounty_bps: Option<u16>,
+	},
+	/// Places an order which swaps the coin sent along with this message once the pool's price reaches the limit
+	/// price. The coin is held by this contract until the order is executed or cancelled.
+	PlaceOrder {
+		/// The pool to swap with, which must have been created by the factory and not be deprecated
+		pool: Addr,
+		/// The minimum amount of the other asset to receive per unit of the coin sent, after the keeper's bounty is
+		/// taken.
+		limit_price: Decimal,
+	},
+	/// Cancels an order and refunds the escrowed coin. Only the order's owner may do this.
+	CancelOrder { order_id: u64 },
+	/// Executes an order if `SimulateSwap` on its pool meets its limit price. Anyone can do this, and they will
+	/// receive the keeper bounty.
+	ExecuteOrder { order_id: u64 },
+	/// Used internally to receive the result of an order's swap.
+	CompleteOrder {},
+}
+
+/// This structure describes the available query messages for the limit orders contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LimitOrdersQueryMsg {
+	/// Returns the contract's config.
+	#[returns(LimitOrdersConfigJsonable)]
+	Config,
+	/// Returns the specified order, if it's still open.
+	#[returns(Option<LimitOrderInfo>)]
+	Order { order_id: u64 },
+	/// Returns all open orders, in the order they were placed.
+	#[returns(Vec<LimitOrderInfo>)]
+	Orders { after: Option<u64>, limit: Option<u32> },
+	/// Returns the specified account's open orders, in the order they were placed.
+	#[returns(Vec<LimitOrderInfo>)]
+	OrdersByOwner { owner: Addr },
+	/// Tells you whether or not the specified order can currently be executed, and what executing it would result
+	/// in.
+	#[returns(LimitOrderSimulateExecutionResponse)]
+	SimulateExecution { order_id: u64 },
+}
+
+#[cw_serde]
+pub struct LimitOrderInfo {
+	pub order_id: u64,
+	pub owner: Addr,
+	pub pool: Addr,
+	/// The escrowed coin
+	pub offer: Coin,
+	pub ask_denom: String,
+	/// The minimum amount the owner will receive, i.e. `offer.amount * limit_price`
+	pub min_result: Uint128,
+	pub keeper_bounty_bps: u16,
+	/// Unix timestamp (in seconds) of when the order was placed
+	pub created_at: u64,
+}
+
+#[cw_serde]
+pub struct LimitOrderSimulateExecutionResponse {
+	/// True if the limit price has been reached
+	pub executable: bool,
+	/// The amount the owner would receive
+	pub result_amount: Uint128,
+	/// The amount the keeper would receive
+	pub keeper_bounty: Uint128,
+}
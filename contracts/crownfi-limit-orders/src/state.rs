@@ -0,0 +1,192 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use cosmwasm_std::{Addr, StdError};
+use crownfi_cw_common::{
+	data_types::canonical_addr::SeiCanonicalAddr,
+	impl_serializable_as_ref, impl_serializable_borsh,
+	storage::{item::StoredItem, map::StoredMap, OZeroCopy, SerializableItem},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Keepers cannot be given more than 10% of an order's result
+pub const MAX_KEEPER_BOUNTY_BPS: u16 = 1000;
+
+pub const CONFIG_NAMESPACE: &str = "app_cfg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct LimitOrdersConfig {
+	/// The head honcho
+	pub admin: SeiCanonicalAddr,
+	/// The pool factory, orders can only be placed on pools it has created
+	pub factory: SeiCanonicalAddr,
+	/// The portion (in bps) of an order's result which is given to the keeper who executed it
+	pub keeper_bounty_bps: u16,
+	_unused: [u8; 6],
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct LimitOrdersConfigJsonable {
+	/// The head honcho
+	pub admin: Addr,
+	/// The pool factory, orders can only be placed on pools it has created
+	pub factory: Addr,
+	/// The portion (in bps) of an order's result which is given to the keeper who executed it. This only applies to
+	/// orders placed after it was set.
+	pub keeper_bounty_bps: u16,
+}
+
+impl_serializable_as_ref!(LimitOrdersConfig);
+impl StoredItem for LimitOrdersConfig {
+	fn namespace() -> &'static [u8] {
+		CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl LimitOrdersConfig {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "LimitOrdersConfig".into(),
+			}),
+		}
+	}
+	pub fn valid_bounty_bps(&self) -> bool {
+		self.keeper_bounty_bps <= MAX_KEEPER_BOUNTY_BPS
+	}
+}
+impl TryFrom<&LimitOrdersConfigJsonable> for LimitOrdersConfig {
+	type Error = StdError;
+	fn try_from(value: &LimitOrdersConfigJsonable) -> Result<Self, Self::Error> {
+		Ok(LimitOrdersConfig {
+			admin: (&value.admin).try_into()?,
+			factory: (&value.factory).try_into()?,
+			keeper_bounty_bps: value.keeper_bounty_bps,
+			_unused: Zeroable::zeroed(),
+		})
+	}
+}
+impl TryFrom<&LimitOrdersConfig> for LimitOrdersConfigJsonable {
+	type Error = StdError;
+	fn try_from(value: &LimitOrdersConfig) -> Result<Self, Self::Error> {
+		Ok(LimitOrdersConfigJsonable {
+			admin: value.admin.try_into()?,
+			factory: value.factory.try_into()?,
+			keeper_bounty_bps: value.keeper_bounty_bps,
+		})
+	}
+}
+
+pub const STATE_NAMESPACE: &str = "state";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct LimitOrdersState {
+	/// The ID which will be given to the next order placed. IDs start at 1.
+	pub next_order_id: u64,
+}
+impl_serializable_as_ref!(LimitOrdersState);
+impl StoredItem for LimitOrdersState {
+	fn namespace() -> &'static [u8] {
+		STATE_NAMESPACE.as_bytes()
+	}
+}
+impl LimitOrdersState {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "LimitOrdersState".into(),
+			}),
+		}
+	}
+}
+
+pub const EXECUTION_NAMESPACE: &str = "executing";
+
+/// Exists only while an order's swap is being executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct LimitOrderExecution {
+	/// The account which executed the order, and will receive the bounty
+	pub keeper: SeiCanonicalAddr,
+	pub order_id: u64,
+}
+impl_serializable_as_ref!(LimitOrderExecution);
+impl StoredItem for LimitOrderExecution {
+	fn namespace() -> &'static [u8] {
+		EXECUTION_NAMESPACE.as_bytes()
+	}
+}
+impl LimitOrderExecution {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "LimitOrderExecution".into(),
+			}),
+		}
+	}
+}
+
+/// Order IDs as stored, big-endian so orders are iterated in the order they were placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(transparent)]
+pub struct LimitOrderKey([u8; 8]);
+impl_serializable_as_ref!(LimitOrderKey);
+impl From<u64> for LimitOrderKey {
+	fn from(value: u64) -> Self {
+		Self(value.to_be_bytes())
+	}
+}
+impl From<LimitOrderKey> for u64 {
+	fn from(value: LimitOrderKey) -> Self {
+		u64::from_be_bytes(value.0)
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct LimitOrder {
+	pub owner: String,
+	/// The pool the order will be executed against
+	pub pool: String,
+	/// The escrowed coin which will be swapped
+	pub offer_denom: String,
+	pub offer_amount: u128,
+	/// The denom the owner will receive
+	pub ask_denom: String,
+	/// The minimum amount the owner must receive, after the keeper's bounty is taken.
+	pub min_result: u128,
+	/// The keeper bounty at the time the order was placed
+	pub keeper_bounty_bps: u16,
+	/// Unix timestamp (in seconds) of when the order was placed
+	pub created_at: u64,
+}
+impl_serializable_borsh!(LimitOrder);
+
+const ORDERS_NAMESPACE: &str = "orders";
+pub fn get_orders_store() -> StoredMap<LimitOrderKey, LimitOrder> {
+	StoredMap::new(ORDERS_NAMESPACE.as_ref())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct LimitOrderIds {
+	pub order_ids: Vec<u64>,
+}
+impl_serializable_borsh!(LimitOrderIds);
+
+const OWNER_ORDERS_NAMESPACE: &str = "owner_orders";
+/// The IDs of each account's open orders, in the order they were placed.
+pub fn get_owner_orders_store() -> StoredMap<SeiCanonicalAddr, LimitOrderIds> {
+	StoredMap::new(OWNER_ORDERS_NAMESPACE.as_ref())
+}
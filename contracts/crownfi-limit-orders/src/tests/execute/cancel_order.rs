@@ -0,0 +1,88 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, BankMsg, SubMsg,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{execute, query},
+	error::LimitOrdersContractError,
+	msg::{LimitOrderInfo, LimitOrdersExecuteMsg, LimitOrdersQueryMsg},
+	tests::{deps, init, place_order, AddressFactory, PAIR_DENOMS},
+};
+
+#[test]
+fn refunds_the_owner() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::OWNER, &[]),
+		LimitOrdersExecuteMsg::CancelOrder { order_id: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::OWNER.into(),
+			amount: vec![coin(1000, PAIR_DENOMS[0])]
+		})]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "cancel_order"),
+			attr("order_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr("refund", coin(1000, PAIR_DENOMS[0]).to_string()),
+		]
+	);
+
+	let order: Option<LimitOrderInfo> =
+		from_json(query(deps.as_ref(), mock_env(), LimitOrdersQueryMsg::Order { order_id: 1 }).unwrap()).unwrap();
+	assert_eq!(order, None);
+	let owner_orders: Vec<LimitOrderInfo> = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			LimitOrdersQueryMsg::OrdersByOwner {
+				owner: Addr::unchecked(AddressFactory::OWNER),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(owner_orders, vec![]);
+
+	// Can't be refunded twice
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::OWNER, &[]),
+			LimitOrdersExecuteMsg::CancelOrder { order_id: 1 },
+		),
+		Err(LimitOrdersContractError::OrderNotFound(1))
+	);
+}
+
+#[test]
+fn only_the_owner_can_cancel() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::KEEPER, &[]),
+			LimitOrdersExecuteMsg::CancelOrder { order_id: 1 },
+		),
+		Err(CrownfiSwapsCommonError::Unauthorized("Only the order's owner can cancel it".into()).into())
+	);
+}
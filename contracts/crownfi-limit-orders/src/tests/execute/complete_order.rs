@@ -0,0 +1,142 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	BankMsg, Reply, Response, SubMsg, SubMsgResponse, SubMsgResult,
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use cw_utils::ParseReplyError;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::{execute, query, reply, ORDER_EXECUTED_REPLY_ID},
+	error::LimitOrdersContractError,
+	msg::{LimitOrderInfo, LimitOrdersExecuteMsg, LimitOrdersQueryMsg},
+	state::LimitOrderExecution,
+	tests::{deps, init, place_order, set_swap_result, AddressFactory, TestDeps, PAIR_DENOMS},
+};
+
+use super::execute_order::execute_order;
+
+fn order_executed_reply(result: SubMsgResult) -> Reply {
+	Reply {
+		id: ORDER_EXECUTED_REPLY_ID,
+		result,
+	}
+}
+
+fn swap_succeeded() -> SubMsgResult {
+	SubMsgResult::Ok(SubMsgResponse {
+		events: vec![],
+		data: None,
+	})
+}
+
+/// The pool sending the swap's result along with the `receiver_payload`
+fn complete_order(
+	deps: &mut TestDeps,
+	sender: &str,
+	result_amount: u128,
+) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[coin(result_amount, PAIR_DENOMS[1])]),
+		LimitOrdersExecuteMsg::CompleteOrder {},
+	)
+}
+
+#[test]
+fn splits_the_result_with_the_keeper() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+	set_swap_result(&mut deps, 3030);
+	execute_order(&mut deps).unwrap();
+
+	let res = complete_order(&mut deps, AddressFactory::POOL, 3030).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![
+			SubMsg::new(BankMsg::Send {
+				to_address: AddressFactory::OWNER.into(),
+				amount: vec![coin(3000, PAIR_DENOMS[1])]
+			}),
+			SubMsg::new(BankMsg::Send {
+				to_address: AddressFactory::KEEPER.into(),
+				amount: vec![coin(30, PAIR_DENOMS[1])]
+			}),
+		]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "complete_order"),
+			attr("order_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr("keeper", AddressFactory::KEEPER),
+			attr("result", coin(3000, PAIR_DENOMS[1]).to_string()),
+			attr("keeper_bounty", coin(30, PAIR_DENOMS[1]).to_string()),
+		]
+	);
+
+	// The order is gone, and the contract is ready for the next one
+	let order: Option<LimitOrderInfo> =
+		from_json(query(deps.as_ref(), mock_env(), LimitOrdersQueryMsg::Order { order_id: 1 }).unwrap()).unwrap();
+	assert_eq!(order, None);
+	assert!(LimitOrderExecution::load().unwrap().is_none());
+	reply(deps.as_mut(), mock_env(), order_executed_reply(swap_succeeded())).unwrap();
+}
+
+#[test]
+fn only_the_pool_can_complete() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+	set_swap_result(&mut deps, 3030);
+	execute_order(&mut deps).unwrap();
+
+	assert_eq!(
+		complete_order(&mut deps, AddressFactory::KEEPER, 3030),
+		Err(CrownfiSwapsCommonError::Unauthorized("Only the order's pool can send the order's result".into()).into())
+	);
+}
+
+#[test]
+fn result_must_meet_the_limit() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+	set_swap_result(&mut deps, 2020);
+	execute_order(&mut deps).unwrap();
+
+	// 1999cba left after the 20cba bounty
+	assert_eq!(
+		complete_order(&mut deps, AddressFactory::POOL, 2019),
+		Err(LimitOrdersContractError::LimitPriceNotReached)
+	);
+}
+
+#[test]
+fn reply_fails_if_the_order_was_not_completed() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+	set_swap_result(&mut deps, 3030);
+	execute_order(&mut deps).unwrap();
+
+	assert_eq!(
+		reply(deps.as_mut(), mock_env(), order_executed_reply(swap_succeeded())),
+		Err(LimitOrdersContractError::IncompleteExecution)
+	);
+	assert_eq!(
+		reply(
+			deps.as_mut(),
+			mock_env(),
+			order_executed_reply(SubMsgResult::Err("out of gas".into()))
+		),
+		Err(LimitOrdersContractError::FailedReply(ParseReplyError::SubMsgFailure(
+			"out of gas".into()
+		)))
+	);
+}
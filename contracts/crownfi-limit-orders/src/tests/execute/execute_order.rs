@@ -0,0 +1,141 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	to_json_binary, Decimal, ReplyOn, Response, Uint128, WasmMsg,
+};
+use crownfi_pool_pair_contract::msg::PoolPairExecuteMsg;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::{execute, query, ORDER_EXECUTED_REPLY_ID},
+	error::LimitOrdersContractError,
+	msg::{LimitOrderSimulateExecutionResponse, LimitOrdersExecuteMsg, LimitOrdersQueryMsg},
+	tests::{deps, init, place_order, set_swap_result, AddressFactory, TestDeps, PAIR_DENOMS},
+};
+
+pub(super) fn execute_order(deps: &mut TestDeps) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::KEEPER, &[]),
+		LimitOrdersExecuteMsg::ExecuteOrder { order_id: 1 },
+	)
+}
+
+fn simulate_execution(deps: &TestDeps) -> LimitOrderSimulateExecutionResponse {
+	from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			LimitOrdersQueryMsg::SimulateExecution { order_id: 1 },
+		)
+		.unwrap(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn fails_below_the_limit() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+
+	// 1999cba left after the 20cba bounty
+	set_swap_result(&mut deps, 2019);
+	assert_eq!(
+		simulate_execution(&deps),
+		LimitOrderSimulateExecutionResponse {
+			executable: false,
+			result_amount: Uint128::new(1999),
+			keeper_bounty: Uint128::new(20),
+		}
+	);
+	assert_eq!(
+		execute_order(&mut deps),
+		Err(LimitOrdersContractError::LimitPriceNotReached)
+	);
+}
+
+#[test]
+fn swaps_at_the_limit() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+
+	// Exactly 2000cba left after the 20cba bounty
+	set_swap_result(&mut deps, 2020);
+	assert_eq!(
+		simulate_execution(&deps),
+		LimitOrderSimulateExecutionResponse {
+			executable: true,
+			result_amount: Uint128::new(2000),
+			keeper_bounty: Uint128::new(20),
+		}
+	);
+	let res = execute_order(&mut deps).unwrap();
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "execute_order"),
+			attr("order_id", "1"),
+			attr("keeper", AddressFactory::KEEPER),
+		]
+	);
+	assert_eq!(res.messages.len(), 1);
+	assert_eq!(res.messages[0].id, ORDER_EXECUTED_REPLY_ID);
+	assert_eq!(res.messages[0].reply_on, ReplyOn::Success);
+	assert_eq!(
+		res.messages[0].msg,
+		WasmMsg::Execute {
+			contract_addr: AddressFactory::POOL.into(),
+			msg: to_json_binary(&PoolPairExecuteMsg::Swap {
+				expected_result: Some(Uint128::new(2020)),
+				slippage_tolerance: Some(Decimal::zero()),
+				receiver: None,
+				receiver_payload: Some(to_json_binary(&LimitOrdersExecuteMsg::CompleteOrder {}).unwrap()),
+				referral: None,
+			})
+			.unwrap(),
+			funds: vec![coin(1000, PAIR_DENOMS[0])],
+		}
+		.into()
+	);
+}
+
+#[test]
+fn swaps_above_the_limit() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+
+	set_swap_result(&mut deps, 3030);
+	assert_eq!(
+		simulate_execution(&deps),
+		LimitOrderSimulateExecutionResponse {
+			executable: true,
+			result_amount: Uint128::new(3000),
+			keeper_bounty: Uint128::new(30),
+		}
+	);
+	execute_order(&mut deps).unwrap();
+}
+
+#[test]
+fn one_order_at_a_time() {
+	let mut deps = deps();
+	init(&mut deps);
+	place_order(&mut deps).unwrap();
+	place_order(&mut deps).unwrap();
+
+	set_swap_result(&mut deps, 3030);
+	execute_order(&mut deps).unwrap();
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::KEEPER, &[]),
+			LimitOrdersExecuteMsg::ExecuteOrder { order_id: 2 },
+		),
+		Err(LimitOrdersContractError::AlreadyExecutingOrder)
+	);
+}
@@ -0,0 +1,4 @@
+mod cancel_order;
+mod complete_order;
+mod execute_order;
+mod place_order;
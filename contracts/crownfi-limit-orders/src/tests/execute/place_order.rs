@@ -0,0 +1,103 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, Decimal, Uint128,
+};
+
+use crate::{
+	contract::{execute, query},
+	error::LimitOrdersContractError,
+	msg::{LimitOrderInfo, LimitOrdersExecuteMsg, LimitOrdersQueryMsg},
+	tests::{deps, init, place_order, AddressFactory, KEEPER_BOUNTY_BPS, PAIR_DENOMS},
+};
+
+#[test]
+fn escrows_the_offer() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	let res = place_order(&mut deps).unwrap();
+	// The offer stays with this contract until the order is executed or cancelled
+	assert_eq!(res.messages, vec![]);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "place_order"),
+			attr("order_id", "1"),
+			attr("owner", AddressFactory::OWNER),
+			attr("pool", AddressFactory::POOL),
+			attr("offer", coin(1000, PAIR_DENOMS[0]).to_string()),
+			attr("min_result", coin(2000, PAIR_DENOMS[1]).to_string()),
+		]
+	);
+
+	let expected_order = LimitOrderInfo {
+		order_id: 1,
+		owner: Addr::unchecked(AddressFactory::OWNER),
+		pool: Addr::unchecked(AddressFactory::POOL),
+		offer: coin(1000, PAIR_DENOMS[0]),
+		ask_denom: PAIR_DENOMS[1].into(),
+		min_result: Uint128::new(2000),
+		keeper_bounty_bps: KEEPER_BOUNTY_BPS,
+		created_at: mock_env().block.time.seconds(),
+	};
+	let order: Option<LimitOrderInfo> =
+		from_json(query(deps.as_ref(), mock_env(), LimitOrdersQueryMsg::Order { order_id: 1 }).unwrap()).unwrap();
+	assert_eq!(order, Some(expected_order.clone()));
+	let owner_orders: Vec<LimitOrderInfo> = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			LimitOrdersQueryMsg::OrdersByOwner {
+				owner: Addr::unchecked(AddressFactory::OWNER),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(owner_orders, vec![expected_order]);
+
+	// IDs keep going up
+	let res = place_order(&mut deps).unwrap();
+	assert!(res.attributes.contains(&attr("order_id", "2")));
+}
+
+#[test]
+fn only_accepts_active_factory_pools() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	for pool in [AddressFactory::DEPRECATED_POOL, AddressFactory::KEEPER] {
+		assert_eq!(
+			execute(
+				deps.as_mut(),
+				mock_env(),
+				mock_info(AddressFactory::OWNER, &[coin(1000, PAIR_DENOMS[0])]),
+				LimitOrdersExecuteMsg::PlaceOrder {
+					pool: Addr::unchecked(pool),
+					limit_price: Decimal::from_ratio(2u8, 1u8),
+				},
+			),
+			Err(LimitOrdersContractError::UnknownPool(pool.into()))
+		);
+	}
+}
+
+#[test]
+fn offer_must_be_in_the_pool() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::OWNER, &[coin(1000, "xyz")]),
+			LimitOrdersExecuteMsg::PlaceOrder {
+				pool: Addr::unchecked(AddressFactory::POOL),
+				limit_price: Decimal::from_ratio(2u8, 1u8),
+			},
+		),
+		Err(LimitOrdersContractError::OfferNotInPool)
+	);
+}
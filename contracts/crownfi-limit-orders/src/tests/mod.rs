@@ -0,0 +1,135 @@
+use bytemuck::Zeroable;
+use cosmwasm_std::{
+	coin, from_json, testing::*, to_json_binary, Addr, Binary, ContractResult, Decimal, MemoryStorage, OwnedDeps,
+	Response, SystemError, SystemResult, Uint128, WasmQuery,
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_pool_factory_contract::msg::{PoolFactoryCreatedPair, PoolFactoryQueryMsg};
+use crownfi_pool_pair_contract::{
+	msg::{PoolPairCalcSwapResult, PoolPairQueryMsg},
+	state::{PoolPairConfig, PoolPairReserves},
+};
+use crownfi_swaps_common::data_types::pair_id::CanonicalPoolPairIdentifier;
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::contract::*;
+use crate::error::*;
+use crate::msg::*;
+use crate::state::*;
+
+mod execute;
+
+const PAIR_DENOMS: [&str; 2] = ["abc", "cba"];
+/// 1% of each order's result
+const KEEPER_BOUNTY_BPS: u16 = 100;
+
+type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier<SeiQueryWrapper>, SeiQueryWrapper>;
+
+enum AddressFactory {}
+impl AddressFactory {
+	/// supposed to be used as the contract owner/fee receiver etc
+	pub(crate) const ADMIN: &'static str = "sei1zgfgerl8qt9uldlr0y9w7qe97p7zyv5kwg2pge";
+	pub(crate) const FACTORY: &'static str = "sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a";
+	/// A pool created by the factory, trading `PAIR_DENOMS`
+	pub(crate) const POOL: &'static str = "sei1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygskw0yqp";
+	/// A pool which the factory has deprecated
+	pub(crate) const DEPRECATED_POOL: &'static str = "sei1242424242424242424242424242424242424242424242424242ss7lj9n";
+	pub(crate) const OWNER: &'static str = "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue";
+	pub(crate) const KEEPER: &'static str = "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft";
+}
+
+fn deps() -> TestDeps {
+	let mem = Box::new(MockStorage::default());
+	let mem_ptr = Box::leak(mem) as *mut MockStorage;
+	crownfi_cw_common::storage::base::set_global_storage(unsafe { Box::from_raw(mem_ptr) });
+
+	let mut deps = OwnedDeps {
+		querier: MockQuerier::<SeiQueryWrapper>::new(&[]),
+		storage: unsafe { mem_ptr.read() },
+		api: MockApi::default(),
+		custom_query_type: Default::default(),
+	};
+	set_swap_result(&mut deps, 0);
+	deps
+}
+
+/// Mocks the factory and its pools, with `SimulateSwap` on `POOL` resulting in the specified amount.
+fn set_swap_result(deps: &mut TestDeps, result_amount: u128) {
+	deps.querier.update_wasm(move |query| match query {
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == AddressFactory::FACTORY => {
+			let PoolFactoryQueryMsg::PairByAddr { address } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			let pair = [AddressFactory::POOL, AddressFactory::DEPRECATED_POOL]
+				.contains(&address.as_str())
+				.then(|| PoolFactoryCreatedPair {
+					canonical_pair: PAIR_DENOMS.map(String::from),
+					address: address.clone(),
+					deprecated: address == AddressFactory::DEPRECATED_POOL,
+				});
+			SystemResult::Ok(ContractResult::Ok(to_json_binary(&pair).unwrap()))
+		}
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == AddressFactory::POOL => {
+			let PoolPairQueryMsg::SimulateSwap { .. } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			SystemResult::Ok(ContractResult::Ok(
+				to_json_binary(&PoolPairCalcSwapResult {
+					result_amount: Uint128::new(result_amount),
+					spread_amount: Uint128::zero(),
+					total_fee_amount: Uint128::zero(),
+					maker_fee_amount: Uint128::zero(),
+					fee_on_input: false,
+					referral_fee_amount: Uint128::zero(),
+				})
+				.unwrap(),
+			))
+		}
+		WasmQuery::Raw { contract_addr, key } if contract_addr == AddressFactory::POOL => {
+			let key = key.as_slice();
+			let value = if key == CanonicalPoolPairIdentifier::namespace() {
+				borsh::to_vec(&CanonicalPoolPairIdentifier::from(PAIR_DENOMS.map(String::from))).unwrap()
+			} else if key == PoolPairConfig::namespace() {
+				bytemuck::bytes_of(&PoolPairConfig::zeroed()).to_vec()
+			} else if key == PoolPairReserves::namespace() {
+				bytemuck::bytes_of(&PoolPairReserves {
+					amounts: [1000000, 1000000],
+				})
+				.to_vec()
+			} else {
+				vec![]
+			};
+			SystemResult::Ok(ContractResult::Ok(Binary::from(value)))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+}
+
+fn init(deps: &mut TestDeps) -> Response<SeiMsg> {
+	instantiate(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		LimitOrdersInstantiateMsg {
+			config: LimitOrdersConfigJsonable {
+				admin: Addr::unchecked(AddressFactory::ADMIN),
+				factory: Addr::unchecked(AddressFactory::FACTORY),
+				keeper_bounty_bps: KEEPER_BOUNTY_BPS,
+			},
+		},
+	)
+	.unwrap()
+}
+
+/// Has `OWNER` place an order swapping 1000abc for at least 2000cba
+fn place_order(deps: &mut TestDeps) -> Result<Response<SeiMsg>, LimitOrdersContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::OWNER, &[coin(1000, PAIR_DENOMS[0])]),
+		LimitOrdersExecuteMsg::PlaceOrder {
+			pool: Addr::unchecked(AddressFactory::POOL),
+			limit_price: Decimal::from_ratio(2u8, 1u8),
+		},
+	)
+}
@@ -0,0 +1,525 @@
+use bytemuck::Zeroable;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+	attr, coin, to_json_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, QuerierWrapper, Response,
+	StdError, Uint128,
+};
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use cw2::set_contract_version;
+use cw_utils::{nonpayable, one_coin};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+use serde::Deserialize;
+
+use crate::{
+	error::LpIncentivesContractError,
+	msg::{
+		LpIncentivesExecuteMsg, LpIncentivesInstantiateMsg, LpIncentivesPoolInfo, LpIncentivesPosition,
+		LpIncentivesQueryMsg, LpIncentivesRewardProgramInfo,
+	},
+	state::{
+		get_incentive_pools_store, get_staker_positions_store, IncentivePool, LpIncentivesConfig,
+		LpIncentivesConfigJsonable, RewardProgram,
+	},
+};
+
+use self::rewards::{
+	forfeit_position, pending_rewards, remove_finished_programs, settle_position, sync_position, take_pending_rewards,
+	update_pool_rewards, MAX_REWARD_PROGRAMS_PER_POOL,
+};
+
+pub mod rewards;
+
+const CONTRACT_NAME: &str = "crownfi-lp-incentives";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn instantiate(
+	deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+	msg: LpIncentivesInstantiateMsg,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	nonpayable(&msg_info)?;
+	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	LpIncentivesConfig::try_from(&msg.config)?.save()?;
+	Ok(Response::new())
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+#[inline]
+pub fn execute(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	msg: LpIncentivesExecuteMsg,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	match msg {
+		LpIncentivesExecuteMsg::UpdateConfig { admin, factory } => {
+			process_update_config(deps, msg_info, admin, factory)
+		}
+		LpIncentivesExecuteMsg::AddRewardProgram {
+			lp_denom,
+			start_time,
+			end_time,
+		} => process_add_reward_program(deps, env, msg_info, lp_denom, start_time, end_time),
+		LpIncentivesExecuteMsg::Stake { receiver } => process_stake(deps, env, msg_info, receiver),
+		LpIncentivesExecuteMsg::Unstake {
+			lp_denom,
+			amount,
+			receiver,
+		} => process_unstake(deps, env, msg_info, lp_denom, amount, receiver),
+		LpIncentivesExecuteMsg::EmergencyUnstake { lp_denom, receiver } => {
+			process_emergency_unstake(deps, msg_info, lp_denom, receiver)
+		}
+		LpIncentivesExecuteMsg::Claim { lp_denom, receiver } => process_claim(deps, env, msg_info, lp_denom, receiver),
+	}
+}
+
+fn process_update_config(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	admin: Option<Addr>,
+	factory: Option<Addr>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	nonpayable(&msg_info)?;
+	let mut config = LpIncentivesConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if let Some(admin) = admin {
+		config.admin = admin.try_into()?;
+	}
+	if let Some(factory) = factory {
+		config.factory = factory.try_into()?;
+	}
+	config.save()?;
+	Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+/// The factory's `PairByAddr` query, the factory can't be depended on as it depends on this contract.
+#[cw_serde]
+enum PoolFactoryQueryMsg {
+	PairByAddr { address: Addr },
+}
+/// The part of the factory's `PoolFactoryCreatedPair` which is used here
+#[derive(Deserialize)]
+struct PoolFactoryCreatedPair {
+	deprecated: bool,
+}
+
+/// Ensures the LP denom belongs to a pool the factory created and hasn't deprecated.
+fn ensure_factory_pool(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	config: &LpIncentivesConfig,
+	lp_denom: &str,
+) -> Result<(), LpIncentivesContractError> {
+	let unknown_pool = || LpIncentivesContractError::UnknownPool(lp_denom.to_string());
+	if config.factory == SeiCanonicalAddr::zeroed() {
+		return Err(unknown_pool());
+	}
+	let pool_addr = lp_denom
+		.strip_prefix("factory/")
+		.and_then(|lp_denom| lp_denom.strip_suffix("/lp"))
+		.ok_or_else(unknown_pool)?;
+	let pair: Option<PoolFactoryCreatedPair> = querier.query_wasm_smart(
+		Addr::try_from(config.factory)?,
+		&PoolFactoryQueryMsg::PairByAddr {
+			address: Addr::unchecked(pool_addr),
+		},
+	)?;
+	if !pair.is_some_and(|pair| !pair.deprecated) {
+		return Err(unknown_pool());
+	}
+	Ok(())
+}
+
+fn process_add_reward_program(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	lp_denom: String,
+	start_time: Option<u64>,
+	end_time: u64,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	let config = LpIncentivesConfig::load_non_empty()?;
+	let sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	if !config.can_manage_programs(&sender) {
+		return Err(CrownfiSwapsCommonError::Unauthorized(
+			"Only the admin or the factory can add reward programs".into(),
+		)
+		.into());
+	}
+	// The factory only registers programs for its own pools
+	if sender != config.factory {
+		ensure_factory_pool(&deps.querier, &config, &lp_denom)?;
+	}
+	let reward = one_coin(&msg_info)?;
+	let now = env.block.time.seconds();
+	// Rewards cannot be retroactively released
+	let start_time = start_time.unwrap_or(now).max(now);
+	if end_time <= start_time {
+		return Err(LpIncentivesContractError::InvalidSchedule);
+	}
+
+	let pools = get_incentive_pools_store();
+	let mut pool = pools.get(&lp_denom)?.map(|pool| pool.into_inner()).unwrap_or_default();
+	update_pool_rewards(&mut pool, now)?;
+	remove_finished_programs(&mut pool);
+	if pool.programs.len() >= MAX_REWARD_PROGRAMS_PER_POOL {
+		return Err(LpIncentivesContractError::TooManyRewardPrograms);
+	}
+	pool.programs.push(RewardProgram {
+		id: pool.next_program_id,
+		denom: reward.denom.clone(),
+		total_amount: reward.amount.u128(),
+		start_time,
+		end_time,
+		last_update: start_time,
+		// Everyone currently staked will be earning from this
+		unsettled_positions: pool.staker_count,
+		..Default::default()
+	});
+	pool.next_program_id += 1;
+	pools.set(&lp_denom, &pool)?;
+
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "add_reward_program"),
+		attr("lp_denom", lp_denom),
+		attr("reward", reward.to_string()),
+		attr("start_time", start_time.to_string()),
+		attr("end_time", end_time.to_string()),
+	]))
+}
+
+fn process_stake(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+	let stake = one_coin(&msg_info)?;
+
+	let pools = get_incentive_pools_store();
+	let mut pool = pools
+		.get(&stake.denom)?
+		.ok_or(LpIncentivesContractError::PoolNotRegistered)?
+		.into_inner();
+	update_pool_rewards(&mut pool, env.block.time.seconds())?;
+
+	let stakers = get_staker_positions_store();
+	let staker_addr = SeiCanonicalAddr::try_from(&receiver)?;
+	let mut staker = stakers
+		.get(&staker_addr)?
+		.map(|staker| staker.into_inner())
+		.unwrap_or_default();
+	let position = staker.position_mut(&stake.denom);
+	sync_position(&mut pool, position)?;
+	if position.amount == 0 {
+		pool.staker_count += 1;
+	}
+	position.amount = Uint128::new(position.amount).checked_add(stake.amount)?.u128();
+	pool.total_staked = Uint128::new(pool.total_staked).checked_add(stake.amount)?.u128();
+	settle_position(&mut pool, position);
+	remove_finished_programs(&mut pool);
+
+	pools.set(&stake.denom, &pool)?;
+	stakers.set(&staker_addr, &staker)?;
+
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "stake"),
+		attr("sender", msg_info.sender),
+		attr("receiver", receiver),
+		attr("amount", stake.to_string()),
+	]))
+}
+
+fn process_unstake(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	lp_denom: String,
+	amount: Option<Uint128>,
+	receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	nonpayable(&msg_info)?;
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+
+	let pools = get_incentive_pools_store();
+	let mut pool = pools
+		.get(&lp_denom)?
+		.ok_or(LpIncentivesContractError::PoolNotRegistered)?
+		.into_inner();
+	update_pool_rewards(&mut pool, env.block.time.seconds())?;
+
+	let stakers = get_staker_positions_store();
+	let staker_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut staker = stakers
+		.get(&staker_addr)?
+		.map(|staker| staker.into_inner())
+		.unwrap_or_default();
+	let position = staker.position_mut(&lp_denom);
+	let amount = amount.map(|amount| amount.u128()).unwrap_or(position.amount);
+	if amount == 0 {
+		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+	}
+	if amount > position.amount {
+		return Err(LpIncentivesContractError::InsufficientStake);
+	}
+	sync_position(&mut pool, position)?;
+	position.amount -= amount;
+	pool.total_staked -= amount;
+	if position.amount == 0 {
+		pool.staker_count -= 1;
+	}
+	settle_position(&mut pool, position);
+	remove_finished_programs(&mut pool);
+	staker.prune();
+
+	pools.set(&lp_denom, &pool)?;
+	if staker.positions.is_empty() {
+		stakers.remove(&staker_addr);
+	} else {
+		stakers.set(&staker_addr, &staker)?;
+	}
+
+	let unstaked = coin(amount, lp_denom);
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "unstake"),
+			attr("sender", msg_info.sender),
+			attr("receiver", receiver.clone()),
+			attr("amount", unstaked.to_string()),
+		])
+		.add_message(BankMsg::Send {
+			to_address: receiver.into_string(),
+			amount: vec![unstaked],
+		}))
+}
+
+fn process_emergency_unstake(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	lp_denom: String,
+	receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	nonpayable(&msg_info)?;
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+
+	let pools = get_incentive_pools_store();
+	let mut pool = pools
+		.get(&lp_denom)?
+		.ok_or(LpIncentivesContractError::PoolNotRegistered)?
+		.into_inner();
+
+	let stakers = get_staker_positions_store();
+	let staker_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut staker = stakers
+		.get(&staker_addr)?
+		.map(|staker| staker.into_inner())
+		.unwrap_or_default();
+	let position = staker.position_mut(&lp_denom);
+	let amount = position.amount;
+	if amount == 0 {
+		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+	}
+	// The pool's rewards aren't brought up to date, so what was released since then goes to the remaining stakers.
+	forfeit_position(&mut pool, position);
+	position.amount = 0;
+	pool.total_staked -= amount;
+	pool.staker_count -= 1;
+	remove_finished_programs(&mut pool);
+	staker.prune();
+
+	pools.set(&lp_denom, &pool)?;
+	if staker.positions.is_empty() {
+		stakers.remove(&staker_addr);
+	} else {
+		stakers.set(&staker_addr, &staker)?;
+	}
+
+	let unstaked = coin(amount, lp_denom);
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "emergency_unstake"),
+			attr("sender", msg_info.sender),
+			attr("receiver", receiver.clone()),
+			attr("amount", unstaked.to_string()),
+		])
+		.add_message(BankMsg::Send {
+			to_address: receiver.into_string(),
+			amount: vec![unstaked],
+		}))
+}
+
+fn process_claim(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	lp_denom: Option<String>,
+	receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	nonpayable(&msg_info)?;
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+	let now = env.block.time.seconds();
+
+	let pools = get_incentive_pools_store();
+	let stakers = get_staker_positions_store();
+	let staker_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut staker = stakers
+		.get(&staker_addr)?
+		.map(|staker| staker.into_inner())
+		.unwrap_or_default();
+
+	let mut claimed = cosmwasm_std::Coins::default();
+	for position in staker.positions.iter_mut().filter(|position| {
+		lp_denom
+			.as_ref()
+			.map_or(true, |lp_denom| *lp_denom == position.lp_denom)
+	}) {
+		let mut pool = pools
+			.get(&position.lp_denom)?
+			.ok_or(StdError::not_found("incentive pool"))?
+			.into_inner();
+		update_pool_rewards(&mut pool, now)?;
+		sync_position(&mut pool, position)?;
+		for reward in take_pending_rewards(&pool, position)?.into_vec() {
+			claimed.add(reward)?;
+		}
+		settle_position(&mut pool, position);
+		remove_finished_programs(&mut pool);
+		pools.set(&position.lp_denom, &pool)?;
+	}
+	if claimed.is_empty() {
+		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+	}
+	staker.prune();
+	if staker.positions.is_empty() {
+		stakers.remove(&staker_addr);
+	} else {
+		stakers.set(&staker_addr, &staker)?;
+	}
+
+	let claimed = claimed.into_vec();
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "claim"),
+			attr("sender", msg_info.sender),
+			attr("receiver", receiver.clone()),
+			attr(
+				"rewards",
+				claimed
+					.iter()
+					.map(|coin| coin.to_string())
+					.collect::<Vec<_>>()
+					.join(", "),
+			),
+		])
+		.add_message(BankMsg::Send {
+			to_address: receiver.into_string(),
+			amount: claimed,
+		}))
+}
+
+fn pool_info(lp_denom: String, pool: &IncentivePool) -> LpIncentivesPoolInfo {
+	LpIncentivesPoolInfo {
+		lp_denom,
+		total_staked: pool.total_staked.into(),
+		programs: pool
+			.programs
+			.iter()
+			.map(|program| LpIncentivesRewardProgramInfo {
+				denom: program.denom.clone(),
+				total_amount: program.total_amount.into(),
+				released_amount: program.released_amount.into(),
+				start_time: program.start_time,
+				end_time: program.end_time,
+			})
+			.collect(),
+	}
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn query(
+	_deps: Deps<SeiQueryWrapper>,
+	env: Env,
+	msg: LpIncentivesQueryMsg,
+) -> Result<Binary, LpIncentivesContractError> {
+	let now = env.block.time.seconds();
+	Ok(match msg {
+		LpIncentivesQueryMsg::Config => to_json_binary(&LpIncentivesConfigJsonable::try_from(
+			LpIncentivesConfig::load_non_empty()?.as_ref(),
+		)?)?,
+		LpIncentivesQueryMsg::Pool { lp_denom } => to_json_binary(
+			&get_incentive_pools_store()
+				.get(&lp_denom)?
+				.map(|pool| -> Result<_, LpIncentivesContractError> {
+					let mut pool = pool.into_inner();
+					update_pool_rewards(&mut pool, now)?;
+					Ok(pool_info(lp_denom, &pool))
+				})
+				.transpose()?,
+		)?,
+		LpIncentivesQueryMsg::Pools { after, limit } => to_json_binary(
+			&get_incentive_pools_store()
+				.iter_range(after, None)?
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.map(|(lp_denom, pool)| {
+					let mut pool = pool.into_inner();
+					update_pool_rewards(&mut pool, now)?;
+					Ok(pool_info(lp_denom, &pool))
+				})
+				.collect::<Result<Vec<_>, LpIncentivesContractError>>()?,
+		)?,
+		LpIncentivesQueryMsg::Positions { address } => {
+			let pools = get_incentive_pools_store();
+			let mut staker = get_staker_positions_store()
+				.get(&(&address).try_into()?)?
+				.map(|staker| staker.into_inner())
+				.unwrap_or_default();
+			let mut result = Vec::with_capacity(staker.positions.len());
+			for position in staker.positions.iter_mut() {
+				let mut pool = pools
+					.get(&position.lp_denom)?
+					.ok_or(StdError::not_found("incentive pool"))?
+					.into_inner();
+				update_pool_rewards(&mut pool, now)?;
+				sync_position(&mut pool, position)?;
+				result.push(LpIncentivesPosition {
+					lp_denom: position.lp_denom.clone(),
+					amount: position.amount.into(),
+					pending_rewards: pending_rewards(&pool, position)?.into_vec(),
+				});
+			}
+			to_json_binary(&result)?
+		}
+		LpIncentivesQueryMsg::PendingRewards { address, lp_denom } => {
+			let pools = get_incentive_pools_store();
+			let mut staker = get_staker_positions_store()
+				.get(&(&address).try_into()?)?
+				.map(|staker| staker.into_inner())
+				.unwrap_or_default();
+			let mut result = cosmwasm_std::Coins::default();
+			for position in staker.positions.iter_mut().filter(|position| {
+				lp_denom
+					.as_ref()
+					.map_or(true, |lp_denom| *lp_denom == position.lp_denom)
+			}) {
+				let mut pool = pools
+					.get(&position.lp_denom)?
+					.ok_or(StdError::not_found("incentive pool"))?
+					.into_inner();
+				update_pool_rewards(&mut pool, now)?;
+				sync_position(&mut pool, position)?;
+				for reward in pending_rewards(&pool, position)?.into_vec() {
+					result.add(reward)?;
+				}
+			}
+			to_json_binary(&result.into_vec())?
+		}
+	})
+}
@@ -0,0 +1,152 @@
+use cosmwasm_std::{coin, Coins, Decimal256, StdError, Uint128, Uint256};
+
+use crate::{
+	error::LpIncentivesContractError,
+	state::{IncentivePool, PositionReward, RewardProgram, StakerPosition},
+};
+
+/// Puts an upper bound on how much work is done per action, finished programs don't count towards this.
+pub const MAX_REWARD_PROGRAMS_PER_POOL: usize = 32;
+
+/// Returns the amount of rewards the program releases between its last update and the specified time. What's left to
+/// be released is spread evenly over the rest of the program, so anything which wasn't released while nothing was
+/// staked is rolled into what's released afterwards.
+pub fn releasable_rewards_at(program: &RewardProgram, timestamp: u64) -> Uint128 {
+	let remaining_amount = Uint128::new(program.total_amount - program.released_amount);
+	let released_since = program.last_update.max(program.start_time);
+	if timestamp <= released_since {
+		Uint128::zero()
+	} else if timestamp >= program.end_time {
+		remaining_amount
+	} else {
+		remaining_amount.multiply_ratio(timestamp - released_since, program.end_time - released_since)
+	}
+}
+
+/// Brings the `reward_per_share` of all the pool's programs up to date. This must be called before `total_staked`
+/// changes.
+pub fn update_pool_rewards(pool: &mut IncentivePool, timestamp: u64) -> Result<(), LpIncentivesContractError> {
+	for program in pool.programs.iter_mut() {
+		if timestamp <= program.last_update {
+			continue;
+		}
+		// Nothing is released while nothing is staked, as there'd be no one to claim it.
+		let newly_released = releasable_rewards_at(program, timestamp).u128();
+		if newly_released > 0 && pool.total_staked > 0 {
+			program.set_reward_per_share(
+				program
+					.reward_per_share()
+					.checked_add(Decimal256::checked_from_ratio(newly_released, pool.total_staked)?)?,
+			);
+			program.released_amount += newly_released;
+		}
+		program.last_update = timestamp;
+	}
+	Ok(())
+}
+
+/// Moves the rewards earned since the last time the position was synced into its pending rewards. The pool must have
+/// been updated with `update_pool_rewards` beforehand. This must be called before the position's amount changes, and
+/// `settle_position` must be called after.
+pub fn sync_position(pool: &mut IncentivePool, position: &mut StakerPosition) -> Result<(), LpIncentivesContractError> {
+	for program in pool.programs.iter_mut() {
+		let index = match position
+			.rewards
+			.iter()
+			.position(|reward| reward.program_id == program.id)
+		{
+			Some(index) => index,
+			None => {
+				let checkpoint = if position.amount == 0 {
+					// Nothing has been earned, only what's released from here on out can be.
+					program.unsettled_positions += 1;
+					program.reward_per_share
+				} else if program.id >= position.next_program_id {
+					// Added while the position was staked, so it was counted in `unsettled_positions` back then.
+					Default::default()
+				} else {
+					// Already settled
+					continue;
+				};
+				position.rewards.push(PositionReward {
+					program_id: program.id,
+					checkpoint,
+					pending: 0,
+				});
+				position.rewards.len() - 1
+			}
+		};
+		let reward = &mut position.rewards[index];
+		let growth = program.reward_per_share() - reward.checkpoint();
+		if !growth.is_zero() && position.amount > 0 {
+			let earned = Uint128::try_from(Uint256::from(position.amount).checked_mul_floor(growth)?)?;
+			reward.pending = Uint128::new(reward.pending).checked_add(earned)?.u128();
+		}
+		reward.checkpoint = program.reward_per_share;
+	}
+	position.next_program_id = pool.next_program_id;
+	Ok(())
+}
+
+/// Drops the position's rewards from programs it can no longer earn anything from and has nothing left to claim from.
+/// This must be called after the position was synced with `sync_position` and its amount was updated.
+pub fn settle_position(pool: &mut IncentivePool, position: &mut StakerPosition) {
+	let staked = position.amount > 0;
+	position.rewards.retain(|reward| {
+		let Some(program) = pool.programs.iter_mut().find(|program| program.id == reward.program_id) else {
+			return true;
+		};
+		let settled = reward.pending == 0
+			&& (!staked || program.released_amount == program.total_amount)
+			&& reward.checkpoint == program.reward_per_share;
+		if settled {
+			program.unsettled_positions -= 1;
+		}
+		!settled
+	});
+}
+
+/// Drops all of the position's rewards without syncing it first, so this works regardless of the state of the pool's
+/// programs. Whatever the position hasn't claimed is forfeited. This must be called before the position's amount is
+/// set to 0.
+pub fn forfeit_position(pool: &mut IncentivePool, position: &mut StakerPosition) {
+	for program in pool.programs.iter_mut() {
+		// Same as what `sync_position` considers to be counted in `unsettled_positions`
+		let counted = position.rewards.iter().any(|reward| reward.program_id == program.id)
+			|| (position.amount > 0 && program.id >= position.next_program_id);
+		if counted {
+			program.unsettled_positions = program.unsettled_positions.saturating_sub(1);
+		}
+	}
+	position.rewards.clear();
+	position.next_program_id = pool.next_program_id;
+}
+
+/// Removes the programs which have nothing left to release or to be claimed.
+pub fn remove_finished_programs(pool: &mut IncentivePool) {
+	pool.programs.retain(|program| !program.is_finished());
+}
+
+/// Returns the position's pending rewards, the position must have been synced with `sync_position` beforehand.
+pub fn pending_rewards(pool: &IncentivePool, position: &StakerPosition) -> Result<Coins, LpIncentivesContractError> {
+	let mut result = Coins::default();
+	for reward in position.rewards.iter().filter(|reward| reward.pending > 0) {
+		let program = pool
+			.programs
+			.iter()
+			.find(|program| program.id == reward.program_id)
+			.ok_or(StdError::not_found("reward program"))?;
+		result.add(coin(reward.pending, program.denom.clone()))?;
+	}
+	Ok(result)
+}
+
+/// Same as `pending_rewards`, except the position's pending rewards are reset.
+pub fn take_pending_rewards(
+	pool: &IncentivePool,
+	position: &mut StakerPosition,
+) -> Result<Coins, LpIncentivesContractError> {
+	let result = pending_rewards(pool, position)?;
+	position.rewards.iter_mut().for_each(|reward| reward.pending = 0);
+	Ok(result)
+}
@@ -0,0 +1,28 @@
+use cosmwasm_std::StdError;
+use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_std_error_common};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+use crate::contract::rewards::MAX_REWARD_PROGRAMS_PER_POOL;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LpIncentivesContractError {
+	#[error("StdError: {0}")]
+	Std(#[from] StdError),
+	#[error("{0}")]
+	SwapsCommon(#[from] CrownfiSwapsCommonError),
+	#[error("Payment error: {0}")]
+	PaymentError(#[from] PaymentError),
+	#[error("The specified pool has no reward programs")]
+	PoolNotRegistered,
+	#[error("{0} is not the LP token of an active pool created by the factory")]
+	UnknownPool(String),
+	#[error("Reward programs must end after they start, and must end in the future")]
+	InvalidSchedule,
+	#[error("Pools cannot have more than {} reward programs", MAX_REWARD_PROGRAMS_PER_POOL)]
+	TooManyRewardPrograms,
+	#[error("Cannot unstake more than what was staked")]
+	InsufficientStake,
+}
+
+impl_from_cosmwasm_std_error_common!(LpIncentivesContractError);
@@ -0,0 +1,105 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Uint128};
+
+use crate::state::LpIncentivesConfigJsonable;
+
+/// This structure stores the basic settings for creating a new incentives contract.
+#[cw_serde]
+pub struct LpIncentivesInstantiateMsg {
+	pub config: LpIncentivesConfigJsonable,
+}
+
+/// This structure describes the execute messages of the contract.
+#[cw_serde]
+pub enum LpIncentivesExecuteMsg {
+	/// Updates the contract's config
+	UpdateConfig {
+		/// The head honcho
+		admin: Option<Addr>,
+		/// The pool factory, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+		factory: Option<Addr>,
+	},
+	/// Adds a reward program to the pool associated with the specified LP denom. The rewards must be sent along with
+	/// this message. Only the admin or the factory may do this, and only for pools the factory created.
+	AddRewardProgram {
+		/// The pool's LP token denom, i.e. `factory/<pair>/lp`
+		lp_denom: String,
+		/// Unix timestamp (in seconds) of when rewards start being released. Defaults to now.
+		start_time: Option<u64>,
+		/// Unix timestamp (in seconds) of when all rewards will have been released.
+		end_time: u64,
+	},
+	/// Stakes the LP tokens sent along with this message. The pool must have had a reward program added to it.
+	Stake {
+		/// The account which will own the staked tokens, defaults to the sender.
+		receiver: Option<Addr>,
+	},
+	/// Unstakes LP tokens. Unclaimed rewards remain claimable.
+	Unstake {
+		/// The pool's LP token denom
+		lp_denom: String,
+		/// The amount to unstake, defaults to everything staked.
+		amount: Option<Uint128>,
+		/// The account receiving the LP tokens, defaults to the sender.
+		receiver: Option<Addr>,
+	},
+	/// Unstakes everything from the specified pool without bringing its rewards up to date, in case that fails. All
+	/// unclaimed rewards from the pool are forfeited.
+	EmergencyUnstake {
+		/// The pool's LP token denom
+		lp_denom: String,
+		/// The account receiving the LP tokens, defaults to the sender.
+		receiver: Option<Addr>,
+	},
+	/// Claims all pending rewards
+	Claim {
+		/// If specified, only claim the rewards earned from this pool.
+		lp_denom: Option<String>,
+		/// The account receiving the rewards, defaults to the sender.
+		receiver: Option<Addr>,
+	},
+}
+
+/// This structure describes the available query messages for the incentives contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LpIncentivesQueryMsg {
+	/// Returns the contract's config.
+	#[returns(LpIncentivesConfigJsonable)]
+	Config,
+	/// Returns the staking state and reward programs of the specified pool.
+	#[returns(Option<LpIncentivesPoolInfo>)]
+	Pool { lp_denom: String },
+	/// Returns the staking state and reward programs of all pools, ordered by LP denom.
+	#[returns(Vec<LpIncentivesPoolInfo>)]
+	Pools { after: Option<String>, limit: Option<u32> },
+	/// Returns everything the specified account has staked, along with their unclaimed rewards.
+	#[returns(Vec<LpIncentivesPosition>)]
+	Positions { address: Addr },
+	/// Returns the total unclaimed rewards for the specified account. Optionally only for a single pool.
+	#[returns(Vec<Coin>)]
+	PendingRewards { address: Addr, lp_denom: Option<String> },
+}
+
+#[cw_serde]
+pub struct LpIncentivesRewardProgramInfo {
+	pub denom: String,
+	pub total_amount: Uint128,
+	pub released_amount: Uint128,
+	pub start_time: u64,
+	pub end_time: u64,
+}
+
+#[cw_serde]
+pub struct LpIncentivesPoolInfo {
+	pub lp_denom: String,
+	pub total_staked: Uint128,
+	pub programs: Vec<LpIncentivesRewardProgramInfo>,
+}
+
+#[cw_serde]
+pub struct LpIncentivesPosition {
+	pub lp_denom: String,
+	pub amount: Uint128,
+	pub pending_rewards: Vec<Coin>,
+}
@@ -0,0 +1,202 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use cosmwasm_std::{Addr, Decimal256, StdError, Uint256};
+use crownfi_cw_common::{
+	data_types::canonical_addr::SeiCanonicalAddr,
+	impl_serializable_as_ref, impl_serializable_borsh,
+	storage::{item::StoredItem, map::StoredMap, OZeroCopy, SerializableItem},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const CONFIG_NAMESPACE: &str = "app_cfg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct LpIncentivesConfig {
+	/// The head honcho
+	pub admin: SeiCanonicalAddr,
+	/// The pool factory, which is allowed to register reward programs. If == Zeroable::zeroed(), None
+	pub factory: SeiCanonicalAddr,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct LpIncentivesConfigJsonable {
+	/// The head honcho
+	pub admin: Addr,
+	/// The pool factory, which is allowed to register reward programs.
+	pub factory: Option<Addr>,
+}
+
+impl_serializable_as_ref!(LpIncentivesConfig);
+impl StoredItem for LpIncentivesConfig {
+	fn namespace() -> &'static [u8] {
+		CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl LpIncentivesConfig {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "LpIncentivesConfig".into(),
+			}),
+		}
+	}
+	/// Returns true if the specified address may register new reward programs
+	pub fn can_manage_programs(&self, addr: &SeiCanonicalAddr) -> bool {
+		self.admin == *addr || (self.factory != Zeroable::zeroed() && self.factory == *addr)
+	}
+}
+impl TryFrom<&LpIncentivesConfigJsonable> for LpIncentivesConfig {
+	type Error = StdError;
+	fn try_from(value: &LpIncentivesConfigJsonable) -> Result<Self, Self::Error> {
+		Ok(LpIncentivesConfig {
+			admin: (&value.admin).try_into()?,
+			factory: value
+				.factory
+				.as_ref()
+				.map(SeiCanonicalAddr::try_from)
+				.transpose()?
+				.unwrap_or(Zeroable::zeroed()),
+		})
+	}
+}
+impl TryFrom<&LpIncentivesConfig> for LpIncentivesConfigJsonable {
+	type Error = StdError;
+	fn try_from(value: &LpIncentivesConfig) -> Result<Self, Self::Error> {
+		Ok(LpIncentivesConfigJsonable {
+			admin: value.admin.try_into()?,
+			factory: if value.factory == Zeroable::zeroed() {
+				None
+			} else {
+				Some(value.factory.try_into()?)
+			},
+		})
+	}
+}
+
+/// A single reward emission schedule. Rewards are released linearly between `start_time` and `end_time`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct RewardProgram {
+	/// Unique within the pool, this is what stakers' rewards are tracked by
+	pub id: u64,
+	/// The denom being distributed
+	pub denom: String,
+	/// The total amount of rewards which will be distributed over the program's lifetime
+	pub total_amount: u128,
+	/// Unix timestamp (in seconds) of when rewards start being released
+	pub start_time: u64,
+	/// Unix timestamp (in seconds) of when all rewards have been released
+	pub end_time: u64,
+	/// Unix timestamp (in seconds) of when `reward_per_share` was last brought up to date
+	pub last_update: u64,
+	/// The amount of rewards released to stakers as of `last_update`
+	pub released_amount: u128,
+	/// Total rewards released per staked share, converted verbatim from a `Decimal256`
+	pub reward_per_share: [u8; 32],
+	/// The amount of positions which may still have rewards to claim from this program
+	pub unsettled_positions: u32,
+}
+impl RewardProgram {
+	/// True once everything has been released and every position has claimed what it earned
+	pub fn is_finished(&self) -> bool {
+		self.released_amount == self.total_amount && self.unsettled_positions == 0
+	}
+	#[inline]
+	pub fn reward_per_share(&self) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.reward_per_share))
+	}
+	#[inline]
+	pub fn set_reward_per_share(&mut self, value: Decimal256) {
+		self.reward_per_share = value.atomics().to_le_bytes();
+	}
+}
+
+/// Staking state for a single pool, keyed by its LP denom
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct IncentivePool {
+	/// The total amount of LP tokens currently staked
+	pub total_staked: u128,
+	/// The amount of positions with something staked
+	pub staker_count: u32,
+	/// The id the next reward program will be given
+	pub next_program_id: u64,
+	/// Programs are removed once they're finished, see `RewardProgram::is_finished`.
+	pub programs: Vec<RewardProgram>,
+}
+impl_serializable_borsh!(IncentivePool);
+
+/// A position's rewards from a single reward program
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PositionReward {
+	/// The `RewardProgram::id` these rewards are from
+	pub program_id: u64,
+	/// The program's `reward_per_share` as of the last time the position was updated.
+	pub checkpoint: [u8; 32],
+	/// Unclaimed rewards as of the last time the position was updated.
+	pub pending: u128,
+}
+impl PositionReward {
+	#[inline]
+	pub fn checkpoint(&self) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.checkpoint))
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct StakerPosition {
+	/// The LP denom this position is for
+	pub lp_denom: String,
+	/// The amount of LP tokens staked
+	pub amount: u128,
+	/// The pool's `next_program_id` as of the last time the position was updated. Programs with a lower id which the
+	/// position has no rewards for have already been settled.
+	pub next_program_id: u64,
+	/// Rewards from the programs the position hasn't settled yet
+	pub rewards: Vec<PositionReward>,
+}
+impl StakerPosition {
+	pub fn is_empty(&self) -> bool {
+		self.amount == 0 && self.rewards.is_empty()
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct StakerPositions {
+	pub positions: Vec<StakerPosition>,
+}
+impl_serializable_borsh!(StakerPositions);
+impl StakerPositions {
+	/// Returns the position for the specified LP denom, creating an empty one if it doesn't exist.
+	pub fn position_mut(&mut self, lp_denom: &str) -> &mut StakerPosition {
+		let index = match self.positions.iter().position(|position| position.lp_denom == lp_denom) {
+			Some(index) => index,
+			None => {
+				self.positions.push(StakerPosition {
+					lp_denom: lp_denom.to_string(),
+					..Default::default()
+				});
+				self.positions.len() - 1
+			}
+		};
+		&mut self.positions[index]
+	}
+	/// Removes positions which have nothing staked and nothing to claim
+	pub fn prune(&mut self) {
+		self.positions.retain(|position| !position.is_empty());
+	}
+}
+
+const POOLS_NAMESPACE: &str = "pools";
+pub fn get_incentive_pools_store() -> StoredMap<String, IncentivePool> {
+	StoredMap::new(POOLS_NAMESPACE.as_ref())
+}
+
+const STAKERS_NAMESPACE: &str = "stakers";
+pub fn get_staker_positions_store() -> StoredMap<SeiCanonicalAddr, StakerPositions> {
+	StoredMap::new(STAKERS_NAMESPACE.as_ref())
+}
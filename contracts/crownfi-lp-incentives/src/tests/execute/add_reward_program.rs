@@ -0,0 +1,98 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{execute, rewards::MAX_REWARD_PROGRAMS_PER_POOL},
+	error::LpIncentivesContractError,
+	msg::LpIncentivesExecuteMsg,
+	tests::{
+		add_program, claim, deps, env_after, init, mock_factory_pools, stake, AddressFactory, LP_DENOM, POOL,
+		REWARD_DENOM,
+	},
+};
+
+fn add_program_for(sender: &str, lp_denom: &str) -> Result<(), LpIncentivesContractError> {
+	let mut deps = deps();
+	init(&mut deps);
+	mock_factory_pools(&mut deps, &[(POOL, false), (AddressFactory::BOB, true)]);
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[coin(1000, REWARD_DENOM)]),
+		LpIncentivesExecuteMsg::AddRewardProgram {
+			lp_denom: lp_denom.into(),
+			start_time: None,
+			end_time: mock_env().block.time.seconds() + 100,
+		},
+	)
+	.map(|_| ())
+}
+
+#[test]
+fn only_factory_pools_are_accepted() {
+	assert_eq!(add_program_for(AddressFactory::ADMIN, LP_DENOM), Ok(()));
+	for lp_denom in [
+		"factory/sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue/lp".to_string(),
+		// Deprecated
+		format!("factory/{}/lp", AddressFactory::BOB),
+		format!("factory/{}/not_lp", POOL),
+		"usei".to_string(),
+	] {
+		assert_eq!(
+			add_program_for(AddressFactory::ADMIN, &lp_denom),
+			Err(LpIncentivesContractError::UnknownPool(lp_denom.clone()))
+		);
+	}
+}
+
+#[test]
+fn factory_is_trusted_with_its_own_pools() {
+	assert_eq!(add_program_for(AddressFactory::FACTORY, "usei"), Ok(()));
+}
+
+#[test]
+fn sender_must_be_admin_or_factory() {
+	assert!(matches!(
+		add_program_for(AddressFactory::ALICE, LP_DENOM),
+		Err(LpIncentivesContractError::SwapsCommon(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+}
+
+#[test]
+fn schedule_must_end_in_the_future() {
+	let mut deps = deps();
+	init(&mut deps);
+	assert_eq!(
+		add_program(&mut deps, mock_env(), 1000, 0),
+		Err(LpIncentivesContractError::InvalidSchedule)
+	);
+}
+
+#[test]
+fn finished_programs_make_room_for_new_ones() {
+	let mut deps = deps();
+	init(&mut deps);
+
+	add_program(&mut deps, mock_env(), 1000, 100).unwrap();
+	stake(&mut deps, mock_env(), AddressFactory::ALICE, 100).unwrap();
+	for _ in 1..MAX_REWARD_PROGRAMS_PER_POOL {
+		add_program(&mut deps, mock_env(), 1000, 100).unwrap();
+	}
+	assert_eq!(
+		add_program(&mut deps, mock_env(), 1000, 100),
+		Err(LpIncentivesContractError::TooManyRewardPrograms)
+	);
+
+	// Everything has been released, but not claimed yet
+	assert_eq!(
+		add_program(&mut deps, env_after(100), 1000, 100),
+		Err(LpIncentivesContractError::TooManyRewardPrograms)
+	);
+	claim(&mut deps, env_after(100), AddressFactory::ALICE).unwrap();
+	add_program(&mut deps, env_after(100), 1000, 100).unwrap();
+}
@@ -0,0 +1,116 @@
+use cosmwasm_std::{coin, BankMsg, SubMsg};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	error::LpIncentivesContractError,
+	tests::{add_program, claim, deps, env_after, init, pending_rewards, stake, AddressFactory, REWARD_DENOM},
+};
+
+#[test]
+fn rewards_are_split_by_stake() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, env_after(0), 1000, 100).unwrap();
+	stake(&mut deps, env_after(0), AddressFactory::ALICE, 100).unwrap();
+	stake(&mut deps, env_after(0), AddressFactory::BOB, 300).unwrap();
+
+	assert_eq!(
+		pending_rewards(&deps, env_after(50), AddressFactory::ALICE),
+		vec![coin(125, REWARD_DENOM)]
+	);
+	assert_eq!(
+		pending_rewards(&deps, env_after(50), AddressFactory::BOB),
+		vec![coin(375, REWARD_DENOM)]
+	);
+
+	let res = claim(&mut deps, env_after(50), AddressFactory::ALICE).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::ALICE.into(),
+			amount: vec![coin(125, REWARD_DENOM)]
+		})]
+	);
+	assert_eq!(pending_rewards(&deps, env_after(50), AddressFactory::ALICE), vec![]);
+
+	// Nothing more is released once the program has ended
+	assert_eq!(
+		pending_rewards(&deps, env_after(1000), AddressFactory::ALICE),
+		vec![coin(125, REWARD_DENOM)]
+	);
+	assert_eq!(
+		pending_rewards(&deps, env_after(1000), AddressFactory::BOB),
+		vec![coin(750, REWARD_DENOM)]
+	);
+}
+
+#[test]
+fn nothing_to_claim() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, env_after(0), 1000, 100).unwrap();
+	stake(&mut deps, env_after(0), AddressFactory::ALICE, 100).unwrap();
+
+	assert_eq!(
+		claim(&mut deps, env_after(0), AddressFactory::ALICE),
+		Err(CrownfiSwapsCommonError::PayoutIsZero.into())
+	);
+	assert_eq!(
+		claim(&mut deps, env_after(50), AddressFactory::BOB),
+		Err(LpIncentivesContractError::SwapsCommon(
+			CrownfiSwapsCommonError::PayoutIsZero
+		))
+	);
+}
+
+#[test]
+fn rewards_are_not_released_while_nothing_is_staked() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, env_after(0), 1000, 100).unwrap();
+
+	// The 500 which would have been released by now are spread over the rest of the program instead
+	stake(&mut deps, env_after(50), AddressFactory::ALICE, 100).unwrap();
+	assert_eq!(
+		pending_rewards(&deps, env_after(75), AddressFactory::ALICE),
+		vec![coin(500, REWARD_DENOM)]
+	);
+	claim(&mut deps, env_after(100), AddressFactory::ALICE).unwrap();
+	assert_eq!(pending_rewards(&deps, env_after(200), AddressFactory::ALICE), vec![]);
+}
+
+#[test]
+fn programs_added_later_are_earned_from() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, env_after(0), 1000, 100).unwrap();
+	stake(&mut deps, env_after(0), AddressFactory::ALICE, 100).unwrap();
+	add_program(&mut deps, env_after(50), 200, 100).unwrap();
+
+	assert_eq!(
+		pending_rewards(&deps, env_after(100), AddressFactory::ALICE),
+		vec![coin(1100, REWARD_DENOM)]
+	);
+	let res = claim(&mut deps, env_after(200), AddressFactory::ALICE).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::ALICE.into(),
+			amount: vec![coin(1200, REWARD_DENOM)]
+		})]
+	);
+}
+
+#[test]
+fn large_rewards_per_share_dont_overflow() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, env_after(0), 1_000_000_000_000_000_000_000_000_000_000, 100).unwrap();
+	stake(&mut deps, env_after(0), AddressFactory::ALICE, 1).unwrap();
+
+	assert_eq!(
+		pending_rewards(&deps, env_after(100), AddressFactory::ALICE),
+		vec![coin(1_000_000_000_000_000_000_000_000_000_000, REWARD_DENOM)]
+	);
+	claim(&mut deps, env_after(100), AddressFactory::ALICE).unwrap();
+}
@@ -0,0 +1,3 @@
+mod add_reward_program;
+mod claim;
+mod stake_and_unstake;
@@ -0,0 +1,138 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+	BankMsg, Response, SubMsg, Uint128,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::execute,
+	error::LpIncentivesContractError,
+	msg::LpIncentivesExecuteMsg,
+	tests::{
+		add_program, claim, deps, env_after, init, pending_rewards, stake, AddressFactory, TestDeps, LP_DENOM,
+		REWARD_DENOM,
+	},
+};
+
+fn unstake(
+	deps: &mut TestDeps,
+	seconds: u64,
+	amount: Option<u128>,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	execute(
+		deps.as_mut(),
+		env_after(seconds),
+		mock_info(AddressFactory::ALICE, &[]),
+		LpIncentivesExecuteMsg::Unstake {
+			lp_denom: LP_DENOM.into(),
+			amount: amount.map(Uint128::new),
+			receiver: None,
+		},
+	)
+}
+
+#[test]
+fn pool_must_have_a_program() {
+	let mut deps = deps();
+	init(&mut deps);
+	assert_eq!(
+		stake(&mut deps, mock_env(), AddressFactory::ALICE, 100),
+		Err(LpIncentivesContractError::PoolNotRegistered)
+	);
+	assert_eq!(
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::ALICE, &[coin(100, "usei")]),
+			LpIncentivesExecuteMsg::Stake { receiver: None },
+		),
+		Err(LpIncentivesContractError::PoolNotRegistered)
+	);
+}
+
+#[test]
+fn cannot_unstake_more_than_staked() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, mock_env(), 1000, 100).unwrap();
+	stake(&mut deps, mock_env(), AddressFactory::ALICE, 100).unwrap();
+	assert_eq!(
+		unstake(&mut deps, 10, Some(101)),
+		Err(LpIncentivesContractError::InsufficientStake)
+	);
+}
+
+#[test]
+fn unstaking_keeps_earned_rewards() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, mock_env(), 1000, 100).unwrap();
+	stake(&mut deps, mock_env(), AddressFactory::ALICE, 100).unwrap();
+
+	let res = unstake(&mut deps, 25, Some(50)).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::ALICE.into(),
+			amount: vec![coin(50, LP_DENOM)]
+		})]
+	);
+	let res = unstake(&mut deps, 50, None).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::ALICE.into(),
+			amount: vec![coin(50, LP_DENOM)]
+		})]
+	);
+
+	// Nothing more is earned once everything is unstaked
+	assert_eq!(
+		pending_rewards(&deps, env_after(100), AddressFactory::ALICE),
+		vec![coin(500, REWARD_DENOM)]
+	);
+	claim(&mut deps, env_after(100), AddressFactory::ALICE).unwrap();
+	assert_eq!(pending_rewards(&deps, env_after(100), AddressFactory::ALICE), vec![]);
+}
+
+#[test]
+fn emergency_unstake_forfeits_rewards() {
+	let mut deps = deps();
+	init(&mut deps);
+	add_program(&mut deps, mock_env(), 1000, 100).unwrap();
+	stake(&mut deps, mock_env(), AddressFactory::ALICE, 100).unwrap();
+	stake(&mut deps, mock_env(), AddressFactory::BOB, 100).unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		env_after(50),
+		mock_info(AddressFactory::ALICE, &[]),
+		LpIncentivesExecuteMsg::EmergencyUnstake {
+			lp_denom: LP_DENOM.into(),
+			receiver: None,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::ALICE.into(),
+			amount: vec![coin(100, LP_DENOM)]
+		})]
+	);
+	assert_eq!(pending_rewards(&deps, env_after(50), AddressFactory::ALICE), vec![]);
+	assert_eq!(
+		unstake(&mut deps, 50, None),
+		Err(CrownfiSwapsCommonError::PayoutIsZero.into())
+	);
+
+	// What Alice earned, and what was released since, went to the only one still staked
+	assert_eq!(
+		pending_rewards(&deps, env_after(100), AddressFactory::BOB),
+		vec![coin(1000, REWARD_DENOM)]
+	);
+	claim(&mut deps, env_after(100), AddressFactory::BOB).unwrap();
+	assert_eq!(pending_rewards(&deps, env_after(100), AddressFactory::BOB), vec![]);
+}
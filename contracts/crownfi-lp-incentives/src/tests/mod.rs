@@ -0,0 +1,162 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+	coin, from_json, testing::*, to_json_binary, Addr, Coin, ContractResult, Env, MemoryStorage, OwnedDeps, Response,
+	SystemError, SystemResult, WasmQuery,
+};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::contract::*;
+use crate::error::*;
+use crate::msg::*;
+use crate::state::*;
+
+mod execute;
+
+const LP_DENOM: &str = "factory/sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a/lp";
+const POOL: &str = "sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a";
+const REWARD_DENOM: &str = "reward";
+
+type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier<SeiQueryWrapper>, SeiQueryWrapper>;
+
+enum AddressFactory {}
+impl AddressFactory {
+	pub(crate) const ADMIN: &'static str = "sei1zgfgerl8qt9uldlr0y9w7qe97p7zyv5kwg2pge";
+	pub(crate) const FACTORY: &'static str = "sei1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygskw0yqp";
+	pub(crate) const ALICE: &'static str = "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue";
+	pub(crate) const BOB: &'static str = "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft";
+}
+
+fn deps() -> TestDeps {
+	let querier = MockQuerier::<SeiQueryWrapper>::new(&[]);
+
+	let mem = Box::new(MockStorage::default());
+	let mem_ptr = Box::leak(mem) as *mut MockStorage;
+	crownfi_cw_common::storage::base::set_global_storage(unsafe { Box::from_raw(mem_ptr) });
+
+	OwnedDeps {
+		querier,
+		storage: unsafe { mem_ptr.read() },
+		api: MockApi::default(),
+		custom_query_type: Default::default(),
+	}
+}
+
+/// Same as the factory's `PairByAddr` query message
+#[cw_serde]
+enum FactoryQueryMsg {
+	PairByAddr { address: Addr },
+}
+
+/// Same as the factory's `PoolFactoryCreatedPair`
+#[cw_serde]
+struct FactoryCreatedPair {
+	canonical_pair: [String; 2],
+	address: Addr,
+	deprecated: bool,
+}
+
+/// Makes the factory report the specified pools as the ones it created, along with whether they're deprecated.
+fn mock_factory_pools(deps: &mut TestDeps, pools: &[(&str, bool)]) {
+	let pools: Vec<(String, bool)> = pools
+		.iter()
+		.map(|(pool, deprecated)| (pool.to_string(), *deprecated))
+		.collect();
+	deps.querier.update_wasm(move |query| match query {
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == AddressFactory::FACTORY => {
+			let FactoryQueryMsg::PairByAddr { address } = from_json(msg).unwrap();
+			let result = pools
+				.iter()
+				.find(|(pool, _)| *pool == address.as_str())
+				.map(|(_, deprecated)| FactoryCreatedPair {
+					canonical_pair: ["abc".into(), "cba".into()],
+					address: address.clone(),
+					deprecated: *deprecated,
+				});
+			SystemResult::Ok(ContractResult::Ok(to_json_binary(&result).unwrap()))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+}
+
+fn init(deps: &mut TestDeps) -> Response<SeiMsg> {
+	mock_factory_pools(deps, &[(POOL, false)]);
+	instantiate(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		LpIncentivesInstantiateMsg {
+			config: LpIncentivesConfigJsonable {
+				admin: Addr::unchecked(AddressFactory::ADMIN),
+				factory: Some(Addr::unchecked(AddressFactory::FACTORY)),
+			},
+		},
+	)
+	.unwrap()
+}
+
+/// `mock_env` with the block time moved forward by the specified amount of seconds
+fn env_after(seconds: u64) -> Env {
+	let mut env = mock_env();
+	env.block.time = env.block.time.plus_seconds(seconds);
+	env
+}
+
+fn add_program(
+	deps: &mut TestDeps,
+	env: Env,
+	amount: u128,
+	duration: u64,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	let end_time = env.block.time.seconds() + duration;
+	execute(
+		deps.as_mut(),
+		env,
+		mock_info(AddressFactory::ADMIN, &[coin(amount, REWARD_DENOM)]),
+		LpIncentivesExecuteMsg::AddRewardProgram {
+			lp_denom: LP_DENOM.into(),
+			start_time: None,
+			end_time,
+		},
+	)
+}
+
+fn stake(
+	deps: &mut TestDeps,
+	env: Env,
+	staker: &str,
+	amount: u128,
+) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	execute(
+		deps.as_mut(),
+		env,
+		mock_info(staker, &[coin(amount, LP_DENOM)]),
+		LpIncentivesExecuteMsg::Stake { receiver: None },
+	)
+}
+
+fn claim(deps: &mut TestDeps, env: Env, staker: &str) -> Result<Response<SeiMsg>, LpIncentivesContractError> {
+	execute(
+		deps.as_mut(),
+		env,
+		mock_info(staker, &[]),
+		LpIncentivesExecuteMsg::Claim {
+			lp_denom: None,
+			receiver: None,
+		},
+	)
+}
+
+fn pending_rewards(deps: &TestDeps, env: Env, staker: &str) -> Vec<Coin> {
+	from_json(
+		query(
+			deps.as_ref(),
+			env,
+			LpIncentivesQueryMsg::PendingRewards {
+				address: Addr::unchecked(staker),
+				lp_denom: None,
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap()
+}
@@ -1,14 +1,25 @@
+use bytemuck::Zeroable;
 use cosmwasm_std::{
-	attr, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, StdError,
-	SubMsg, WasmMsg,
+	attr, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Reply,
+	ReplyOn, Response, StdError, SubMsg, Uint128, WasmMsg,
 };
-use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_cw20_wrapper::msg::CW20WrapperQueryMsg;
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_erc20_wrapper::msg::ERC20WrapperQueryMsg;
+use crownfi_lp_incentives_contract::msg::LpIncentivesExecuteMsg;
 use crownfi_pool_pair_contract::{
-	msg::{PoolPairExecuteMsg, PoolPairInstantiateMsg},
-	state::PoolPairConfigJsonable,
+	contract::{pool::balances_into_share_value, shares::LP_SUBDENOM},
+	msg::{PoolPairExecuteMsg, PoolPairInstantiateMsg, PoolPairMigrateMsg},
+	raw_query::{query_pool_config, query_pool_total_shares, PoolPairRawState},
+	state::{PoolPairConfigFlags, PoolPairConfigJsonable},
 };
 use crownfi_swaps_common::{
-	data_types::pair_id::CanonicalPoolPairIdentifier, error::CrownfiSwapsCommonError, validation::msg::two_coins,
+	data_types::{
+		pair_id::CanonicalPoolPairIdentifier,
+		pending_admin::{PendingAdminTransfer, PendingAdminTransferJsonable},
+	},
+	error::CrownfiSwapsCommonError,
+	validation::{coin::validate_native_denom, msg::two_coins},
 };
 use cw2::set_contract_version;
 use cw_utils::{nonpayable, parse_reply_instantiate_data, ParseReplyError};
@@ -16,13 +27,31 @@ use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::{
 	error::PoolFactoryContractError,
-	msg::{PoolFactoryCreatedPair, PoolFactoryExecuteMsg, PoolFactoryInstantiateMsg, PoolFactoryQueryMsg},
-	state::{get_pool_addresses_store, PoolFactoryConfig, PoolFactoryConfigFlags, PoolFactoryConfigJsonable},
+	msg::{
+		PoolFactoryBatchSimulateResult, PoolFactoryCreatedPair, PoolFactoryDenomTokenMetadata,
+		PoolFactoryDeprecatedPair, PoolFactoryExecuteMsg, PoolFactoryInstantiateMsg, PoolFactoryMigrateMsg,
+		PoolFactoryPairInfo, PoolFactoryPoolCodeVersion, PoolFactoryPoolSnapshot, PoolFactoryQueryMsg,
+		PoolFactoryRoleHolder, PoolFactoryUserPosition, PoolFactoryUserPositions,
+	},
+	state::{
+		get_denom_list_store, get_denom_origins_store, get_denom_pairs_store, get_evm_pointers_store,
+		get_pending_pool_fees_store, get_pool_addresses_store, get_pool_code_info_store, get_pool_graveyard_store,
+		get_pool_pairs_store, get_roles_store, get_token_metadata_store, get_underlying_pairs_store, has_role,
+		index_pool, PoolFactoryConfig, PoolFactoryConfigFlags, PoolFactoryConfigJsonable, PoolFactoryDenomFilter,
+		PoolFactoryDeprecatedPool, PoolFactoryPendingConfig, PoolFactoryPendingPoolFees, PoolFactoryPoolCodeInfo,
+		PoolFactoryPoolCreationRules, PoolFactoryPoolCreationRulesJsonable, PoolFactoryRole, PoolFactoryRoleFlags,
+		PoolFactoryTokenMetadata, PoolFactoryUnderlyingAsset, PoolFactoryWrapperConfig,
+		PoolFactoryWrapperConfigJsonable,
+	},
 };
 
 const CONTRACT_NAME: &str = "crownfi-pool-factory";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Default and maximum amount of pools to go through in queries which query each pool
+const POOL_SCAN_DEFAULT_LIMIT: u32 = 30;
+const POOL_SCAN_MAX_LIMIT: u32 = 100;
+
 /// `reply` call code IDs used in a sub-message.
 const INSTANTIATE_PAIR_REPLY_ID: u64 = 0xf09f8fad62727272;
 
@@ -36,10 +65,27 @@ pub fn instantiate(
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
 	nonpayable(&msg_info)?;
 	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-	PoolFactoryConfig::try_from(&msg.config)?.save()?;
+	let config = PoolFactoryConfig::try_from(&msg.config)?;
+	if !config.valid_fee_bps() {
+		return Err(PoolFactoryContractError::InvalidFeeBps);
+	}
+	config.save()?;
+	if let Some(guardian) = msg.guardian {
+		get_roles_store().set(&SeiCanonicalAddr::try_from(&guardian)?, &PoolFactoryRoleFlags::GUARDIAN)?;
+	}
 	Ok(Response::new())
 }
 
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn migrate(
+	deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	_msg: PoolFactoryMigrateMsg,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	Ok(Response::new().add_attributes(vec![attr("action", "migrate"), attr("version", CONTRACT_VERSION)]))
+}
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 #[inline]
 pub fn execute(
@@ -50,37 +96,119 @@ pub fn execute(
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
 	match msg {
 		PoolFactoryExecuteMsg::UpdateConfig {
-			admin,
 			fee_receiver,
 			pair_code_id,
+			lp_incentives,
 			default_total_fee_bps,
 			default_maker_fee_bps,
 			permissionless_pool_cration,
+			timelock_seconds,
+			cw20_wrapper,
+			erc20_wrapper,
+			reject_duplicate_underlying_pairs,
+			max_referral_fee_bps,
 		} => process_update_config(
 			deps,
+			env,
 			msg_info,
-			admin,
 			fee_receiver,
 			pair_code_id,
+			lp_incentives,
 			default_total_fee_bps,
 			default_maker_fee_bps,
 			permissionless_pool_cration,
+			timelock_seconds,
+			cw20_wrapper,
+			erc20_wrapper,
+			reject_duplicate_underlying_pairs,
+			max_referral_fee_bps,
 		),
+		PoolFactoryExecuteMsg::ApplyPendingConfig {} => process_apply_pending_config(deps, env, msg_info),
+		PoolFactoryExecuteMsg::CancelPendingConfig {} => process_cancel_pending_config(deps, msg_info),
+		PoolFactoryExecuteMsg::ProposeAdmin { admin } => process_propose_admin(deps, env, msg_info, admin),
+		PoolFactoryExecuteMsg::AcceptAdmin {} => process_accept_admin(deps, env, msg_info),
+		PoolFactoryExecuteMsg::CancelAdminTransfer {} => process_cancel_admin_transfer(deps, msg_info),
+		PoolFactoryExecuteMsg::ProposePoolAdmin { pair, admin } => {
+			process_forward_pool_admin_transfer(deps, msg_info, pair, PoolPairExecuteMsg::ProposeAdmin { admin })
+		}
+		PoolFactoryExecuteMsg::CancelPoolAdminTransfer { pair } => {
+			process_forward_pool_admin_transfer(deps, msg_info, pair, PoolPairExecuteMsg::CancelAdminTransfer {})
+		}
+		PoolFactoryExecuteMsg::UpdatePoolCreationRules {
+			creation_fee,
+			min_initial_liquidity,
+			denom_filter,
+		} => process_update_pool_creation_rules(deps, msg_info, creation_fee, min_initial_liquidity, denom_filter),
+		PoolFactoryExecuteMsg::UpdateDenomList { add, remove } => {
+			process_update_denom_list(deps, msg_info, add, remove)
+		}
 		PoolFactoryExecuteMsg::CreatePool {
 			left_denom,
 			initial_shares_receiver,
 		} => process_create_pool(deps, env, msg_info, left_denom, initial_shares_receiver),
+		PoolFactoryExecuteMsg::DeprecatePool { pair } => process_deprecate_pool(deps, env, msg_info, pair),
+		PoolFactoryExecuteMsg::ReplacePool {
+			left_denom,
+			initial_shares_receiver,
+		} => process_replace_pool(deps, env, msg_info, left_denom, initial_shares_receiver),
 		PoolFactoryExecuteMsg::UpdateFeesForPool {
 			pair,
 			total_fee_bps,
 			maker_fee_bps,
-		} => process_update_fees_for_pool(deps, msg_info, pair, total_fee_bps, maker_fee_bps),
+		} => process_update_fees_for_pool(deps, env, msg_info, pair, total_fee_bps, maker_fee_bps),
+		PoolFactoryExecuteMsg::ApplyPendingFeesForPool { pair } => {
+			process_apply_pending_fees_for_pool(deps, env, msg_info, pair)
+		}
+		PoolFactoryExecuteMsg::CancelPendingFeesForPool { pair } => {
+			process_cancel_pending_fees_for_pool(deps, msg_info, pair)
+		}
 		PoolFactoryExecuteMsg::UpdateGlobalConfigForPool { after, limit } => {
 			process_update_global_config_for_pool(deps, msg_info, after, limit)
 		},
 		PoolFactoryExecuteMsg::UpdatePoolCode { pair, payload } => {
 			process_update_pool_code(deps, msg_info, pair, payload)
 		},
+		PoolFactoryExecuteMsg::MigratePools { after, limit, payload } => {
+			process_migrate_pools(deps, msg_info, after, limit, payload)
+		}
+		PoolFactoryExecuteMsg::PinPoolCode { pair, pinned } => process_pin_pool_code(deps, msg_info, pair, pinned),
+		PoolFactoryExecuteMsg::SetPoolPaused { pair, swaps, deposits } => {
+			process_set_pool_paused(deps, msg_info, pair, swaps, deposits)
+		}
+		PoolFactoryExecuteMsg::SetAllPoolsPaused {
+			swaps,
+			deposits,
+			after,
+			limit,
+		} => process_set_all_pools_paused(deps, msg_info, swaps, deposits, after, limit),
+		PoolFactoryExecuteMsg::SetPoolLongTermOrders { pair, enabled } => {
+			process_set_pool_features(deps, env, msg_info, pair, Some(enabled), None, None)
+		}
+		PoolFactoryExecuteMsg::SetPoolPositionTracking { pair, enabled } => {
+			process_set_pool_features(deps, env, msg_info, pair, None, Some(enabled), None)
+		}
+		PoolFactoryExecuteMsg::SetPoolFeeOnInput { pair, enabled } => {
+			process_set_pool_features(deps, env, msg_info, pair, None, None, Some(enabled))
+		}
+		PoolFactoryExecuteMsg::ReindexPools { after, limit } => process_reindex_pools(deps, msg_info, after, limit),
+		PoolFactoryExecuteMsg::RecordUnderlyingPairs { after, limit } => {
+			process_record_underlying_pairs(deps, msg_info, after, limit)
+		}
+		PoolFactoryExecuteMsg::SetTokenMetadata { denom, metadata } => {
+			process_set_token_metadata(deps, msg_info, denom, Some(metadata))
+		}
+		PoolFactoryExecuteMsg::RemoveTokenMetadata { denom } => process_set_token_metadata(deps, msg_info, denom, None),
+		PoolFactoryExecuteMsg::GrantRoles { address, roles } => {
+			process_update_roles(deps, msg_info, address, roles, true)
+		}
+		PoolFactoryExecuteMsg::RevokeRoles { address, roles } => {
+			process_update_roles(deps, msg_info, address, roles, false)
+		}
+		PoolFactoryExecuteMsg::RegisterIncentiveProgram {
+			pair,
+			start_time,
+			end_time,
+		} => process_register_incentive_program(deps, msg_info, pair, start_time, end_time),
 	}
 }
 
@@ -95,8 +223,17 @@ pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response<SeiMsg>,
 			// We shouldn't have to check if the pair created matches the one we expected as that's the only scenerio
 			// where we asked for a reply on anything.
 			let new_pair_addr = Addr::unchecked(msg.contract_address);
+			let new_pair_canon_addr = SeiCanonicalAddr::try_from(&new_pair_addr)?;
 			let pool_map = get_pool_addresses_store();
-			pool_map.set(&new_pair, &(&new_pair_addr).try_into()?)?;
+			pool_map.set(&new_pair, &new_pair_canon_addr)?;
+			index_pool(&new_pair, &new_pair_canon_addr)?;
+			get_pool_code_info_store().set(
+				&new_pair,
+				&PoolFactoryPoolCodeInfo {
+					code_id: Some(PoolFactoryConfig::load_non_empty()?.pair_code_id),
+					pinned: false,
+				},
+			)?;
 			Ok(Response::new().add_attributes(vec![
 				attr("action", "create_pair"),
 				attr("pair", new_pair.to_string()),
@@ -111,13 +248,19 @@ pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response<SeiMsg>,
 
 fn process_update_config(
 	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
 	msg_info: MessageInfo,
-	admin: Option<Addr>,
 	fee_receiver: Option<Addr>,
 	pair_code_id: Option<u64>,
+	lp_incentives: Option<Addr>,
 	default_total_fee_bps: Option<u16>,
 	default_maker_fee_bps: Option<u16>,
 	permissionless_pool_cration: Option<bool>,
+	timelock_seconds: Option<u32>,
+	cw20_wrapper: Option<Addr>,
+	erc20_wrapper: Option<Addr>,
+	reject_duplicate_underlying_pairs: Option<bool>,
+	max_referral_fee_bps: Option<u16>,
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
 	nonpayable(&msg_info)?;
 	let mut config = PoolFactoryConfig::load_non_empty()?;
@@ -126,20 +269,60 @@ fn process_update_config(
 			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
 		);
 	}
-	if let Some(admin) = admin {
-		config.admin = admin.try_into()?;
-	}
+	// Sensitive changes are queued if there's a timelock, along with whatever was queued before. Changes which take
+	// effect immediately replace any queued change to the same setting.
+	let timelock_seconds_before = config.timelock_seconds;
+	let timelocked = timelock_seconds_before > 0;
+	let mut pending = PoolFactoryPendingConfig::load()?
+		.map(|pending| pending.into_inner())
+		.unwrap_or_default();
+	let mut queued = false;
 	if let Some(fee_receiver) = fee_receiver {
 		config.fee_receiver = fee_receiver.try_into()?;
+		if config.fee_receiver == SeiCanonicalAddr::zeroed()
+			&& PoolFactoryPoolCreationRules::load()?.is_some_and(|rules| rules.creation_fee().is_some())
+		{
+			return Err(PoolFactoryContractError::CreationFeeWithoutReceiver);
+		}
 	}
 	if let Some(pair_code_id) = pair_code_id {
-		config.pair_code_id = pair_code_id;
+		if timelocked && pair_code_id != config.pair_code_id {
+			pending.pair_code_id = Some(pair_code_id);
+			queued = true;
+		} else {
+			config.pair_code_id = pair_code_id;
+			pending.pair_code_id = None;
+		}
+	}
+	if let Some(lp_incentives) = lp_incentives {
+		config.lp_incentives = lp_incentives.try_into()?;
 	}
 	if let Some(default_total_fee_bps) = default_total_fee_bps {
-		config.default_total_fee_bps = default_total_fee_bps;
+		if timelocked && default_total_fee_bps > config.default_total_fee_bps {
+			pending.default_total_fee_bps = Some(default_total_fee_bps);
+			queued = true;
+		} else {
+			config.default_total_fee_bps = default_total_fee_bps;
+			pending.default_total_fee_bps = None;
+		}
 	}
 	if let Some(default_maker_fee_bps) = default_maker_fee_bps {
-		config.default_maker_fee_bps = default_maker_fee_bps;
+		if timelocked && default_maker_fee_bps > config.default_maker_fee_bps {
+			pending.default_maker_fee_bps = Some(default_maker_fee_bps);
+			queued = true;
+		} else {
+			config.default_maker_fee_bps = default_maker_fee_bps;
+			pending.default_maker_fee_bps = None;
+		}
+	}
+	if let Some(timelock_seconds) = timelock_seconds {
+		if timelocked && timelock_seconds < config.timelock_seconds {
+			pending.timelock_seconds = Some(timelock_seconds);
+			queued = true;
+		} else {
+			config.timelock_seconds = timelock_seconds;
+			pending.timelock_seconds = None;
+		}
 	}
 	if let Some(permissionless_pool_cration) = permissionless_pool_cration {
 		if permissionless_pool_cration {
@@ -148,34 +331,425 @@ fn process_update_config(
 			config.flags &= !PoolFactoryConfigFlags::PERMISSIONLESS_POOL_CRATION;
 		}
 	}
+	if let Some(reject_duplicate_underlying_pairs) = reject_duplicate_underlying_pairs {
+		config.flags.set(
+			PoolFactoryConfigFlags::REJECT_DUPLICATE_UNDERLYING_PAIRS,
+			reject_duplicate_underlying_pairs,
+		);
+	}
+	if let Some(max_referral_fee_bps) = max_referral_fee_bps {
+		config.max_referral_fee_bps = max_referral_fee_bps;
+	}
+	// Queued fees are checked against the rest of the config now, rather than failing once they're applied
+	let mut config_after_pending = *config;
+	config_after_pending.default_total_fee_bps = pending.default_total_fee_bps.unwrap_or(config.default_total_fee_bps);
+	config_after_pending.default_maker_fee_bps = pending.default_maker_fee_bps.unwrap_or(config.default_maker_fee_bps);
+	if !config.valid_fee_bps() || !config_after_pending.valid_fee_bps() {
+		return Err(PoolFactoryContractError::InvalidFeeBps);
+	}
 	config.save()?;
-	Ok(Response::new().add_attribute("action", "update_config"))
+	if cw20_wrapper.is_some() || erc20_wrapper.is_some() {
+		let mut wrapper_config = PoolFactoryWrapperConfig::load_or_default()?;
+		if let Some(cw20_wrapper) = cw20_wrapper {
+			wrapper_config.cw20_wrapper = cw20_wrapper.try_into()?;
+		}
+		if let Some(erc20_wrapper) = erc20_wrapper {
+			wrapper_config.erc20_wrapper = erc20_wrapper.try_into()?;
+		}
+		wrapper_config.save()?;
+	}
+	let mut response = Response::new().add_attribute("action", "update_config");
+	if pending.is_empty() {
+		PoolFactoryPendingConfig::remove();
+	} else {
+		if queued {
+			// The timelock in effect when the changes were requested is used, not the one which may have just been
+			// set. What was queued before has to wait until then as well, so nothing is applied early along with it.
+			pending.apply_after = env.block.time.seconds() + timelock_seconds_before as u64;
+			response = response.add_attribute("pending_apply_after", pending.apply_after.to_string());
+		}
+		pending.save()?;
+	}
+	Ok(response)
 }
 
-fn process_create_pool(
+fn process_apply_pending_config(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let mut config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let pending = PoolFactoryPendingConfig::load()?.ok_or(PoolFactoryContractError::NothingPending)?;
+	if env.block.time.seconds() < pending.apply_after {
+		return Err(CrownfiSwapsCommonError::Timelocked(pending.apply_after).into());
+	}
+	if let Some(pair_code_id) = pending.pair_code_id {
+		config.pair_code_id = pair_code_id;
+	}
+	if let Some(default_total_fee_bps) = pending.default_total_fee_bps {
+		config.default_total_fee_bps = default_total_fee_bps;
+	}
+	if let Some(default_maker_fee_bps) = pending.default_maker_fee_bps {
+		config.default_maker_fee_bps = default_maker_fee_bps;
+	}
+	if let Some(timelock_seconds) = pending.timelock_seconds {
+		config.timelock_seconds = timelock_seconds;
+	}
+	if !config.valid_fee_bps() {
+		return Err(PoolFactoryContractError::InvalidFeeBps);
+	}
+	config.save()?;
+	PoolFactoryPendingConfig::remove();
+	Ok(Response::new().add_attribute("action", "apply_pending_config"))
+}
+
+fn process_cancel_pending_config(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if PoolFactoryPendingConfig::load()?.is_none() {
+		return Err(PoolFactoryContractError::NothingPending);
+	}
+	PoolFactoryPendingConfig::remove();
+	Ok(Response::new().add_attribute("action", "cancel_pending_config"))
+}
+
+fn process_propose_admin(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	admin: Addr,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let accept_after = env.block.time.seconds() + config.timelock_seconds as u64;
+	PendingAdminTransfer {
+		new_admin: (&admin).try_into()?,
+		accept_after,
+	}
+	.save()?;
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "propose_admin"),
+		attr("new_admin", admin),
+		attr("accept_after", accept_after.to_string()),
+	]))
+}
+
+fn process_accept_admin(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let pending = PendingAdminTransfer::load_acceptable::<PoolFactoryContractError>(
+		&(&msg_info.sender).try_into()?,
+		env.block.time.seconds(),
+	)?;
+	let mut config = PoolFactoryConfig::load_non_empty()?;
+	config.admin = pending.new_admin;
+	config.save()?;
+	PendingAdminTransfer::remove();
+	Ok(Response::new().add_attributes(vec![attr("action", "accept_admin"), attr("new_admin", msg_info.sender)]))
+}
+
+fn process_cancel_admin_transfer(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	PendingAdminTransfer::load_non_empty::<PoolFactoryContractError>()?;
+	PendingAdminTransfer::remove();
+	Ok(Response::new().add_attribute("action", "cancel_admin_transfer"))
+}
+
+/// Sends the pool's admin transfer messages on behalf of the admin, as the pool's admin is this contract.
+fn process_forward_pool_admin_transfer(
 	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	msg: PoolPairExecuteMsg,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair.into())?
+		.ok_or(StdError::not_found("pair address"))?;
+
+	Ok(Response::new().add_message(WasmMsg::Execute {
+		contract_addr: pool_addr.to_string(),
+		msg: to_json_binary(&msg)?,
+		funds: Vec::new(),
+	}))
+}
+
+fn process_create_pool(
+	deps: DepsMut<SeiQueryWrapper>,
 	env: Env,
 	msg_info: MessageInfo,
 	left_denom: String,
 	initial_shares_receiver: Option<Addr>,
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
-	let pool_coins = two_coins(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	let sender = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let is_admin = config.admin == sender;
+	let is_pool_creator = has_role(&config, &sender, PoolFactoryRoleFlags::POOL_CREATOR)?;
+	if !config
+		.flags
+		.contains(PoolFactoryConfigFlags::PERMISSIONLESS_POOL_CRATION)
+		&& !is_pool_creator
+	{
+		return Err(CrownfiSwapsCommonError::Unauthorized("Permissionless pool creation is disabled".into()).into());
+	}
+	let mut response = Response::new();
+	let mut pool_msg_info = msg_info.clone();
+	let rules = PoolFactoryPoolCreationRules::load()?
+		.map(|rules| rules.into_inner())
+		.unwrap_or_default();
+	if !is_pool_creator {
+		if let Some(creation_fee) = rules.creation_fee() {
+			// The fee may be in one of the pair's denoms, so it's taken out of the funds rather than sent separately.
+			let Some(fee_index) = pool_msg_info
+				.funds
+				.iter()
+				.position(|fund| fund.denom == creation_fee.denom && fund.amount >= creation_fee.amount)
+			else {
+				return Err(PoolFactoryContractError::CreationFeeRequired(creation_fee));
+			};
+			pool_msg_info.funds[fee_index].amount -= creation_fee.amount;
+			if pool_msg_info.funds[fee_index].amount.is_zero() {
+				pool_msg_info.funds.remove(fee_index);
+			}
+			response = response.add_message(BankMsg::Send {
+				to_address: config.fee_receiver.to_string(),
+				amount: vec![creation_fee],
+			});
+		}
+	}
+	let pool_coins = two_coins(&pool_msg_info)?;
 	let new_pool_id = CanonicalPoolPairIdentifier::from([pool_coins[0].denom.clone(), pool_coins[1].denom.clone()]);
 	if get_pool_addresses_store().has(&new_pool_id) {
 		return Err(PoolFactoryContractError::PairAlreadyExists);
 	}
-	let config = PoolFactoryConfig::load_non_empty()?;
-	let is_admin = config.admin == (&msg_info.sender).try_into()?;
-	if !is_admin
-		&& !config
+	if !is_pool_creator {
+		let min_initial_liquidity = Uint128::from(rules.min_initial_liquidity);
+		if pool_coins
+			.iter()
+			.any(|pool_coin| pool_coin.amount < min_initial_liquidity)
+		{
+			return Err(PoolFactoryContractError::InsufficientInitialLiquidity(
+				min_initial_liquidity,
+			));
+		}
+		let denom_list = get_denom_list_store();
+		for pool_coin in pool_coins.iter() {
+			let allowed = match rules.denom_filter {
+				PoolFactoryDenomFilter::None => true,
+				PoolFactoryDenomFilter::Allowlist => denom_list.has(&pool_coin.denom),
+				PoolFactoryDenomFilter::Blocklist => !denom_list.has(&pool_coin.denom),
+			};
+			if !allowed {
+				return Err(PoolFactoryContractError::DenomNotAllowed(pool_coin.denom.clone()));
+			}
+		}
+	}
+	if let Some(existing_pair) = record_underlying_pair(deps.as_ref(), &new_pool_id)? {
+		if config
 			.flags
-			.contains(PoolFactoryConfigFlags::PERMISSIONLESS_POOL_CRATION)
-	{
-		return Err(CrownfiSwapsCommonError::Unauthorized("Permissionless pool creation is disabled".into()).into());
+			.contains(PoolFactoryConfigFlags::REJECT_DUPLICATE_UNDERLYING_PAIRS)
+		{
+			return Err(PoolFactoryContractError::DuplicateUnderlyingPair(
+				existing_pair.to_string(),
+			));
+		}
 	}
+	Ok(response.add_submessage(instantiate_pool_submsg(
+		&env,
+		&config,
+		&pool_msg_info,
+		&new_pool_id,
+		left_denom,
+		initial_shares_receiver,
+		is_admin,
+	)?))
+}
+
+fn process_update_pool_creation_rules(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	creation_fee: Option<Coin>,
+	min_initial_liquidity: Option<Uint128>,
+	denom_filter: Option<PoolFactoryDenomFilter>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let mut rules = PoolFactoryPoolCreationRules::load()?
+		.map(|rules| rules.into_inner())
+		.unwrap_or_default();
+	if let Some(creation_fee) = creation_fee {
+		validate_native_denom(&creation_fee.denom)?;
+		rules.creation_fee_denom = creation_fee.denom;
+		rules.creation_fee_amount = creation_fee.amount.u128();
+		if rules.creation_fee().is_some() && config.fee_receiver == SeiCanonicalAddr::zeroed() {
+			return Err(PoolFactoryContractError::CreationFeeWithoutReceiver);
+		}
+	}
+	if let Some(min_initial_liquidity) = min_initial_liquidity {
+		rules.min_initial_liquidity = min_initial_liquidity.u128();
+	}
+	if let Some(denom_filter) = denom_filter {
+		rules.denom_filter = denom_filter;
+	}
+	rules.save()?;
+	Ok(Response::new().add_attribute("action", "update_pool_creation_rules"))
+}
+
+fn process_update_denom_list(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	add: Vec<String>,
+	remove: Vec<String>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let denom_list = get_denom_list_store();
+	for denom in add.iter() {
+		validate_native_denom(denom)?;
+		denom_list.set(denom, &true)?;
+	}
+	for denom in remove.iter() {
+		denom_list.remove(denom);
+	}
+	Ok(Response::new().add_attribute("action", "update_denom_list"))
+}
+
+/// Resolves the asset the specified denom ultimately represents, looking through the configured wrapper contracts and
+/// the EVM pointers recorded in the token metadata.
+fn underlying_asset_of(
+	deps: Deps<SeiQueryWrapper>,
+	wrapper_config: &PoolFactoryWrapperConfig,
+	denom: &str,
+) -> Result<PoolFactoryUnderlyingAsset, PoolFactoryContractError> {
+	if wrapper_config.cw20_wrapper != SeiCanonicalAddr::zeroed() {
+		let cw20_wrapper = Addr::try_from(wrapper_config.cw20_wrapper)?;
+		if denom.starts_with(&format!("factory/{cw20_wrapper}/")) {
+			let cw20: Option<Addr> = deps.querier.query_wasm_smart(
+				&cw20_wrapper,
+				&CW20WrapperQueryMsg::UnwrappedAddrOf {
+					denom: denom.to_string(),
+				},
+			)?;
+			if let Some(cw20) = cw20 {
+				return Ok(PoolFactoryUnderlyingAsset::Cw20 {
+					contract: cw20.into_string(),
+				});
+			}
+		}
+	}
+	if wrapper_config.erc20_wrapper != SeiCanonicalAddr::zeroed() {
+		let erc20_wrapper = Addr::try_from(wrapper_config.erc20_wrapper)?;
+		if denom.starts_with(&format!("factory/{erc20_wrapper}/")) {
+			let erc20: Option<String> = deps.querier.query_wasm_smart(
+				&erc20_wrapper,
+				&ERC20WrapperQueryMsg::UnwrappedAddrOf {
+					denom: denom.to_string(),
+				},
+			)?;
+			if let Some(erc20) = erc20 {
+				let erc20 = erc20.to_lowercase();
+				// The ERC20 token may be the pointer contract of a bank denom
+				if let Some(pointee) = get_evm_pointers_store().get(&erc20)? {
+					return Ok(pointee.into_inner());
+				}
+				return Ok(PoolFactoryUnderlyingAsset::Erc20 { contract: erc20 });
+			}
+		}
+	}
+	Ok(PoolFactoryUnderlyingAsset::Native {
+		denom: denom.to_string(),
+	})
+}
+
+/// Records the underlying assets of the pair's denoms. Returns the pair previously recorded for the same underlying
+/// assets if it's a different one.
+fn record_underlying_pair(
+	deps: Deps<SeiQueryWrapper>,
+	pair: &CanonicalPoolPairIdentifier,
+) -> Result<Option<CanonicalPoolPairIdentifier>, PoolFactoryContractError> {
+	let wrapper_config = PoolFactoryWrapperConfig::load_or_default()?;
+	let denom_origins = get_denom_origins_store();
+	let mut underlying_assets = Vec::with_capacity(2);
+	for denom in [&pair.left, &pair.right] {
+		let underlying_asset = underlying_asset_of(deps, &wrapper_config, denom)?;
+		if !matches!(underlying_asset, PoolFactoryUnderlyingAsset::Native { .. }) {
+			denom_origins.set(denom, &underlying_asset)?;
+		}
+		underlying_assets.push(underlying_asset.to_string());
+	}
+	let [left, right]: [String; 2] = underlying_assets.try_into().expect("there should be exactly 2 assets");
+	let underlying_pair = CanonicalPoolPairIdentifier::from([left, right]);
+	let underlying_pairs = get_underlying_pairs_store();
+	match underlying_pairs.get(&underlying_pair)? {
+		Some(existing_pair) if *existing_pair != *pair => Ok(Some(existing_pair.into_inner())),
+		Some(_) => Ok(None),
+		None => {
+			underlying_pairs.set(&underlying_pair, pair)?;
+			Ok(None)
+		}
+	}
+}
+
+/// Creates the sub-message which instantiates the pool contract for `new_pool_id` with the funds sent.
+fn instantiate_pool_submsg(
+	env: &Env,
+	config: &PoolFactoryConfig,
+	msg_info: &MessageInfo,
+	new_pool_id: &CanonicalPoolPairIdentifier,
+	left_denom: String,
+	initial_shares_receiver: Option<Addr>,
+	endorsed: bool,
+) -> Result<SubMsg<SeiMsg>, PoolFactoryContractError> {
 	// This later gets referenced in the reply.
 	new_pool_id.save()?;
-	Ok(Response::new().add_submessage(SubMsg {
+	Ok(SubMsg {
 		id: INSTANTIATE_PAIR_REPLY_ID,
 		msg: CosmosMsg::from(WasmMsg::Instantiate {
 			admin: Some(env.contract.address.clone().into_string()),
@@ -188,23 +762,33 @@ fn process_create_pool(
 					total_fee_bps: config.default_total_fee_bps,
 					maker_fee_bps: config.default_maker_fee_bps,
 					inverse: left_denom == new_pool_id.right,
-					endorsed: is_admin,
+					endorsed,
+					swaps_paused: false,
+					deposits_paused: false,
+					long_term_orders: false,
+					position_tracking: false,
+					fee_on_input: false,
+					max_referral_fee_bps: config.max_referral_fee_bps,
+				},
+				lp_staking: if config.lp_incentives == SeiCanonicalAddr::zeroed() {
+					None
+				} else {
+					Some(config.lp_incentives.try_into()?)
 				},
 			})?,
-			funds: pool_coins.into(),
-			label: format!("CrownFi Sei Swap Pool {}", &new_pool_id),
+			funds: msg_info.funds.clone(),
+			label: format!("CrownFi Sei Swap Pool {}", new_pool_id),
 		}),
 		gas_limit: None,
 		reply_on: ReplyOn::Success,
-	}))
+	})
 }
 
-fn process_update_fees_for_pool(
+fn process_deprecate_pool(
 	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
 	msg_info: MessageInfo,
 	pair: [String; 2],
-	total_fee_bps: Option<u16>,
-	maker_fee_bps: Option<u16>,
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
 	nonpayable(&msg_info)?;
 	let config = PoolFactoryConfig::load_non_empty()?;
@@ -213,21 +797,300 @@ fn process_update_fees_for_pool(
 			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
 		);
 	}
+	let pair = CanonicalPoolPairIdentifier::from(pair);
 	let pool_addr = get_pool_addresses_store()
-		.get(&pair.into())?
+		.get(&pair)?
 		.ok_or(StdError::not_found("pair address"))?;
+	let graveyard = get_pool_graveyard_store();
+	if graveyard.has(&pool_addr) {
+		return Err(PoolFactoryContractError::PoolDeprecated);
+	}
+	graveyard.set(
+		&pool_addr,
+		&PoolFactoryDeprecatedPool {
+			pair: pair.clone(),
+			deprecated_at: env.block.time.seconds(),
+			replaced: false,
+		},
+	)?;
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "deprecate_pool"),
+			attr("pair", pair.to_string()),
+			attr("contract_addr", pool_addr.to_string()),
+		])
+		.add_message(WasmMsg::Execute {
+			contract_addr: pool_addr.to_string(),
+			msg: to_json_binary(&PoolPairExecuteMsg::SetPaused {
+				swaps: None,
+				deposits: Some(true),
+			})?,
+			funds: Vec::new(),
+		}))
+}
 
-	Ok(Response::new().add_message(WasmMsg::Execute {
+fn process_replace_pool(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	left_denom: String,
+	initial_shares_receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != (&msg_info.sender).try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let pool_coins = two_coins(&msg_info)?;
+	let pair = CanonicalPoolPairIdentifier::from([pool_coins[0].denom.clone(), pool_coins[1].denom.clone()]);
+	let old_pool_addr = get_pool_addresses_store()
+		.get(&pair)?
+		.ok_or(StdError::not_found("pair address"))?;
+	let graveyard = get_pool_graveyard_store();
+	let mut deprecated_pool = graveyard
+		.get(&old_pool_addr)?
+		.map(|deprecated_pool| deprecated_pool.into_inner())
+		.unwrap_or_else(|| PoolFactoryDeprecatedPool {
+			pair: pair.clone(),
+			deprecated_at: env.block.time.seconds(),
+			replaced: false,
+		});
+	deprecated_pool.replaced = true;
+	graveyard.set(&old_pool_addr, &deprecated_pool)?;
+	// The wrapper config may have changed since the old pool was created
+	record_underlying_pair(deps.as_ref(), &pair)?;
+	// Anything queued for the old pool doesn't apply to the new one. The address and code info get overwritten in the
+	// reply.
+	get_pending_pool_fees_store().remove(&pair);
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "replace_pool"),
+			attr("pair", pair.to_string()),
+			attr("old_contract_addr", old_pool_addr.to_string()),
+		])
+		// Only withdrawals remain possible on the old pool
+		.add_message(WasmMsg::Execute {
+			contract_addr: old_pool_addr.to_string(),
+			msg: to_json_binary(&PoolPairExecuteMsg::SetPaused {
+				swaps: Some(true),
+				deposits: Some(true),
+			})?,
+			funds: Vec::new(),
+		})
+		.add_submessage(instantiate_pool_submsg(
+			&env,
+			&config,
+			&msg_info,
+			&pair,
+			left_denom,
+			initial_shares_receiver,
+			true,
+		)?))
+}
+
+/// Ensures the sender is the admin or has been granted the specified role.
+fn ensure_role(
+	config: &PoolFactoryConfig,
+	msg_info: &MessageInfo,
+	role: PoolFactoryRoleFlags,
+) -> Result<(), PoolFactoryContractError> {
+	if !has_role(config, &(&msg_info.sender).try_into()?, role)? {
+		return Err(CrownfiSwapsCommonError::Unauthorized(format!(
+			"Sender is not the currently configured admin and does not have the {:?} role",
+			role
+		))
+		.into());
+	}
+	Ok(())
+}
+
+fn process_update_roles(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	address: Addr,
+	roles: Vec<PoolFactoryRole>,
+	grant: bool,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let roles_store = get_roles_store();
+	let holder = SeiCanonicalAddr::try_from(&address)?;
+	let mut holder_roles = roles_store
+		.get(&holder)?
+		.map(|roles| *roles)
+		.unwrap_or(PoolFactoryRoleFlags::empty());
+	holder_roles.set(roles.into_iter().collect(), grant);
+	if holder_roles.is_empty() {
+		roles_store.remove(&holder);
+	} else {
+		roles_store.set(&holder, &holder_roles)?;
+	}
+	Ok(Response::new().add_attributes(vec![
+		attr("action", if grant { "grant_roles" } else { "revoke_roles" }),
+		attr("address", address),
+		attr("roles", format!("{:?}", holder_roles)),
+	]))
+}
+
+fn process_set_token_metadata(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	denom: String,
+	metadata: Option<PoolFactoryTokenMetadata>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	let token_metadata_store = get_token_metadata_store();
+	let evm_pointers = get_evm_pointers_store();
+	if let Some(old_evm_pointer) = token_metadata_store
+		.get(&denom)?
+		.and_then(|old_metadata| old_metadata.into_inner().evm_pointer)
+	{
+		evm_pointers.remove(&old_evm_pointer.to_lowercase());
+	}
+	let action = if let Some(metadata) = metadata {
+		validate_native_denom(&denom)?;
+		metadata.validate()?;
+		if let Some(evm_pointer) = metadata.evm_pointer.as_ref() {
+			evm_pointers.set(
+				&evm_pointer.to_lowercase(),
+				&PoolFactoryUnderlyingAsset::Native { denom: denom.clone() },
+			)?;
+		}
+		token_metadata_store.set(&denom, &metadata)?;
+		"set_token_metadata"
+	} else {
+		token_metadata_store.remove(&denom);
+		"remove_token_metadata"
+	};
+	Ok(Response::new().add_attributes(vec![attr("action", action), attr("denom", denom)]))
+}
+
+fn update_pool_fees_msg(
+	pool_addr: &SeiCanonicalAddr,
+	total_fee_bps: Option<u16>,
+	maker_fee_bps: Option<u16>,
+	fee_on_input: Option<bool>,
+) -> Result<WasmMsg, PoolFactoryContractError> {
+	Ok(WasmMsg::Execute {
 		contract_addr: pool_addr.to_string(),
 		msg: to_json_binary(&PoolPairExecuteMsg::UpdateConfig {
-			admin: None,
 			fee_receiver: None,
 			total_fee_bps,
 			maker_fee_bps,
 			endorsed: None,
+			lp_staking: None,
+			long_term_orders: None,
+			position_tracking: None,
+			fee_on_input,
+			max_referral_fee_bps: None,
 		})?,
 		funds: Vec::new(),
-	}))
+	})
+}
+
+fn process_update_fees_for_pool(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	total_fee_bps: Option<u16>,
+	maker_fee_bps: Option<u16>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::FEE_MANAGER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair)?
+		.ok_or(StdError::not_found("pair address"))?;
+
+	if config.timelock_seconds > 0 {
+		let pool_config = query_pool_config(&deps.querier, &Addr::try_from(pool_addr.as_ref())?)?;
+		if total_fee_bps.is_some_and(|total_fee_bps| total_fee_bps > pool_config.total_fee_bps)
+			|| maker_fee_bps.is_some_and(|maker_fee_bps| maker_fee_bps > pool_config.maker_fee_bps)
+		{
+			let apply_after = env.block.time.seconds() + config.timelock_seconds as u64;
+			let pending_fees_store = get_pending_pool_fees_store();
+			// A queued `fee_on_input` change is kept, but has to wait for the new timelock as well.
+			let fee_on_input = pending_fees_store.get(&pair)?.and_then(|pending| pending.fee_on_input);
+			pending_fees_store.set(
+				&pair,
+				&PoolFactoryPendingPoolFees {
+					total_fee_bps,
+					maker_fee_bps,
+					fee_on_input,
+					apply_after,
+				},
+			)?;
+			return Ok(Response::new().add_attributes(vec![
+				attr("action", "queue_fees_for_pool"),
+				attr("pair", pair.to_string()),
+				attr("pending_apply_after", apply_after.to_string()),
+			]));
+		}
+	}
+	Ok(Response::new().add_message(update_pool_fees_msg(&pool_addr, total_fee_bps, maker_fee_bps, None)?))
+}
+
+fn process_apply_pending_fees_for_pool(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::FEE_MANAGER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair)?
+		.ok_or(StdError::not_found("pair address"))?;
+	let pending_fees_store = get_pending_pool_fees_store();
+	let pending = pending_fees_store
+		.get(&pair)?
+		.ok_or(PoolFactoryContractError::NothingPending)?;
+	if env.block.time.seconds() < pending.apply_after {
+		return Err(CrownfiSwapsCommonError::Timelocked(pending.apply_after).into());
+	}
+	pending_fees_store.remove(&pair);
+	Ok(Response::new().add_message(update_pool_fees_msg(
+		&pool_addr,
+		pending.total_fee_bps,
+		pending.maker_fee_bps,
+		pending.fee_on_input,
+	)?))
+}
+
+fn process_cancel_pending_fees_for_pool(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::FEE_MANAGER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
+	let pending_fees_store = get_pending_pool_fees_store();
+	if !pending_fees_store.has(&pair) {
+		return Err(PoolFactoryContractError::NothingPending);
+	}
+	pending_fees_store.remove(&pair);
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "cancel_pending_fees_for_pool"),
+		attr("pair", pair.to_string()),
+	]))
 }
 
 fn process_update_global_config_for_pool(
@@ -244,7 +1107,6 @@ fn process_update_global_config_for_pool(
 			.map(|(_, addr)| WasmMsg::Execute {
 				contract_addr: addr.to_string(),
 				msg: to_json_binary(&PoolPairExecuteMsg::UpdateConfig {
-					admin: None,
 					fee_receiver: Some(
 						config
 							.fee_receiver
@@ -254,6 +1116,16 @@ fn process_update_global_config_for_pool(
 					total_fee_bps: None,
 					maker_fee_bps: None,
 					endorsed: None,
+					lp_staking: Some(
+						config
+							.lp_incentives
+							.try_into()
+							.expect("address stringification shouldn't fail"),
+					),
+					long_term_orders: None,
+					position_tracking: None,
+					fee_on_input: None,
+					max_referral_fee_bps: Some(config.max_referral_fee_bps),
 				})
 				.expect("serialization shouldn't fail"),
 				funds: Vec::new(),
@@ -270,19 +1142,322 @@ fn process_update_pool_code(
 ) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
 	nonpayable(&msg_info)?;
 	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::UPGRADER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
 	let pool_addr = get_pool_addresses_store()
-		.get(&pair.into())?
+		.get(&pair)?
 		.ok_or(StdError::not_found("pair address"))?;
+	let code_info_store = get_pool_code_info_store();
+	let mut code_info = code_info_store
+		.get(&pair)?
+		.map(|code_info| code_info.into_inner())
+		.unwrap_or_default();
+	if code_info.pinned {
+		return Err(PoolFactoryContractError::PoolCodePinned);
+	}
+	code_info.code_id = Some(config.pair_code_id);
+	code_info_store.set(&pair, &code_info)?;
 	Ok(Response::new().add_message(WasmMsg::Migrate {
 		contract_addr: pool_addr.to_string(),
 		new_code_id: config.pair_code_id,
-		msg: payload.unwrap_or_default()
+		msg: migrate_payload(payload)?,
 	}))
 }
 
+/// Returns the specified migrate message, or an empty `PoolPairMigrateMsg` if none was specified.
+fn migrate_payload(payload: Option<Binary>) -> Result<Binary, PoolFactoryContractError> {
+	Ok(match payload {
+		Some(payload) => payload,
+		None => to_json_binary(&PoolPairMigrateMsg {})?,
+	})
+}
+
+fn process_migrate_pools(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	after: Option<[String; 2]>,
+	limit: Option<u32>,
+	payload: Option<Binary>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::UPGRADER)?;
+	let payload = migrate_payload(payload)?;
+	// Collected first as we're writing to storage while going through them
+	let pools = get_pool_addresses_store()
+		.iter_range(after.map(|v| v.into()), None)?
+		.take(limit.unwrap_or(u32::MAX) as usize)
+		.collect::<Vec<_>>();
+	let code_info_store = get_pool_code_info_store();
+	let mut response = Response::new().add_attribute("action", "migrate_pools");
+	let mut migrated_count = 0u32;
+	for (pair, pool_addr) in pools.iter() {
+		let mut code_info = code_info_store
+			.get(pair)?
+			.map(|code_info| code_info.into_inner())
+			.unwrap_or_default();
+		if code_info.pinned || code_info.code_id == Some(config.pair_code_id) {
+			continue;
+		}
+		code_info.code_id = Some(config.pair_code_id);
+		code_info_store.set(pair, &code_info)?;
+		response = response.add_message(WasmMsg::Migrate {
+			contract_addr: pool_addr.to_string(),
+			new_code_id: config.pair_code_id,
+			msg: payload.clone(),
+		});
+		migrated_count += 1;
+	}
+	response = response.add_attribute("migrated_count", migrated_count.to_string());
+	if let Some((last_pair, _)) = pools.last() {
+		response = response.add_attribute("last_pair", last_pair.to_string());
+	}
+	Ok(response)
+}
+
+fn process_pin_pool_code(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	pinned: bool,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::UPGRADER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
+	if !get_pool_addresses_store().has(&pair) {
+		return Err(StdError::not_found("pair address").into());
+	}
+	let code_info_store = get_pool_code_info_store();
+	let mut code_info = code_info_store
+		.get(&pair)?
+		.map(|code_info| code_info.into_inner())
+		.unwrap_or_default();
+	code_info.pinned = pinned;
+	code_info_store.set(&pair, &code_info)?;
+	Ok(Response::new().add_attributes(vec![
+		attr("action", if pinned { "pin_pool_code" } else { "unpin_pool_code" }),
+		attr("pair", pair.to_string()),
+	]))
+}
+
+fn process_reindex_pools(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	after: Option<[String; 2]>,
+	limit: Option<u32>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	// Collected first as we're writing to storage while going through them
+	let pools = get_pool_addresses_store()
+		.iter_range(after.map(|v| v.into()), None)?
+		.take(limit.unwrap_or(u32::MAX) as usize)
+		.collect::<Vec<_>>();
+	for (pair, pool_addr) in pools.iter() {
+		index_pool(pair, pool_addr)?;
+	}
+	let mut response = Response::new().add_attributes(vec![
+		attr("action", "reindex_pools"),
+		attr("indexed_count", pools.len().to_string()),
+	]);
+	if let Some((last_pair, _)) = pools.last() {
+		response = response.add_attribute("last_pair", last_pair.to_string());
+	}
+	Ok(response)
+}
+
+fn process_record_underlying_pairs(
+	deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	after: Option<[String; 2]>,
+	limit: Option<u32>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	// Collected first as we're writing to storage while going through them
+	let pools = get_pool_addresses_store()
+		.iter_range(after.map(|v| v.into()), None)?
+		.take(limit.unwrap_or(u32::MAX) as usize)
+		.collect::<Vec<_>>();
+	for (pair, _) in pools.iter() {
+		// Existing duplicates are left as they are, the first pool recorded is the one which is kept.
+		record_underlying_pair(deps.as_ref(), pair)?;
+	}
+	let mut response = Response::new().add_attributes(vec![
+		attr("action", "record_underlying_pairs"),
+		attr("recorded_count", pools.len().to_string()),
+	]);
+	if let Some((last_pair, _)) = pools.last() {
+		response = response.add_attribute("last_pair", last_pair.to_string());
+	}
+	Ok(response)
+}
+
+/// The admin may pause or unpause pools, while those with the guardian role may only pause them.
+fn ensure_can_set_paused(
+	msg_info: &MessageInfo,
+	swaps: Option<bool>,
+	deposits: Option<bool>,
+) -> Result<(), PoolFactoryContractError> {
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin == (&msg_info.sender).try_into()? {
+		return Ok(());
+	}
+	ensure_role(&config, msg_info, PoolFactoryRoleFlags::GUARDIAN)?;
+	if swaps == Some(false) || deposits == Some(false) {
+		return Err(CrownfiSwapsCommonError::Unauthorized("Only the admin can unpause pools".into()).into());
+	}
+	Ok(())
+}
+
+fn process_set_pool_paused(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	swaps: Option<bool>,
+	deposits: Option<bool>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	ensure_can_set_paused(&msg_info, swaps, deposits)?;
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair.into())?
+		.ok_or(StdError::not_found("pair address"))?;
+
+	Ok(Response::new().add_message(WasmMsg::Execute {
+		contract_addr: pool_addr.to_string(),
+		msg: to_json_binary(&PoolPairExecuteMsg::SetPaused { swaps, deposits })?,
+		funds: Vec::new(),
+	}))
+}
+
+fn process_set_all_pools_paused(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	swaps: Option<bool>,
+	deposits: Option<bool>,
+	after: Option<[String; 2]>,
+	limit: Option<u32>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	ensure_can_set_paused(&msg_info, swaps, deposits)?;
+	let msg = to_json_binary(&PoolPairExecuteMsg::SetPaused { swaps, deposits })?;
+	Ok(Response::new().add_messages(
+		get_pool_addresses_store()
+			.iter_range(after.map(|v| v.into()), None)?
+			.map(|(_, addr)| WasmMsg::Execute {
+				contract_addr: addr.to_string(),
+				msg: msg.clone(),
+				funds: Vec::new(),
+			})
+			.take(limit.unwrap_or(u32::MAX) as usize),
+	))
+}
+
+fn process_set_pool_features(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	long_term_orders: Option<bool>,
+	position_tracking: Option<bool>,
+	fee_on_input: Option<bool>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolFactoryConfig::load_non_empty()?;
+	ensure_role(&config, &msg_info, PoolFactoryRoleFlags::FEE_MANAGER)?;
+	let pair = CanonicalPoolPairIdentifier::from(pair);
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair)?
+		.ok_or(StdError::not_found("pair address"))?;
+
+	if let Some(fee_on_input) = fee_on_input.filter(|_| config.timelock_seconds > 0) {
+		// Moving the fee to the other side of the swap changes what traders receive, so it's queued like a fee increase.
+		let pool_config = query_pool_config(&deps.querier, &Addr::try_from(pool_addr.as_ref())?)?;
+		if fee_on_input != pool_config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT) {
+			let apply_after = env.block.time.seconds() + config.timelock_seconds as u64;
+			let pending_fees_store = get_pending_pool_fees_store();
+			let mut pending = pending_fees_store
+				.get(&pair)?
+				.map(|pending| pending.into_inner())
+				.unwrap_or_default();
+			pending.fee_on_input = Some(fee_on_input);
+			pending.apply_after = apply_after;
+			pending_fees_store.set(&pair, &pending)?;
+			return Ok(Response::new().add_attributes(vec![
+				attr("action", "queue_fees_for_pool"),
+				attr("pair", pair.to_string()),
+				attr("pending_apply_after", apply_after.to_string()),
+			]));
+		}
+	}
+
+	Ok(Response::new().add_message(WasmMsg::Execute {
+		contract_addr: pool_addr.to_string(),
+		msg: to_json_binary(&PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders,
+			position_tracking,
+			fee_on_input,
+			max_referral_fee_bps: None,
+		})?,
+		funds: Vec::new(),
+	}))
+}
+
+fn process_register_incentive_program(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	pair: [String; 2],
+	start_time: Option<u64>,
+	end_time: u64,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	let config = PoolFactoryConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if config.lp_incentives == SeiCanonicalAddr::zeroed() {
+		return Err(PoolFactoryContractError::LpIncentivesNotConfigured);
+	}
+	let pool_addr = get_pool_addresses_store()
+		.get(&pair.into())?
+		.ok_or(StdError::not_found("pair address"))?;
+	let lp_denom = format!("factory/{}/{}", pool_addr.as_ref(), LP_SUBDENOM);
+	Ok(Response::new()
+		.add_attributes(vec![
+			attr("action", "register_incentive_program"),
+			attr("lp_denom", &lp_denom),
+		])
+		.add_message(WasmMsg::Execute {
+			contract_addr: config.lp_incentives.to_string(),
+			msg: to_json_binary(&LpIncentivesExecuteMsg::AddRewardProgram {
+				lp_denom,
+				start_time,
+				end_time,
+			})?,
+			funds: msg_info.funds,
+		}))
+}
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn query(
-	_deps: Deps<SeiQueryWrapper>,
+	deps: Deps<SeiQueryWrapper>,
 	_env: Env,
 	msg: PoolFactoryQueryMsg,
 ) -> Result<Binary, PoolFactoryContractError> {
@@ -290,14 +1465,289 @@ pub fn query(
 		PoolFactoryQueryMsg::Config => to_json_binary(&PoolFactoryConfigJsonable::try_from(
 			PoolFactoryConfig::load_non_empty()?.as_ref(),
 		)?)?,
-		PoolFactoryQueryMsg::PairAddr { pair } => to_json_binary(
-			&get_pool_addresses_store()
-				.get(&pair.into())?
-				.map(|addr| Addr::try_from(addr.as_ref()))
+		PoolFactoryQueryMsg::PendingAdmin => to_json_binary(
+			&PendingAdminTransfer::load()?
+				.map(|pending| PendingAdminTransferJsonable::try_from(pending.as_ref()))
 				.transpose()?,
 		)?,
+		PoolFactoryQueryMsg::PendingConfig => {
+			to_json_binary(&PoolFactoryPendingConfig::load()?.map(|pending| pending.into_inner()))?
+		}
+		PoolFactoryQueryMsg::PendingFeesForPool { pair } => to_json_binary(
+			&get_pending_pool_fees_store()
+				.get(&pair.into())?
+				.map(|pending| pending.into_inner()),
+		)?,
+		PoolFactoryQueryMsg::PoolCreationRules => to_json_binary(&PoolFactoryPoolCreationRulesJsonable::from(
+			PoolFactoryPoolCreationRules::load()?
+				.map(|rules| rules.into_inner())
+				.unwrap_or_default(),
+		))?,
+		PoolFactoryQueryMsg::DenomList { after, limit } => to_json_binary(
+			&get_denom_list_store()
+				.iter_range(after, None)?
+				.map(|(denom, _)| denom)
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.collect::<Vec<_>>(),
+		)?,
+		PoolFactoryQueryMsg::Wrappers => to_json_binary(&PoolFactoryWrapperConfigJsonable::try_from(
+			&PoolFactoryWrapperConfig::load_or_default()?,
+		)?)?,
+		PoolFactoryQueryMsg::UnderlyingAsset { denom } => to_json_binary(&underlying_asset_of(
+			deps,
+			&PoolFactoryWrapperConfig::load_or_default()?,
+			&denom,
+		)?)?,
+		PoolFactoryQueryMsg::TokenMetadata { denom } => to_json_binary(
+			&get_token_metadata_store()
+				.get(&denom)?
+				.map(|metadata| metadata.into_inner()),
+		)?,
+		PoolFactoryQueryMsg::TokenMetadataBatch { denoms } => {
+			let token_metadata_store = get_token_metadata_store();
+			let mut result = Vec::with_capacity(denoms.len());
+			for denom in denoms {
+				if let Some(metadata) = token_metadata_store.get(&denom)? {
+					result.push(PoolFactoryDenomTokenMetadata {
+						denom,
+						metadata: metadata.into_inner(),
+					});
+				}
+			}
+			to_json_binary(&result)?
+		}
+		PoolFactoryQueryMsg::AllTokenMetadata { after, limit } => to_json_binary(
+			&get_token_metadata_store()
+				.iter_range(after, None)?
+				.map(|(denom, metadata)| PoolFactoryDenomTokenMetadata {
+					denom,
+					metadata: metadata.into_inner(),
+				})
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.collect::<Vec<_>>(),
+		)?,
+		PoolFactoryQueryMsg::Roles { address } => to_json_binary(&Vec::<PoolFactoryRole>::from(
+			get_roles_store()
+				.get(&(&address).try_into()?)?
+				.map(|roles| *roles)
+				.unwrap_or(PoolFactoryRoleFlags::empty()),
+		))?,
+		PoolFactoryQueryMsg::RoleHolders { after, limit } => to_json_binary(
+			&get_roles_store()
+				.iter_range(after.map(|after| SeiCanonicalAddr::try_from(&after)).transpose()?, None)?
+				.map(|(address, roles)| {
+					Ok(PoolFactoryRoleHolder {
+						address: address.try_into()?,
+						roles: (*roles).into(),
+					})
+				})
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.collect::<Result<Vec<_>, StdError>>()?,
+		)?,
+		PoolFactoryQueryMsg::PoolCodeVersions { after, limit } => {
+			let code_info_store = get_pool_code_info_store();
+			to_json_binary(
+				&get_pool_addresses_store()
+					.iter_range(after.map(|after| after.into()), None)?
+					.take(limit.unwrap_or(u32::MAX) as usize)
+					.map(|(pair, address)| {
+						let code_info = code_info_store
+							.get(&pair)?
+							.map(|code_info| code_info.into_inner())
+							.unwrap_or_default();
+						Ok(PoolFactoryPoolCodeVersion {
+							canonical_pair: pair.into(),
+							address: address.as_ref().try_into()?,
+							code_id: code_info.code_id,
+							pinned: code_info.pinned,
+						})
+					})
+					.collect::<Result<Vec<_>, StdError>>()?,
+			)?
+		}
+		PoolFactoryQueryMsg::PairAddr { pair } => {
+			let pair = CanonicalPoolPairIdentifier::from(pair);
+			to_json_binary(
+				&get_pool_addresses_store()
+					.get(&pair)?
+					.map(|address| {
+						Ok::<_, StdError>(PoolFactoryCreatedPair {
+							address: address.as_ref().try_into()?,
+							deprecated: get_pool_graveyard_store().has(&address),
+							canonical_pair: pair.into(),
+						})
+					})
+					.transpose()?,
+			)?
+		}
+		PoolFactoryQueryMsg::PairByAddr { address } => {
+			let canon_address = SeiCanonicalAddr::try_from(&address)?;
+			to_json_binary(
+				&get_pool_pairs_store()
+					.get(&canon_address)?
+					.map(|pair| PoolFactoryCreatedPair {
+						canonical_pair: pair.into_inner().into(),
+						address,
+						deprecated: get_pool_graveyard_store().has(&canon_address),
+					}),
+			)?
+		}
+		PoolFactoryQueryMsg::PairsContainingDenom { denom, after, limit } => {
+			let pair_addr_store = get_pool_addresses_store();
+			let graveyard = get_pool_graveyard_store();
+			let other_denoms = get_denom_pairs_store()
+				.get(&denom)?
+				.map(|denom_pairs| denom_pairs.into_inner().other_denoms)
+				.unwrap_or_default();
+			let start_index = match after {
+				Some(after) => other_denoms.partition_point(|other_denom| *other_denom <= after),
+				None => 0,
+			};
+			to_json_binary(
+				&other_denoms[start_index..]
+					.iter()
+					.take(limit.unwrap_or(u32::MAX) as usize)
+					.map(|other_denom| {
+						let pair = CanonicalPoolPairIdentifier::from((denom.clone(), other_denom.clone()));
+						let address = pair_addr_store.get(&pair)?.ok_or(StdError::not_found("pair address"))?;
+						Ok(PoolFactoryCreatedPair {
+							canonical_pair: pair.into(),
+							address: address.as_ref().try_into()?,
+							deprecated: graveyard.has(&address),
+						})
+					})
+					.collect::<Result<Vec<_>, StdError>>()?,
+			)?
+		}
+		PoolFactoryQueryMsg::PairInfo { pair } => {
+			let pair = CanonicalPoolPairIdentifier::from(pair);
+			let address = Addr::try_from(
+				get_pool_addresses_store()
+					.get(&pair)?
+					.ok_or(StdError::not_found("pair address"))?
+					.as_ref(),
+			)?;
+			let total_shares = query_pool_total_shares(&deps.querier, &address)?;
+			let deprecated = get_pool_graveyard_store().has(&(&address).try_into()?);
+			let pool = PoolPairRawState::query_with_pair_id(&deps.querier, address, pair)?;
+			let config = PoolPairConfigJsonable::try_from(&pool.config)?;
+			to_json_binary(&PoolFactoryPairInfo {
+				reserves: pool.reserves(),
+				canonical_pair: pool.pair_id.into(),
+				address: pool.address,
+				endorsed: config.endorsed,
+				deprecated,
+				config,
+				total_shares,
+			})?
+		}
+		PoolFactoryQueryMsg::DeprecatedPairs { after, limit } => to_json_binary(
+			&get_pool_graveyard_store()
+				.iter_range(after.map(|after| SeiCanonicalAddr::try_from(&after)).transpose()?, None)?
+				.map(|(address, deprecated_pool)| {
+					let deprecated_pool = deprecated_pool.into_inner();
+					Ok(PoolFactoryDeprecatedPair {
+						canonical_pair: deprecated_pool.pair.into(),
+						address: address.try_into()?,
+						deprecated_at: deprecated_pool.deprecated_at,
+						replaced: deprecated_pool.replaced,
+					})
+				})
+				.take(limit.unwrap_or(u32::MAX) as usize)
+				.collect::<Result<Vec<_>, StdError>>()?,
+		)?,
+		PoolFactoryQueryMsg::BatchSimulate { requests } => {
+			if requests.len() > POOL_SCAN_MAX_LIMIT as usize {
+				return Err(PoolFactoryContractError::TooManyRequests(POOL_SCAN_MAX_LIMIT));
+			}
+			let pair_addr_store = get_pool_addresses_store();
+			let mut results = Vec::with_capacity(requests.len());
+			for request in requests {
+				let pair = CanonicalPoolPairIdentifier::from(request.pair);
+				let pool_addr = Addr::try_from(
+					pair_addr_store
+						.get(&pair)?
+						.ok_or(StdError::not_found("pair address"))?
+						.as_ref(),
+				)?;
+				let pool = PoolPairRawState::query_with_pair_id(&deps.querier, pool_addr, pair)?;
+				let result = pool.simulate_swap(&request.offer, 0)?;
+				results.push(PoolFactoryBatchSimulateResult {
+					canonical_pair: pool.pair_id.into(),
+					address: pool.address,
+					offer: request.offer,
+					result,
+				});
+			}
+			to_json_binary(&results)?
+		}
+		PoolFactoryQueryMsg::PoolsSnapshot { after, limit } => {
+			let limit = limit.unwrap_or(POOL_SCAN_DEFAULT_LIMIT).min(POOL_SCAN_MAX_LIMIT) as usize;
+			let pools = get_pool_addresses_store()
+				.iter_range(after.map(|after| after.into()), None)?
+				.take(limit)
+				.collect::<Vec<_>>();
+			let mut snapshots = Vec::with_capacity(pools.len());
+			for (pair, pool_addr) in pools.into_iter() {
+				let pool_addr = Addr::try_from(pool_addr.as_ref())?;
+				let total_shares = query_pool_total_shares(&deps.querier, &pool_addr)?;
+				let pool = PoolPairRawState::query_with_pair_id(&deps.querier, pool_addr, pair)?;
+				let spot_price = if pool.balances.iter().any(|balance| balance.is_zero()) {
+					None
+				} else {
+					Some(Decimal::checked_from_ratio(pool.balances[1], pool.balances[0])?)
+				};
+				snapshots.push(PoolFactoryPoolSnapshot {
+					reserves: pool.reserves(),
+					total_shares,
+					total_fee_bps: pool.config.total_fee_bps,
+					maker_fee_bps: pool.config.maker_fee_bps,
+					spot_price,
+					swaps_paused: pool.config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED),
+					canonical_pair: pool.pair_id.into(),
+					address: pool.address,
+				});
+			}
+			to_json_binary(&snapshots)?
+		}
+		PoolFactoryQueryMsg::UserPositions { address, after, limit } => {
+			let limit = limit.unwrap_or(POOL_SCAN_DEFAULT_LIMIT).min(POOL_SCAN_MAX_LIMIT) as usize;
+			let pools = get_pool_addresses_store()
+				.iter_range(after.map(|after| after.into()), None)?
+				.take(limit)
+				.collect::<Vec<_>>();
+			let mut positions = Vec::new();
+			for (pair, pool_addr) in pools.iter() {
+				let pool_addr = Addr::try_from(pool_addr.as_ref())?;
+				let shares = deps
+					.querier
+					.query_balance(&address, format!("factory/{}/{}", pool_addr, LP_SUBDENOM))?
+					.amount;
+				if shares.is_zero() {
+					continue;
+				}
+				let total_shares = query_pool_total_shares(&deps.querier, &pool_addr)?;
+				let pool = PoolPairRawState::query_with_pair_id(&deps.querier, pool_addr, pair.clone())?;
+				positions.push(PoolFactoryUserPosition {
+					share_value: balances_into_share_value(shares, total_shares, pool.reserves()),
+					canonical_pair: pool.pair_id.into(),
+					address: pool.address,
+					shares,
+					pool_share: Decimal::checked_from_ratio(shares, total_shares).unwrap_or_default(),
+				});
+			}
+			to_json_binary(&PoolFactoryUserPositions {
+				positions,
+				next: if pools.len() == limit {
+					pools.last().map(|(pair, _)| pair.clone().into())
+				} else {
+					None
+				},
+			})?
+		}
 		PoolFactoryQueryMsg::Pairs { after, limit } => {
 			let pair_addr_store = get_pool_addresses_store();
+			let graveyard = get_pool_graveyard_store();
 			to_json_binary(
 				&pair_addr_store
 					.iter_range(after.map(|after| after.into()), None)?
@@ -307,6 +1757,7 @@ pub fn query(
 							.as_ref()
 							.try_into()
 							.expect("address stringification shouldn't fail"),
+						deprecated: graveyard.has(&address),
 					})
 					.take(limit.unwrap_or(u32::MAX) as usize)
 					.collect::<Vec<_>>(),
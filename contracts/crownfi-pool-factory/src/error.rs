@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Coin, StdError, Uint128};
+use crownfi_pool_pair_contract::error::PoolPairContractError;
 use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_std_error_common};
 use cw_utils::{ParseReplyError, PaymentError};
 use thiserror::Error;
@@ -11,10 +12,34 @@ pub enum PoolFactoryContractError {
 	SwapsCommon(#[from] CrownfiSwapsCommonError),
 	#[error("Payment error: {0}")]
 	PaymentError(#[from] PaymentError),
+	#[error("{0}")]
+	PoolPair(#[from] PoolPairContractError),
 	#[error("Failed reply: {0}")]
 	FailedReply(#[from] ParseReplyError),
 	#[error("Pair already exists")]
 	PairAlreadyExists,
+	#[error("There are no pending changes")]
+	NothingPending,
+	#[error("No LP incentives contract has been configured")]
+	LpIncentivesNotConfigured,
+	#[error("A pool creation fee of {0} is required")]
+	CreationFeeRequired(Coin),
+	#[error("A pool creation fee cannot be charged without a fee receiver")]
+	CreationFeeWithoutReceiver,
+	#[error("At least {0} of each denom must be provided when creating a pool")]
+	InsufficientInitialLiquidity(Uint128),
+	#[error("Pools cannot be created for {0}")]
+	DenomNotAllowed(String),
+	#[error("A pool with the same underlying assets already exists: {0}")]
+	DuplicateUnderlyingPair(String),
+	#[error("Pool has been deprecated")]
+	PoolDeprecated,
+	#[error("Pool code is pinned")]
+	PoolCodePinned,
+	#[error("At most {0} requests can be made at once")]
+	TooManyRequests(u32),
+	#[error("Fees cannot exceed 10000 bps, and the maker fee cannot exceed the total fee")]
+	InvalidFeeBps,
 }
 
 impl_from_cosmwasm_std_error_common!(PoolFactoryContractError);
@@ -2,3 +2,6 @@ pub mod contract;
 pub mod error;
 pub mod msg;
 pub mod state;
+
+#[cfg(test)]
+mod tests;
@@ -1,41 +1,130 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
+use crownfi_pool_pair_contract::{contract::pool::PoolPairCalcSwapResult, state::PoolPairConfigJsonable};
+use crownfi_swaps_common::data_types::pending_admin::PendingAdminTransferJsonable;
 
-use crate::state::PoolFactoryConfigJsonable;
+use crate::state::{
+	PoolFactoryConfigJsonable, PoolFactoryDenomFilter, PoolFactoryPendingConfig, PoolFactoryPendingPoolFees,
+	PoolFactoryPoolCreationRulesJsonable, PoolFactoryRole, PoolFactoryTokenMetadata, PoolFactoryUnderlyingAsset,
+	PoolFactoryWrapperConfigJsonable,
+};
 
 /// This structure stores the basic settings for creating a new factory contract.
 #[cw_serde]
 pub struct PoolFactoryInstantiateMsg {
 	pub config: PoolFactoryConfigJsonable,
+	/// Granted the `guardian` role, which is able to pause pools in case of an emergency.
+	#[serde(default)]
+	pub guardian: Option<Addr>,
 }
 
+/// Sent when upgrading the factory contract. Pools created before their underlying assets were recorded can be
+/// backfilled afterwards with `RecordUnderlyingPairs`.
+#[cw_serde]
+pub struct PoolFactoryMigrateMsg {}
+
 /// This structure describes the execute messages of the contract.
 #[cw_serde]
 pub enum PoolFactoryExecuteMsg {
 	/// UpdateConfig updates relevant code IDs
+	///
+	/// If a timelock is configured, changes to `pair_code_id`, fee increases, and timelock decreases are queued and
+	/// must be applied with `ApplyPendingConfig` once the timelock has elapsed. Newly queued changes are added to those
+	/// already queued and restart the timelock. Changes which take effect immediately cancel any queued change to the
+	/// same setting.
 	UpdateConfig {
-		/// The head honcho
-		admin: Option<Addr>,
-		/// Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+		/// Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. Cannot be
+		/// disabled while there's a pool creation fee.
 		fee_receiver: Option<Addr>,
 		/// Code to use when instantiating new pool pairs
 		pair_code_id: Option<u64>,
+		/// The LP incentives contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. New pools will
+		/// use this for `auto_stake`, existing pools can be updated with `UpdateGlobalConfigForPool`.
+		lp_incentives: Option<Addr>,
 		/// The total fees (in bps) charged by a pair of this type
 		default_total_fee_bps: Option<u16>,
 		/// The amount of fees (in bps) collected by the Maker contract from this pair type
 		default_maker_fee_bps: Option<u16>,
 		/// If true, everyone will be able to create new trading pairs
 		permissionless_pool_cration: Option<bool>,
+		/// How long (in seconds) sensitive changes must be queued for before they can be applied
+		timelock_seconds: Option<u32>,
+		/// The CW20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+		cw20_wrapper: Option<Addr>,
+		/// The ERC20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+		erc20_wrapper: Option<Addr>,
+		/// If true, pools can't be created for pairs which share the same underlying assets as an existing pool
+		reject_duplicate_underlying_pairs: Option<bool>,
+		/// The highest referral fee (in bps) swaps on the pairs can pay. Existing pools can be updated with
+		/// `UpdateGlobalConfigForPool`.
+		max_referral_fee_bps: Option<u16>,
 	},
+	/// Applies the config changes queued by `UpdateConfig` once the timelock has elapsed.
+	ApplyPendingConfig {},
+	/// Discards the config changes queued by `UpdateConfig`.
+	CancelPendingConfig {},
+	/// Proposes a new admin, the transfer only takes effect once they accept it with `AcceptAdmin`. This replaces any
+	/// previously proposed admin. If a timelock is configured, the transfer can only be accepted after it elapses.
+	ProposeAdmin {
+		/// The proposed head honcho
+		admin: Addr,
+	},
+	/// Accepts a previously proposed admin transfer. Must be sent by the proposed admin.
+	AcceptAdmin {},
+	/// Cancels a previously proposed admin transfer.
+	CancelAdminTransfer {},
+	/// Proposes a new admin for the specified pool, who must then accept it by sending `AcceptAdmin` to the pool. Once
+	/// accepted, the pool can no longer be managed through this contract.
+	ProposePoolAdmin {
+		/// The trading pair to hand over. The associated pool contract must have already been created.
+		pair: [String; 2],
+		/// The proposed head honcho of the pool
+		admin: Addr,
+	},
+	/// Cancels an admin transfer proposed with `ProposePoolAdmin`.
+	CancelPoolAdminTransfer { pair: [String; 2] },
+	/// Updates the anti-spam measures for permissionless pool creation. These don't apply to the admin or those with
+	/// the `pool_creator` role.
+	UpdatePoolCreationRules {
+		/// Sent to the `fee_receiver` when creating a pool, set the amount to 0 to disable. Requires a `fee_receiver`.
+		creation_fee: Option<Coin>,
+		/// The minimum amount of each denom which must be provided when creating a pool
+		min_initial_liquidity: Option<Uint128>,
+		/// Whether the denom list is used as an allowlist or a blocklist
+		denom_filter: Option<PoolFactoryDenomFilter>,
+	},
+	/// Adds and removes denoms from the list used by the pool creation rules' `denom_filter`.
+	UpdateDenomList { add: Vec<String>, remove: Vec<String> },
 	/// CreatePool instantiates a new pair pool contract.
-	/// The pair is determined by the initial liquidity funds sent to this contract
+	/// The pair is determined by the initial liquidity funds sent to this contract. If permissionless pool creation is
+	/// disabled, this requires the `pool_creator` role.
+	///
+	/// Unless the sender has the `pool_creator` role, the pool creation rules apply. If there's a creation fee, it
+	/// must be sent along with the initial liquidity.
 	CreatePool {
 		/// As funds must be given in alphabetical order, this is used to determine whether or not the pair should be
 		/// inversed when presented to the user
 		left_denom: String,
 		initial_shares_receiver: Option<Addr>,
 	},
-	/// Sets the specified trading pair to have the specified fees.
+	/// Marks the specified pool as deprecated and pauses deposits for it. It remains the pool for its pair until it's
+	/// replaced with `ReplacePool`.
+	DeprecatePool {
+		/// The trading pair to deprecate. The associated pool contract must have already been created.
+		pair: [String; 2],
+	},
+	/// Creates a new pool which takes over the pair from the existing one, which gets paused and moved to the deprecated
+	/// pools. The pair is determined by the initial liquidity funds sent to this contract, same as `CreatePool`.
+	ReplacePool {
+		/// As funds must be given in alphabetical order, this is used to determine whether or not the pair should be
+		/// inversed when presented to the user
+		left_denom: String,
+		initial_shares_receiver: Option<Addr>,
+	},
+	/// Sets the specified trading pair to have the specified fees. Requires the `fee_manager` role.
+	///
+	/// If a timelock is configured, fee increases are queued and must be applied with `ApplyPendingFeesForPool` once
+	/// the timelock has elapsed.
 	UpdateFeesForPool {
 		/// The trading pair to change. The associated pool contract must have already been created.
 		pair: [String; 2],
@@ -44,9 +133,13 @@ pub enum PoolFactoryExecuteMsg {
 		/// The maker fee, where 10000 is 100%. Must be less than `total_fee_bps`.
 		maker_fee_bps: Option<u16>,
 	},
+	/// Applies the fee changes queued by `UpdateFeesForPool` and `SetPoolFeeOnInput` once the timelock has elapsed.
+	ApplyPendingFeesForPool { pair: [String; 2] },
+	/// Discards the fee changes queued by `UpdateFeesForPool` and `SetPoolFeeOnInput`.
+	CancelPendingFeesForPool { pair: [String; 2] },
 	/// Syncs the non-fee-amount configuration options for all pools.
 	/// 
-	/// Currently this only syncs the maker fee receiver.
+	/// Currently this syncs the maker fee receiver and the LP staking contract used for `auto_stake`.
 	UpdateGlobalConfigForPool {
 		/// Pool pair config is updated in lexicographical order. If you need to execute this instruction accross
 		/// multiple transactions, this is where you can specify to pick up where you left off.
@@ -54,11 +147,126 @@ pub enum PoolFactoryExecuteMsg {
 		/// The limit amount of pools to update, by default, all pools will be updated.
 		limit: Option<u32>,
 	},
-	/// Upgrades the specified pool pair to the `pair_code_id` as specified in this contract's config.
+	/// Upgrades the specified pool pair to the `pair_code_id` as specified in this contract's config. Requires the
+	/// `upgrader` role. Fails if the pool has been pinned.
 	UpdatePoolCode {
 		pair: [String; 2],
-		payload: Option<Binary>
-	}
+		/// The migrate message sent to the pool, defaults to `{}`.
+		payload: Option<Binary>,
+	},
+	/// Upgrades all pools to the `pair_code_id` as specified in this contract's config. Requires the `upgrader` role.
+	///
+	/// Pinned pools and pools already running the configured code are skipped. The last pool considered is emitted as
+	/// the `last_pair` attribute.
+	MigratePools {
+		/// Pools are considered in lexicographical order. If you need to execute this instruction accross multiple
+		/// transactions, this is where you can specify to pick up where you left off.
+		after: Option<[String; 2]>,
+		/// The limit amount of pools to consider, by default, all pools will be considered.
+		limit: Option<u32>,
+		/// The migrate message sent to each pool, defaults to `{}`.
+		payload: Option<Binary>,
+	},
+	/// Pins or unpins the specified pool's code. Pinned pools aren't upgraded, which allows rolling out new code to a
+	/// subset of pools. Requires the `upgrader` role.
+	PinPoolCode {
+		/// The trading pair to change. The associated pool contract must have already been created.
+		pair: [String; 2],
+		pinned: bool,
+	},
+	/// Pauses or unpauses swaps and deposits for the specified pool. Withdrawals are always allowed.
+	///
+	/// The admin can do both, while those with the `guardian` role can only pause.
+	SetPoolPaused {
+		/// The trading pair to change. The associated pool contract must have already been created.
+		pair: [String; 2],
+		/// If specified, enables or disables swaps
+		swaps: Option<bool>,
+		/// If specified, enables or disables providing liquidity
+		deposits: Option<bool>,
+	},
+	/// Same as `SetPoolPaused`, but for all pools.
+	SetAllPoolsPaused {
+		/// If specified, enables or disables swaps
+		swaps: Option<bool>,
+		/// If specified, enables or disables providing liquidity
+		deposits: Option<bool>,
+		/// Pools are updated in lexicographical order. If you need to execute this instruction accross multiple
+		/// transactions, this is where you can specify to pick up where you left off.
+		after: Option<[String; 2]>,
+		/// The limit amount of pools to update, by default, all pools will be updated.
+		limit: Option<u32>,
+	},
+	/// Enables or disables placing long-term orders on the specified pool. Existing orders are always settled. Requires
+	/// the `fee_manager` role.
+	SetPoolLongTermOrders {
+		/// The trading pair to change. The associated pool contract must have already been created.
+		pair: [String; 2],
+		enabled: bool,
+	},
+	/// Enables or disables recording the cost basis of each address's deposits on the specified pool, which is used by
+	/// the pool's `PositionReport` query. Existing positions are still reduced on withdrawals. Requires the
+	/// `fee_manager` role.
+	SetPoolPositionTracking {
+		/// The trading pair to change. The associated pool contract must have already been created.
+		pair: [String; 2],
+		enabled: bool,
+	},
+	/// Sets whether the specified pool takes swap fees from the offered amount rather than the result. Requires the
+	/// `fee_manager` role.
+	///
+	/// If a timelock is configured, changes are queued along with any other pending fee changes for the pool, and must
+	/// be applied with `ApplyPendingFeesForPool` once the timelock has elapsed.
+	SetPoolFeeOnInput {
+		/// The trading pair to change. The associated pool contract must have already been created.
+		pair: [String; 2],
+		enabled: bool,
+	},
+	/// Adds pools to the reverse and per-denom indexes used by `PairByAddr` and `PairsContainingDenom`. Pools created
+	/// after these indexes were introduced are indexed automatically, this is only needed for older pools.
+	ReindexPools {
+		/// Pools are indexed in lexicographical order. If you need to execute this instruction accross multiple
+		/// transactions, this is where you can specify to pick up where you left off.
+		after: Option<[String; 2]>,
+		/// The limit amount of pools to index, by default, all pools will be indexed.
+		limit: Option<u32>,
+	},
+	/// Records the underlying assets of pools, which `reject_duplicate_underlying_pairs` relies on. Pools created after
+	/// these were introduced are recorded automatically, this is only needed for older pools.
+	RecordUnderlyingPairs {
+		/// Pools are recorded in lexicographical order. If you need to execute this instruction accross multiple
+		/// transactions, this is where you can specify to pick up where you left off.
+		after: Option<[String; 2]>,
+		/// The limit amount of pools to record, by default, all pools will be recorded.
+		limit: Option<u32>,
+	},
+	/// Sets the verified metadata for the specified denom, replacing any which was previously set.
+	SetTokenMetadata {
+		denom: String,
+		metadata: PoolFactoryTokenMetadata,
+	},
+	/// Removes the verified metadata for the specified denom.
+	RemoveTokenMetadata { denom: String },
+	/// Grants the specified roles to the specified address. The admin implicitly has every role.
+	GrantRoles {
+		address: Addr,
+		roles: Vec<PoolFactoryRole>,
+	},
+	/// Revokes the specified roles from the specified address.
+	RevokeRoles {
+		address: Addr,
+		roles: Vec<PoolFactoryRole>,
+	},
+	/// Registers a reward program for the specified pool with the configured LP incentives contract. The rewards must
+	/// be sent along with this message.
+	RegisterIncentiveProgram {
+		/// The trading pair to incentivise. The associated pool contract must have already been created.
+		pair: [String; 2],
+		/// Unix timestamp (in seconds) of when rewards start being released. Defaults to now.
+		start_time: Option<u64>,
+		/// Unix timestamp (in seconds) of when all rewards will have been released.
+		end_time: u64,
+	},
 }
 
 /// This structure describes the available query messages for the factory contract.
@@ -68,9 +276,95 @@ pub enum PoolFactoryQueryMsg {
 	/// Config returns contract settings specified in the custom [`ConfigResponse`] structure.
 	#[returns(PoolFactoryConfigJsonable)]
 	Config,
+	/// Returns the proposed admin transfer, if any.
+	#[returns(Option<PendingAdminTransferJsonable>)]
+	PendingAdmin,
+	/// Returns the config changes waiting for the timelock to elapse, if any.
+	#[returns(Option<PoolFactoryPendingConfig>)]
+	PendingConfig,
+	/// Returns the fee changes for the specified pool waiting for the timelock to elapse, if any.
+	#[returns(Option<PoolFactoryPendingPoolFees>)]
+	PendingFeesForPool { pair: [String; 2] },
+	/// Returns the anti-spam measures for permissionless pool creation.
+	#[returns(PoolFactoryPoolCreationRulesJsonable)]
+	PoolCreationRules,
+	/// Returns the denoms used by the pool creation rules' `denom_filter`, in lexicographical order.
+	#[returns(Vec<String>)]
+	DenomList { after: Option<String>, limit: Option<u32> },
+	/// Returns the token wrapper contracts used to resolve the underlying asset of wrapped denoms.
+	#[returns(PoolFactoryWrapperConfigJsonable)]
+	Wrappers,
+	/// Returns the asset the specified denom ultimately represents.
+	#[returns(PoolFactoryUnderlyingAsset)]
+	UnderlyingAsset { denom: String },
+	/// Returns the verified metadata for the specified denom, if any.
+	#[returns(Option<PoolFactoryTokenMetadata>)]
+	TokenMetadata { denom: String },
+	/// Returns the verified metadata for the specified denoms. Denoms without verified metadata are omitted.
+	#[returns(Vec<PoolFactoryDenomTokenMetadata>)]
+	TokenMetadataBatch { denoms: Vec<String> },
+	/// Returns all verified token metadata, ordered by denom.
+	#[returns(Vec<PoolFactoryDenomTokenMetadata>)]
+	AllTokenMetadata { after: Option<String>, limit: Option<u32> },
+	/// Returns the roles explicitly granted to the specified address.
+	#[returns(Vec<PoolFactoryRole>)]
+	Roles { address: Addr },
+	/// Returns all addresses which have been granted roles, ordered by their canonical address.
+	#[returns(Vec<PoolFactoryRoleHolder>)]
+	RoleHolders { after: Option<Addr>, limit: Option<u32> },
+	/// Returns the code each pool is running and whether or not it has been pinned.
+	#[returns(Vec<PoolFactoryPoolCodeVersion>)]
+	PoolCodeVersions {
+		after: Option<[String; 2]>,
+		limit: Option<u32>,
+	},
 	/// Gets the contract address for a pair. The result may include the inverse pair if it exists.
-	#[returns(Option<Addr>)]
+	#[returns(Option<PoolFactoryCreatedPair>)]
 	PairAddr { pair: [String; 2] },
+	/// Returns the pair the specified address is the pool contract for, if it was created by this factory.
+	#[returns(Option<PoolFactoryCreatedPair>)]
+	PairByAddr { address: Addr },
+	/// Returns all pairs which contain the specified denom, ordered by the other denom in the pair.
+	#[returns(Vec<PoolFactoryCreatedPair>)]
+	PairsContainingDenom {
+		denom: String,
+		/// The other denom of the last pair returned by a previous query
+		after: Option<String>,
+		limit: Option<u32>,
+	},
+	/// Returns the pool's address, config, reserves, and total shares.
+	#[returns(PoolFactoryPairInfo)]
+	PairInfo { pair: [String; 2] },
+	/// Returns all pools which have been deprecated, including those which have been replaced, ordered by their
+	/// canonical address.
+	#[returns(Vec<PoolFactoryDeprecatedPair>)]
+	DeprecatedPairs { after: Option<Addr>, limit: Option<u32> },
+	/// Simulates swaps for multiple pools at once. This reads the pools' storage directly rather than querying each
+	/// of them. At most 100 requests can be made at once.
+	#[returns(Vec<PoolFactoryBatchSimulateResult>)]
+	BatchSimulate {
+		requests: Vec<PoolFactoryBatchSimulateRequest>,
+	},
+	/// Returns the reserves, total shares, fees, and spot price of pools. This reads the pools' storage directly rather
+	/// than querying each of them.
+	///
+	/// Up to `limit` pools (default 30, maximum 100) are returned at a time.
+	#[returns(Vec<PoolFactoryPoolSnapshot>)]
+	PoolsSnapshot {
+		after: Option<[String; 2]>,
+		limit: Option<u32>,
+	},
+	/// Returns the pools the specified address holds LP shares in, along with the value of those shares.
+	///
+	/// Pools are scanned in lexicographical order. As this queries each pool, only up to `limit` pools (default 30,
+	/// maximum 100) are scanned at a time. If there are more pools to scan, `next` can be used as `after` in the next
+	/// query.
+	#[returns(PoolFactoryUserPositions)]
+	UserPositions {
+		address: Addr,
+		after: Option<[String; 2]>,
+		limit: Option<u32>,
+	},
 	/// Pairs returns an array of pairs and their information according to the specified parameters in `start_after` and `limit` variables.
 	#[returns(Vec<PoolFactoryCreatedPair>)]
 	Pairs {
@@ -83,4 +377,98 @@ pub enum PoolFactoryQueryMsg {
 pub struct PoolFactoryCreatedPair {
 	pub canonical_pair: [String; 2],
 	pub address: Addr,
+	/// True if the pool has been deprecated
+	pub deprecated: bool,
+}
+
+#[cw_serde]
+pub struct PoolFactoryDeprecatedPair {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	/// Unix timestamp (in seconds) of when the pool was deprecated
+	pub deprecated_at: u64,
+	/// True if a new pool has taken over the pair
+	pub replaced: bool,
+}
+
+#[cw_serde]
+pub struct PoolFactoryPairInfo {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	pub config: PoolPairConfigJsonable,
+	/// The pool's balances, in the same order as `canonical_pair`
+	pub reserves: [Coin; 2],
+	pub total_shares: Uint128,
+	pub endorsed: bool,
+	pub deprecated: bool,
+}
+
+#[cw_serde]
+pub struct PoolFactoryDenomTokenMetadata {
+	pub denom: String,
+	pub metadata: PoolFactoryTokenMetadata,
+}
+
+#[cw_serde]
+pub struct PoolFactoryBatchSimulateRequest {
+	pub pair: [String; 2],
+	pub offer: Coin,
+}
+
+#[cw_serde]
+pub struct PoolFactoryBatchSimulateResult {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	pub offer: Coin,
+	pub result: PoolPairCalcSwapResult,
+}
+
+#[cw_serde]
+pub struct PoolFactoryPoolSnapshot {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	/// The pool's balances, in the same order as `canonical_pair`
+	pub reserves: [Coin; 2],
+	pub total_shares: Uint128,
+	pub total_fee_bps: u16,
+	pub maker_fee_bps: u16,
+	/// The amount of `canonical_pair[1]` one unit of `canonical_pair[0]` is worth, before fees. This is always in the
+	/// canonical order, regardless of whether the pool is configured as inverse. None if either reserve is empty.
+	pub spot_price: Option<Decimal>,
+	pub swaps_paused: bool,
+}
+
+#[cw_serde]
+pub struct PoolFactoryUserPosition {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	/// The amount of LP shares held
+	pub shares: Uint128,
+	/// The current value of the LP shares held, in the same order as `canonical_pair`
+	pub share_value: [Coin; 2],
+	/// The portion of the pool's total shares held
+	pub pool_share: Decimal,
+}
+
+#[cw_serde]
+pub struct PoolFactoryUserPositions {
+	pub positions: Vec<PoolFactoryUserPosition>,
+	/// If there are more pools to scan, the pair to specify as `after` in the next query
+	pub next: Option<[String; 2]>,
+}
+
+#[cw_serde]
+pub struct PoolFactoryRoleHolder {
+	pub address: Addr,
+	pub roles: Vec<PoolFactoryRole>,
+}
+
+#[cw_serde]
+pub struct PoolFactoryPoolCodeVersion {
+	pub canonical_pair: [String; 2],
+	pub address: Addr,
+	/// The code ID the pool was last instantiated or migrated with, unknown for pools created before this was tracked
+	pub code_id: Option<u64>,
+	/// If true, the pool will not be upgraded
+	pub pinned: bool,
 }
@@ -1,9 +1,10 @@
 use bitflags::bitflags;
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{coin, Addr, Coin, StdError, Uint128};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr,
-	impl_serializable_as_ref,
+	impl_serializable_as_ref, impl_serializable_borsh,
 	storage::{item::StoredItem, map::StoredMap, OZeroCopy, SerializableItem},
 };
 use crownfi_swaps_common::data_types::pair_id::CanonicalPoolPairIdentifier;
@@ -20,6 +21,9 @@ bitflags! {
 	pub struct PoolFactoryConfigFlags: u8 {
 		/// If true, everyone will be able to create new trading pairs
 		const PERMISSIONLESS_POOL_CRATION = 0b00000001u8;
+		/// If true, pools can't be created for pairs which share the same underlying assets as an existing pool, e.g.
+		/// a pool for a wrapped token when one already exists for the native token.
+		const REJECT_DUPLICATE_UNDERLYING_PAIRS = 0b00000010u8;
 	}
 }
 
@@ -32,15 +36,20 @@ pub struct PoolFactoryConfig {
 	pub fee_receiver: SeiCanonicalAddr,
 	/// Code to use when instantiating new pool pairs
 	pub pair_code_id: u64,
-	_unused_1: [u8; 32], // More possible code id configs
+	/// The LP incentives contract reward programs are registered with. If == Zeroable::zeroed(), None
+	pub lp_incentives: SeiCanonicalAddr,
 	/// The total fees (in bps) charged by a pair of this type
 	pub default_total_fee_bps: u16,
 	/// The amount of fees (in bps) collected by the Maker contract from this pair type
 	pub default_maker_fee_bps: u16,
-	_unused_2: [u8; 4], // Possible lower-bound fees
+	/// How long (in seconds) sensitive changes must be queued for before they can be applied
+	pub timelock_seconds: u32,
 	/// Collection of boolean values
 	pub flags: PoolFactoryConfigFlags, // Possible lower-bound fees
-	_unused_3: [u8; 7], // bit flags may be extended upon (plus we need the padding)
+	_unused_2: u8, // bit flags may be extended upon
+	/// The highest referral fee (in bps) swaps on the pairs can pay
+	pub max_referral_fee_bps: u16,
+	_unused_3: [u8; 4], // we need the padding
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
@@ -51,12 +60,24 @@ pub struct PoolFactoryConfigJsonable {
 	pub fee_receiver: Addr,
 	/// Code to use when instantiating new pool pairs
 	pub pair_code_id: u64,
+	/// The LP incentives contract reward programs are registered with
+	#[serde(default)]
+	pub lp_incentives: Option<Addr>,
 	/// The total fees (in bps) charged by a pair of this type
 	pub default_total_fee_bps: u16,
 	/// The amount of fees (in bps) collected by the Maker contract from this pair type
 	pub default_maker_fee_bps: u16,
 	/// If true, everyone will be able to create new trading pairs
 	pub permissionless_pool_cration: bool,
+	/// How long (in seconds) sensitive changes must be queued for before they can be applied
+	#[serde(default)]
+	pub timelock_seconds: u32,
+	/// If true, pools can't be created for pairs which share the same underlying assets as an existing pool
+	#[serde(default)]
+	pub reject_duplicate_underlying_pairs: bool,
+	/// The highest referral fee (in bps) swaps on the pairs can pay
+	#[serde(default)]
+	pub max_referral_fee_bps: u16,
 }
 
 impl_serializable_as_ref!(PoolFactoryConfig);
@@ -78,7 +99,9 @@ impl PoolFactoryConfig {
 		}
 	}
 	pub fn valid_fee_bps(&self) -> bool {
-		self.default_total_fee_bps <= MAX_TOTAL_FEE_BPS && self.default_maker_fee_bps <= self.default_total_fee_bps
+		self.default_total_fee_bps <= MAX_TOTAL_FEE_BPS
+			&& self.default_maker_fee_bps <= self.default_total_fee_bps
+			&& self.max_referral_fee_bps <= MAX_TOTAL_FEE_BPS
 	}
 }
 impl TryFrom<&PoolFactoryConfigJsonable> for PoolFactoryConfig {
@@ -88,13 +111,24 @@ impl TryFrom<&PoolFactoryConfigJsonable> for PoolFactoryConfig {
 		if value.permissionless_pool_cration {
 			flags = flags.union(PoolFactoryConfigFlags::PERMISSIONLESS_POOL_CRATION);
 		}
+		if value.reject_duplicate_underlying_pairs {
+			flags = flags.union(PoolFactoryConfigFlags::REJECT_DUPLICATE_UNDERLYING_PAIRS);
+		}
 		Ok(PoolFactoryConfig {
 			admin: (&value.admin).try_into()?,
 			fee_receiver: (&value.fee_receiver).try_into()?,
 			pair_code_id: value.pair_code_id,
+			lp_incentives: value
+				.lp_incentives
+				.as_ref()
+				.map(SeiCanonicalAddr::try_from)
+				.transpose()?
+				.unwrap_or(Zeroable::zeroed()),
 			default_total_fee_bps: value.default_total_fee_bps,
 			default_maker_fee_bps: value.default_maker_fee_bps,
+			timelock_seconds: value.timelock_seconds,
 			flags,
+			max_referral_fee_bps: value.max_referral_fee_bps,
 			..Zeroable::zeroed()
 		})
 	}
@@ -107,16 +141,409 @@ impl TryFrom<&PoolFactoryConfig> for PoolFactoryConfigJsonable {
 			admin: value.admin.try_into()?,
 			fee_receiver: value.fee_receiver.try_into()?,
 			pair_code_id: value.pair_code_id,
+			lp_incentives: if value.lp_incentives == Zeroable::zeroed() {
+				None
+			} else {
+				Some(value.lp_incentives.try_into()?)
+			},
 			default_total_fee_bps: value.default_total_fee_bps,
 			default_maker_fee_bps: value.default_maker_fee_bps,
 			permissionless_pool_cration: value
 				.flags
 				.contains(PoolFactoryConfigFlags::PERMISSIONLESS_POOL_CRATION),
+			timelock_seconds: value.timelock_seconds,
+			reject_duplicate_underlying_pairs: value
+				.flags
+				.contains(PoolFactoryConfigFlags::REJECT_DUPLICATE_UNDERLYING_PAIRS),
+			max_referral_fee_bps: value.max_referral_fee_bps,
+		})
+	}
+}
+
+pub const WRAPPER_CONFIG_NAMESPACE: &str = "wrp_cfg";
+/// The token wrapper contracts used to resolve the underlying asset of wrapped denoms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolFactoryWrapperConfig {
+	/// If == Zeroable::zeroed(), None
+	pub cw20_wrapper: SeiCanonicalAddr,
+	/// If == Zeroable::zeroed(), None
+	pub erc20_wrapper: SeiCanonicalAddr,
+}
+impl_serializable_as_ref!(PoolFactoryWrapperConfig);
+impl StoredItem for PoolFactoryWrapperConfig {
+	fn namespace() -> &'static [u8] {
+		WRAPPER_CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl PoolFactoryWrapperConfig {
+	pub fn load_or_default() -> Result<Self, StdError> {
+		Ok(Self::load()?.map(|config| *config).unwrap_or(Zeroable::zeroed()))
+	}
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct PoolFactoryWrapperConfigJsonable {
+	pub cw20_wrapper: Option<Addr>,
+	pub erc20_wrapper: Option<Addr>,
+}
+impl TryFrom<&PoolFactoryWrapperConfig> for PoolFactoryWrapperConfigJsonable {
+	type Error = StdError;
+	fn try_from(value: &PoolFactoryWrapperConfig) -> Result<Self, Self::Error> {
+		Ok(PoolFactoryWrapperConfigJsonable {
+			cw20_wrapper: if value.cw20_wrapper == Zeroable::zeroed() {
+				None
+			} else {
+				Some(value.cw20_wrapper.try_into()?)
+			},
+			erc20_wrapper: if value.erc20_wrapper == Zeroable::zeroed() {
+				None
+			} else {
+				Some(value.erc20_wrapper.try_into()?)
+			},
 		})
 	}
 }
 
+bitflags! {
+	/// Permissions which can be granted to addresses other than the admin. The admin implicitly has all of them.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Zeroable, Pod)]
+	#[repr(transparent)]
+	pub struct PoolFactoryRoleFlags: u8 {
+		/// Can update pool fees and features
+		const FEE_MANAGER = 0b00000001u8;
+		/// Can create pools when permissionless pool creation is disabled
+		const POOL_CREATOR = 0b00000010u8;
+		/// Can upgrade pool contracts
+		const UPGRADER = 0b00000100u8;
+		/// Can pause pools
+		const GUARDIAN = 0b00001000u8;
+	}
+}
+impl_serializable_as_ref!(PoolFactoryRoleFlags);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolFactoryRole {
+	/// Can update pool fees and features
+	FeeManager,
+	/// Can create pools when permissionless pool creation is disabled
+	PoolCreator,
+	/// Can upgrade pool contracts
+	Upgrader,
+	/// Can pause pools
+	Guardian,
+}
+impl From<PoolFactoryRole> for PoolFactoryRoleFlags {
+	fn from(value: PoolFactoryRole) -> Self {
+		match value {
+			PoolFactoryRole::FeeManager => PoolFactoryRoleFlags::FEE_MANAGER,
+			PoolFactoryRole::PoolCreator => PoolFactoryRoleFlags::POOL_CREATOR,
+			PoolFactoryRole::Upgrader => PoolFactoryRoleFlags::UPGRADER,
+			PoolFactoryRole::Guardian => PoolFactoryRoleFlags::GUARDIAN,
+		}
+	}
+}
+impl FromIterator<PoolFactoryRole> for PoolFactoryRoleFlags {
+	fn from_iter<T: IntoIterator<Item = PoolFactoryRole>>(iter: T) -> Self {
+		iter.into_iter()
+			.fold(PoolFactoryRoleFlags::empty(), |flags, role| flags.union(role.into()))
+	}
+}
+impl From<PoolFactoryRoleFlags> for Vec<PoolFactoryRole> {
+	fn from(value: PoolFactoryRoleFlags) -> Self {
+		[
+			PoolFactoryRole::FeeManager,
+			PoolFactoryRole::PoolCreator,
+			PoolFactoryRole::Upgrader,
+			PoolFactoryRole::Guardian,
+		]
+		.into_iter()
+		.filter(|role| value.contains((*role).into()))
+		.collect()
+	}
+}
+
+const ROLES_NAMESPACE: &str = "roles";
+pub fn get_roles_store() -> StoredMap<SeiCanonicalAddr, PoolFactoryRoleFlags> {
+	StoredMap::new(ROLES_NAMESPACE.as_ref())
+}
+
+/// Returns true if the specified address is the admin, or has been granted the specified role.
+pub fn has_role(
+	config: &PoolFactoryConfig,
+	addr: &SeiCanonicalAddr,
+	role: PoolFactoryRoleFlags,
+) -> Result<bool, StdError> {
+	if config.admin == *addr {
+		return Ok(true);
+	}
+	Ok(get_roles_store().get(addr)?.is_some_and(|roles| roles.contains(role)))
+}
+
+const PENDING_CONFIG_NAMESPACE: &str = "pend_cfg";
+/// Sensitive config changes which are waiting for the timelock to elapse.
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PoolFactoryPendingConfig {
+	pub pair_code_id: Option<u64>,
+	pub default_total_fee_bps: Option<u16>,
+	pub default_maker_fee_bps: Option<u16>,
+	pub timelock_seconds: Option<u32>,
+	/// Unix timestamp (in seconds) after which these changes may be applied
+	pub apply_after: u64,
+}
+impl_serializable_borsh!(PoolFactoryPendingConfig);
+impl StoredItem for PoolFactoryPendingConfig {
+	fn namespace() -> &'static [u8] {
+		PENDING_CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl PoolFactoryPendingConfig {
+	pub fn is_empty(&self) -> bool {
+		self.pair_code_id.is_none()
+			&& self.default_total_fee_bps.is_none()
+			&& self.default_maker_fee_bps.is_none()
+			&& self.timelock_seconds.is_none()
+	}
+}
+
+/// Which denoms pools can be created for by those who don't have the `pool_creator` role.
+#[derive(
+	Debug, Clone, Copy, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolFactoryDenomFilter {
+	/// Any denom can be used
+	#[default]
+	None,
+	/// Only denoms in the denom list can be used
+	Allowlist,
+	/// Denoms in the denom list cannot be used
+	Blocklist,
+}
+
+const POOL_CREATION_RULES_NAMESPACE: &str = "create_rules";
+/// Anti-spam measures for permissionless pool creation. These don't apply to those with the `pool_creator` role.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolFactoryPoolCreationRules {
+	pub creation_fee_denom: String,
+	/// Sent to the `fee_receiver` when creating a pool, 0 to disable.
+	pub creation_fee_amount: u128,
+	/// The minimum amount of each denom which must be provided when creating a pool
+	pub min_initial_liquidity: u128,
+	pub denom_filter: PoolFactoryDenomFilter,
+}
+impl_serializable_borsh!(PoolFactoryPoolCreationRules);
+impl StoredItem for PoolFactoryPoolCreationRules {
+	fn namespace() -> &'static [u8] {
+		POOL_CREATION_RULES_NAMESPACE.as_bytes()
+	}
+}
+impl PoolFactoryPoolCreationRules {
+	pub fn creation_fee(&self) -> Option<Coin> {
+		if self.creation_fee_amount == 0 {
+			None
+		} else {
+			Some(coin(self.creation_fee_amount, &self.creation_fee_denom))
+		}
+	}
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct PoolFactoryPoolCreationRulesJsonable {
+	/// Sent to the `fee_receiver` when creating a pool
+	pub creation_fee: Option<Coin>,
+	/// The minimum amount of each denom which must be provided when creating a pool
+	pub min_initial_liquidity: Uint128,
+	pub denom_filter: PoolFactoryDenomFilter,
+}
+impl From<PoolFactoryPoolCreationRules> for PoolFactoryPoolCreationRulesJsonable {
+	fn from(value: PoolFactoryPoolCreationRules) -> Self {
+		PoolFactoryPoolCreationRulesJsonable {
+			creation_fee: value.creation_fee(),
+			min_initial_liquidity: value.min_initial_liquidity.into(),
+			denom_filter: value.denom_filter,
+		}
+	}
+}
+
+const DENOM_LIST_NAMESPACE: &str = "denom_list";
+/// The denoms the `denom_filter` applies to
+pub fn get_denom_list_store() -> StoredMap<String, bool> {
+	StoredMap::new(DENOM_LIST_NAMESPACE.as_ref())
+}
+
+/// Pool fee changes which are waiting for the timelock to elapse.
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PoolFactoryPendingPoolFees {
+	pub total_fee_bps: Option<u16>,
+	pub maker_fee_bps: Option<u16>,
+	pub fee_on_input: Option<bool>,
+	/// Unix timestamp (in seconds) after which these changes may be applied
+	pub apply_after: u64,
+}
+impl_serializable_borsh!(PoolFactoryPendingPoolFees);
+
+const PENDING_POOL_FEES_NAMESPACE: &str = "pend_fees";
+pub fn get_pending_pool_fees_store() -> StoredMap<CanonicalPoolPairIdentifier, PoolFactoryPendingPoolFees> {
+	StoredMap::new(PENDING_POOL_FEES_NAMESPACE.as_ref())
+}
+
 const POOL_ADDRESSES_NAMESPACE: &str = "pools";
 pub fn get_pool_addresses_store() -> StoredMap<CanonicalPoolPairIdentifier, SeiCanonicalAddr> {
 	StoredMap::new(POOL_ADDRESSES_NAMESPACE.as_ref())
 }
+
+const POOL_PAIRS_NAMESPACE: &str = "pool_pairs";
+/// Reverse index of `get_pool_addresses_store()`
+pub fn get_pool_pairs_store() -> StoredMap<SeiCanonicalAddr, CanonicalPoolPairIdentifier> {
+	StoredMap::new(POOL_PAIRS_NAMESPACE.as_ref())
+}
+
+/// A pool which has been deprecated. It remains the canonical pool for its pair until it's replaced.
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolFactoryDeprecatedPool {
+	pub pair: CanonicalPoolPairIdentifier,
+	/// Unix timestamp (in seconds) of when the pool was deprecated
+	pub deprecated_at: u64,
+	/// If true, a new pool has taken over the pair
+	pub replaced: bool,
+}
+impl_serializable_borsh!(PoolFactoryDeprecatedPool);
+
+const POOL_GRAVEYARD_NAMESPACE: &str = "graveyard";
+/// Deprecated pools, keyed by address, as replaced pools no longer have an entry in `get_pool_addresses_store()`.
+pub fn get_pool_graveyard_store() -> StoredMap<SeiCanonicalAddr, PoolFactoryDeprecatedPool> {
+	StoredMap::new(POOL_GRAVEYARD_NAMESPACE.as_ref())
+}
+
+/// The denoms which a denom has been paired with, in lexicographical order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolFactoryDenomPairs {
+	pub other_denoms: Vec<String>,
+}
+impl_serializable_borsh!(PoolFactoryDenomPairs);
+
+const DENOM_PAIRS_NAMESPACE: &str = "denom_pairs";
+pub fn get_denom_pairs_store() -> StoredMap<String, PoolFactoryDenomPairs> {
+	StoredMap::new(DENOM_PAIRS_NAMESPACE.as_ref())
+}
+
+/// Adds the specified pool to the reverse and per-denom indexes. Pools which are already indexed are left as-is.
+pub fn index_pool(pair: &CanonicalPoolPairIdentifier, addr: &SeiCanonicalAddr) -> Result<(), StdError> {
+	get_pool_pairs_store().set(addr, pair)?;
+	let denom_pairs_store = get_denom_pairs_store();
+	for (denom, other_denom) in [(&pair.left, &pair.right), (&pair.right, &pair.left)] {
+		let mut denom_pairs = denom_pairs_store
+			.get(denom)?
+			.map(|denom_pairs| denom_pairs.into_inner())
+			.unwrap_or_default();
+		if let Err(index) = denom_pairs.other_denoms.binary_search(other_denom) {
+			denom_pairs.other_denoms.insert(index, other_denom.clone());
+			denom_pairs_store.set(denom, &denom_pairs)?;
+		}
+	}
+	Ok(())
+}
+
+/// Tracks which code each pool is running. Pools created before this was tracked have no entry until they're migrated.
+#[derive(
+	Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PoolFactoryPoolCodeInfo {
+	/// The code ID the pool was last instantiated or migrated with
+	pub code_id: Option<u64>,
+	/// If true, the pool will not be upgraded by `MigratePools` or `UpdatePoolCode`
+	pub pinned: bool,
+}
+impl_serializable_borsh!(PoolFactoryPoolCodeInfo);
+
+const POOL_CODE_INFO_NAMESPACE: &str = "pool_code";
+pub fn get_pool_code_info_store() -> StoredMap<CanonicalPoolPairIdentifier, PoolFactoryPoolCodeInfo> {
+	StoredMap::new(POOL_CODE_INFO_NAMESPACE.as_ref())
+}
+
+/// Verified information about a token, curated by the admin.
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema)]
+pub struct PoolFactoryTokenMetadata {
+	/// The name to show to users
+	pub display_name: String,
+	/// The ticker symbol to show to users
+	pub symbol: String,
+	/// The amount of decimal places used when displaying amounts
+	pub decimals: u8,
+	pub logo_uri: Option<String>,
+	/// The "0x"-prefixed address of the token's EVM pointer contract, if any
+	pub evm_pointer: Option<String>,
+	/// If this denom is minted by a wrapper contract, the address of the token being wrapped, either a CW20 contract
+	/// or an "0x"-prefixed ERC20 contract.
+	pub wrapper_origin: Option<String>,
+}
+impl_serializable_borsh!(PoolFactoryTokenMetadata);
+impl PoolFactoryTokenMetadata {
+	pub fn validate(&self) -> Result<(), StdError> {
+		if self.display_name.is_empty() || self.symbol.is_empty() {
+			return Err(StdError::generic_err("Token display name and symbol cannot be empty"));
+		}
+		if let Some(evm_pointer) = self.evm_pointer.as_ref() {
+			let is_valid = evm_pointer.len() == 42
+				&& evm_pointer.starts_with("0x")
+				&& evm_pointer[2..].chars().all(|c| c.is_ascii_hexdigit());
+			if !is_valid {
+				return Err(StdError::generic_err(format!(
+					"Invalid EVM pointer address: {evm_pointer}"
+				)));
+			}
+		}
+		Ok(())
+	}
+}
+
+const TOKEN_METADATA_NAMESPACE: &str = "token_meta";
+pub fn get_token_metadata_store() -> StoredMap<String, PoolFactoryTokenMetadata> {
+	StoredMap::new(TOKEN_METADATA_NAMESPACE.as_ref())
+}
+
+/// The asset a denom ultimately represents, so that wrapped forms of the same token can be recognised.
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolFactoryUnderlyingAsset {
+	/// A bank denom which isn't minted by a known wrapper contract
+	Native { denom: String },
+	/// A CW20 token, wrapped by the configured CW20 wrapper
+	Cw20 { contract: String },
+	/// An ERC20 token, wrapped by the configured ERC20 wrapper. The address is lower-case and "0x"-prefixed.
+	Erc20 { contract: String },
+}
+impl_serializable_borsh!(PoolFactoryUnderlyingAsset);
+impl std::fmt::Display for PoolFactoryUnderlyingAsset {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			PoolFactoryUnderlyingAsset::Native { denom } => write!(f, "native:{denom}"),
+			PoolFactoryUnderlyingAsset::Cw20 { contract } => write!(f, "cw20:{contract}"),
+			PoolFactoryUnderlyingAsset::Erc20 { contract } => write!(f, "erc20:{contract}"),
+		}
+	}
+}
+
+const DENOM_ORIGINS_NAMESPACE: &str = "denom_origin";
+/// The underlying assets of wrapped denoms which pools have been created with
+pub fn get_denom_origins_store() -> StoredMap<String, PoolFactoryUnderlyingAsset> {
+	StoredMap::new(DENOM_ORIGINS_NAMESPACE.as_ref())
+}
+
+const EVM_POINTERS_NAMESPACE: &str = "evm_ptrs";
+/// Maps lower-case EVM pointer addresses from the token metadata to the denom they point to
+pub fn get_evm_pointers_store() -> StoredMap<String, PoolFactoryUnderlyingAsset> {
+	StoredMap::new(EVM_POINTERS_NAMESPACE.as_ref())
+}
+
+const UNDERLYING_PAIRS_NAMESPACE: &str = "underlying_pairs";
+/// Maps the underlying assets of a pair (as strings) to the first pool pair created for them
+pub fn get_underlying_pairs_store() -> StoredMap<CanonicalPoolPairIdentifier, CanonicalPoolPairIdentifier> {
+	StoredMap::new(UNDERLYING_PAIRS_NAMESPACE.as_ref())
+}
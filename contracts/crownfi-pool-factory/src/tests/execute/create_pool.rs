@@ -0,0 +1,156 @@
+use crate::{
+	error::PoolFactoryContractError,
+	state::{
+		get_denom_origins_store, get_token_metadata_store, get_underlying_pairs_store, PoolFactoryUnderlyingAsset,
+	},
+	tests::{create_pool, deps, init, CW20_DENOM, CW20_TOKEN, ERC20_DENOM, ERC20_TOKEN, POINTEE_DENOM},
+};
+use crownfi_swaps_common::data_types::pair_id::CanonicalPoolPairIdentifier;
+
+#[test]
+fn wrapped_denoms_are_recorded_with_their_origins() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	create_pool(&mut deps, ["usei", CW20_DENOM]).unwrap();
+	create_pool(
+		&mut deps,
+		[
+			"usei",
+			"factory/sei1242424242424242424242424242424242424242424242424242ss7lj9n/unknown",
+		],
+	)
+	.unwrap();
+	create_pool(&mut deps, ["usei", "uusdc"]).unwrap();
+
+	let denom_origins = get_denom_origins_store();
+	assert_eq!(
+		denom_origins
+			.get(&CW20_DENOM.to_string())
+			.unwrap()
+			.map(|origin| origin.into_inner()),
+		Some(PoolFactoryUnderlyingAsset::Cw20 {
+			contract: CW20_TOKEN.into()
+		})
+	);
+	// Denoms which the wrapper doesn't know about, as well as plain native denoms, have no recorded origin
+	assert!(!denom_origins
+		.has(&"factory/sei1242424242424242424242424242424242424242424242424242ss7lj9n/unknown".to_string()));
+	assert!(!denom_origins.has(&"uusdc".to_string()));
+	assert!(!denom_origins.has(&"usei".to_string()));
+
+	let underlying_pairs = get_underlying_pairs_store();
+	assert_eq!(
+		underlying_pairs
+			.get(&CanonicalPoolPairIdentifier::from([
+				format!("cw20:{CW20_TOKEN}"),
+				"native:usei".to_string()
+			]))
+			.unwrap()
+			.map(|pair| pair.into_inner()),
+		Some(CanonicalPoolPairIdentifier::from([
+			"usei".to_string(),
+			CW20_DENOM.to_string()
+		]))
+	);
+	assert_eq!(
+		underlying_pairs
+			.get(&CanonicalPoolPairIdentifier::from([
+				"native:usei".to_string(),
+				"native:uusdc".to_string()
+			]))
+			.unwrap()
+			.map(|pair| pair.into_inner()),
+		Some(CanonicalPoolPairIdentifier::from([
+			"usei".to_string(),
+			"uusdc".to_string()
+		]))
+	);
+}
+
+#[test]
+fn erc20_pointers_resolve_to_their_native_denom() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	create_pool(&mut deps, ["usei", ERC20_DENOM]).unwrap();
+	assert_eq!(
+		get_denom_origins_store()
+			.get(&ERC20_DENOM.to_string())
+			.unwrap()
+			.map(|origin| origin.into_inner()),
+		Some(PoolFactoryUnderlyingAsset::Native {
+			denom: POINTEE_DENOM.into()
+		})
+	);
+	assert!(get_underlying_pairs_store().has(&CanonicalPoolPairIdentifier::from([
+		"native:usei".to_string(),
+		format!("native:{POINTEE_DENOM}")
+	])));
+	// The ERC20 token's address isn't what's recorded as it's the pointer of a native denom
+	assert!(!get_underlying_pairs_store().has(&CanonicalPoolPairIdentifier::from([
+		"native:usei".to_string(),
+		format!("erc20:{ERC20_TOKEN}")
+	])));
+}
+
+#[test]
+fn token_metadata_is_left_alone() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	create_pool(&mut deps, ["usei", CW20_DENOM]).unwrap();
+	create_pool(&mut deps, ["usei", ERC20_DENOM]).unwrap();
+
+	let token_metadata = get_token_metadata_store();
+	assert!(!token_metadata.has(&CW20_DENOM.to_string()));
+	assert!(!token_metadata.has(&ERC20_DENOM.to_string()));
+	assert_eq!(
+		token_metadata
+			.get(&POINTEE_DENOM.to_string())
+			.unwrap()
+			.unwrap()
+			.into_inner()
+			.wrapper_origin,
+		None
+	);
+}
+
+#[test]
+fn duplicate_underlying_pairs_are_allowed_by_default() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	create_pool(&mut deps, ["usei", POINTEE_DENOM]).unwrap();
+	create_pool(&mut deps, ["usei", ERC20_DENOM]).unwrap();
+	// The first pool remains the one recorded
+	assert_eq!(
+		get_underlying_pairs_store()
+			.get(&CanonicalPoolPairIdentifier::from([
+				"native:usei".to_string(),
+				format!("native:{POINTEE_DENOM}")
+			]))
+			.unwrap()
+			.map(|pair| pair.into_inner()),
+		Some(CanonicalPoolPairIdentifier::from([
+			"usei".to_string(),
+			POINTEE_DENOM.to_string()
+		]))
+	);
+}
+
+#[test]
+fn duplicate_underlying_pairs_are_rejected_when_configured() {
+	let mut deps = deps();
+	init(&mut deps, true);
+
+	create_pool(&mut deps, ["usei", POINTEE_DENOM]).unwrap();
+	assert_eq!(
+		create_pool(&mut deps, ["usei", ERC20_DENOM]),
+		Err(PoolFactoryContractError::DuplicateUnderlyingPair(
+			CanonicalPoolPairIdentifier::from(["usei".to_string(), POINTEE_DENOM.to_string()]).to_string()
+		))
+	);
+	// Unrelated pairs are fine
+	create_pool(&mut deps, ["usei", CW20_DENOM]).unwrap();
+}
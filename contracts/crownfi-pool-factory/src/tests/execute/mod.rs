@@ -0,0 +1,4 @@
+mod create_pool;
+mod record_underlying_pairs;
+mod set_pool_features;
+mod update_config;
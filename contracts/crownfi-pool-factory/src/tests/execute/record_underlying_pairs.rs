@@ -0,0 +1,133 @@
+use cosmwasm_std::{
+	attr,
+	testing::{mock_env, mock_info},
+	Addr, Response,
+};
+use crownfi_cw_common::data_types::canonical_addr::SeiCanonicalAddr;
+use crownfi_swaps_common::{data_types::pair_id::CanonicalPoolPairIdentifier, error::CrownfiSwapsCommonError};
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::execute,
+	error::PoolFactoryContractError,
+	msg::PoolFactoryExecuteMsg,
+	state::{
+		get_denom_origins_store, get_pool_addresses_store, get_underlying_pairs_store, PoolFactoryUnderlyingAsset,
+	},
+	tests::{deps, init, AddressFactory, TestDeps, CW20_DENOM, CW20_TOKEN, ERC20_DENOM, POINTEE_DENOM},
+};
+
+/// Adds a pool the way it was before the underlying assets were recorded
+fn add_existing_pool(denoms: [&str; 2], address: &str) -> CanonicalPoolPairIdentifier {
+	let pair = CanonicalPoolPairIdentifier::from([denoms[0].to_string(), denoms[1].to_string()]);
+	get_pool_addresses_store()
+		.set(&pair, &SeiCanonicalAddr::try_from(&Addr::unchecked(address)).unwrap())
+		.unwrap();
+	pair
+}
+
+fn record_underlying_pairs(
+	deps: &mut TestDeps,
+	sender: &str,
+	after: Option<[String; 2]>,
+	limit: Option<u32>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[]),
+		PoolFactoryExecuteMsg::RecordUnderlyingPairs { after, limit },
+	)
+}
+
+#[test]
+fn sender_must_be_admin() {
+	let mut deps = deps();
+	init(&mut deps, false);
+	assert!(matches!(
+		record_underlying_pairs(&mut deps, AddressFactory::FEE_RECEIVER, None, None),
+		Err(PoolFactoryContractError::SwapsCommon(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+}
+
+#[test]
+fn records_underlying_pairs_of_existing_pools() {
+	let mut deps = deps();
+	init(&mut deps, false);
+	let cw20_pair = add_existing_pool(["usei", CW20_DENOM], "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue");
+	let native_pair = add_existing_pool(["usei", "uusdc"], "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft");
+
+	let res = record_underlying_pairs(&mut deps, AddressFactory::ADMIN, None, None).unwrap();
+	assert!(res.attributes.contains(&attr("recorded_count", "2")));
+	assert_eq!(
+		get_denom_origins_store()
+			.get(&CW20_DENOM.to_string())
+			.unwrap()
+			.map(|origin| origin.into_inner()),
+		Some(PoolFactoryUnderlyingAsset::Cw20 {
+			contract: CW20_TOKEN.into()
+		})
+	);
+	let underlying_pairs = get_underlying_pairs_store();
+	assert_eq!(
+		underlying_pairs
+			.get(&CanonicalPoolPairIdentifier::from([
+				"native:usei".to_string(),
+				format!("cw20:{CW20_TOKEN}")
+			]))
+			.unwrap()
+			.map(|pair| pair.into_inner()),
+		Some(cw20_pair)
+	);
+	assert_eq!(
+		underlying_pairs
+			.get(&CanonicalPoolPairIdentifier::from([
+				"native:usei".to_string(),
+				"native:uusdc".to_string()
+			]))
+			.unwrap()
+			.map(|pair| pair.into_inner()),
+		Some(native_pair)
+	);
+}
+
+#[test]
+fn existing_duplicates_do_not_fail() {
+	let mut deps = deps();
+	init(&mut deps, true);
+	add_existing_pool(["usei", POINTEE_DENOM], "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue");
+	add_existing_pool(["usei", ERC20_DENOM], "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft");
+
+	record_underlying_pairs(&mut deps, AddressFactory::ADMIN, None, None).unwrap();
+	assert!(get_underlying_pairs_store().has(&CanonicalPoolPairIdentifier::from([
+		"native:usei".to_string(),
+		format!("native:{POINTEE_DENOM}")
+	])));
+	// Running it again doesn't change anything
+	record_underlying_pairs(&mut deps, AddressFactory::ADMIN, None, None).unwrap();
+}
+
+#[test]
+fn can_be_done_in_batches() {
+	let mut deps = deps();
+	init(&mut deps, false);
+	add_existing_pool(["usei", CW20_DENOM], "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue");
+	add_existing_pool(["usei", "uusdc"], "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft");
+	let recorded_count = || get_underlying_pairs_store().iter_range(None, None).unwrap().count();
+
+	let (first_pair, _) = get_pool_addresses_store()
+		.iter_range(None, None)
+		.unwrap()
+		.next()
+		.unwrap();
+	let res = record_underlying_pairs(&mut deps, AddressFactory::ADMIN, None, Some(1)).unwrap();
+	assert!(res.attributes.contains(&attr("recorded_count", "1")));
+	assert!(res.attributes.contains(&attr("last_pair", first_pair.to_string())));
+	assert_eq!(recorded_count(), 1);
+
+	let res = record_underlying_pairs(&mut deps, AddressFactory::ADMIN, Some(first_pair.into()), Some(1)).unwrap();
+	assert!(res.attributes.contains(&attr("recorded_count", "1")));
+	assert_eq!(recorded_count(), 2);
+}
@@ -0,0 +1,271 @@
+use bytemuck::Zeroable;
+use cosmwasm_std::{
+	from_json,
+	testing::{mock_env, mock_info},
+	to_json_binary, Addr, Binary, ContractResult, Env, SubMsg, SystemError, SystemResult, WasmMsg, WasmQuery,
+};
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_pool_pair_contract::{msg::PoolPairExecuteMsg, state::PoolPairConfig};
+use crownfi_swaps_common::{data_types::pair_id::CanonicalPoolPairIdentifier, error::CrownfiSwapsCommonError};
+
+use crate::{
+	contract::{execute, query},
+	error::PoolFactoryContractError,
+	msg::{PoolFactoryExecuteMsg, PoolFactoryQueryMsg},
+	state::{get_pool_addresses_store, PoolFactoryPendingPoolFees, PoolFactoryRole},
+	tests::{deps, init, AddressFactory, TestDeps},
+};
+
+const POOL: &str = "sei1xvenxvenxvenxvenxvenxvenxvenxven3j50ft";
+const TIMELOCK_SECONDS: u32 = 3600;
+
+/// Adds a pool which doesn't take fees from the input, with a timelock configured if `timelocked` is set.
+fn init_with_pool(deps: &mut TestDeps, timelocked: bool) -> [String; 2] {
+	init(deps, false);
+	let pair = ["usei".to_string(), "uusdc".to_string()];
+	get_pool_addresses_store()
+		.set(
+			&CanonicalPoolPairIdentifier::from(pair.clone()),
+			&SeiCanonicalAddr::try_from(&Addr::unchecked(POOL)).unwrap(),
+		)
+		.unwrap();
+	deps.querier.update_wasm(|query| match query {
+		WasmQuery::Raw { contract_addr, key }
+			if contract_addr == POOL && key.as_slice() == PoolPairConfig::namespace() =>
+		{
+			SystemResult::Ok(ContractResult::Ok(Binary::from(bytemuck::bytes_of(
+				&PoolPairConfig::zeroed(),
+			))))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+	if timelocked {
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::ADMIN, &[]),
+			PoolFactoryExecuteMsg::UpdateConfig {
+				fee_receiver: None,
+				pair_code_id: None,
+				lp_incentives: None,
+				default_total_fee_bps: None,
+				default_maker_fee_bps: None,
+				permissionless_pool_cration: None,
+				timelock_seconds: Some(TIMELOCK_SECONDS),
+				cw20_wrapper: None,
+				erc20_wrapper: None,
+				reject_duplicate_underlying_pairs: None,
+				max_referral_fee_bps: None,
+			},
+		)
+		.unwrap();
+	}
+	pair
+}
+
+fn env_after(seconds: u64) -> Env {
+	let mut env = mock_env();
+	env.block.time = env.block.time.plus_seconds(seconds);
+	env
+}
+
+fn update_config_msg(
+	long_term_orders: Option<bool>,
+	position_tracking: Option<bool>,
+	fee_on_input: Option<bool>,
+) -> SubMsg {
+	SubMsg::new(WasmMsg::Execute {
+		contract_addr: POOL.into(),
+		msg: to_json_binary(&PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders,
+			position_tracking,
+			fee_on_input,
+			max_referral_fee_bps: None,
+		})
+		.unwrap(),
+		funds: Vec::new(),
+	})
+}
+
+fn pending_fees(deps: &TestDeps, pair: &[String; 2]) -> Option<PoolFactoryPendingPoolFees> {
+	from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolFactoryQueryMsg::PendingFeesForPool { pair: pair.clone() },
+		)
+		.unwrap(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn requires_fee_manager_role() {
+	let mut deps = deps();
+	let pair = init_with_pool(&mut deps, false);
+	let manager = "sei1yg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zrvrjue";
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(manager, &[]),
+		PoolFactoryExecuteMsg::SetPoolLongTermOrders {
+			pair: pair.clone(),
+			enabled: true,
+		},
+	);
+	assert!(matches!(
+		res,
+		Err(PoolFactoryContractError::SwapsCommon(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::GrantRoles {
+			address: Addr::unchecked(manager),
+			roles: vec![PoolFactoryRole::FeeManager],
+		},
+	)
+	.unwrap();
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(manager, &[]),
+		PoolFactoryExecuteMsg::SetPoolLongTermOrders { pair, enabled: true },
+	)
+	.unwrap();
+	assert_eq!(res.messages, vec![update_config_msg(Some(true), None, None)]);
+}
+
+#[test]
+fn fee_on_input_is_applied_immediately_without_timelock() {
+	let mut deps = deps();
+	let pair = init_with_pool(&mut deps, false);
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetPoolFeeOnInput { pair, enabled: true },
+	)
+	.unwrap();
+	assert_eq!(res.messages, vec![update_config_msg(None, None, Some(true))]);
+}
+
+#[test]
+fn fee_on_input_changes_are_timelocked() {
+	let mut deps = deps();
+	let pair = init_with_pool(&mut deps, true);
+
+	// Other features aren't fee changes, so they aren't queued
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetPoolPositionTracking {
+			pair: pair.clone(),
+			enabled: true,
+		},
+	)
+	.unwrap();
+	assert_eq!(res.messages, vec![update_config_msg(None, Some(true), None)]);
+
+	// The pool already takes fees from the result, so there's nothing to wait for
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetPoolFeeOnInput {
+			pair: pair.clone(),
+			enabled: false,
+		},
+	)
+	.unwrap();
+	assert_eq!(res.messages, vec![update_config_msg(None, None, Some(false))]);
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetPoolFeeOnInput {
+			pair: pair.clone(),
+			enabled: true,
+		},
+	)
+	.unwrap();
+	assert!(res.messages.is_empty());
+	let apply_after = mock_env().block.time.seconds() + TIMELOCK_SECONDS as u64;
+	assert_eq!(
+		pending_fees(&deps, &pair),
+		Some(PoolFactoryPendingPoolFees {
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			fee_on_input: Some(true),
+			apply_after,
+		})
+	);
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::ApplyPendingFeesForPool { pair: pair.clone() },
+	);
+	assert_eq!(res, Err(CrownfiSwapsCommonError::Timelocked(apply_after).into()));
+
+	let res = execute(
+		deps.as_mut(),
+		env_after(TIMELOCK_SECONDS as u64),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::ApplyPendingFeesForPool { pair: pair.clone() },
+	)
+	.unwrap();
+	assert_eq!(res.messages, vec![update_config_msg(None, None, Some(true))]);
+	assert_eq!(pending_fees(&deps, &pair), None);
+}
+
+#[test]
+fn queued_fee_on_input_is_kept_when_fees_are_queued() {
+	let mut deps = deps();
+	let pair = init_with_pool(&mut deps, true);
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetPoolFeeOnInput {
+			pair: pair.clone(),
+			enabled: true,
+		},
+	)
+	.unwrap();
+	execute(
+		deps.as_mut(),
+		env_after(60),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::UpdateFeesForPool {
+			pair: pair.clone(),
+			total_fee_bps: Some(30),
+			maker_fee_bps: None,
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		pending_fees(&deps, &pair),
+		Some(PoolFactoryPendingPoolFees {
+			total_fee_bps: Some(30),
+			maker_fee_bps: None,
+			fee_on_input: Some(true),
+			apply_after: mock_env().block.time.seconds() + 60 + TIMELOCK_SECONDS as u64,
+		})
+	);
+}
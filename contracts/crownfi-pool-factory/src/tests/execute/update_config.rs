@@ -0,0 +1,165 @@
+use cosmwasm_std::{
+	testing::{mock_env, mock_info},
+	Addr, Response,
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::{execute, instantiate},
+	error::PoolFactoryContractError,
+	msg::{PoolFactoryExecuteMsg, PoolFactoryInstantiateMsg},
+	state::{PoolFactoryConfig, PoolFactoryConfigJsonable, PoolFactoryPendingConfig},
+	tests::{deps, init, AddressFactory, TestDeps},
+};
+
+fn update_fees(
+	deps: &mut TestDeps,
+	default_total_fee_bps: Option<u16>,
+	default_maker_fee_bps: Option<u16>,
+	max_referral_fee_bps: Option<u16>,
+) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			pair_code_id: None,
+			lp_incentives: None,
+			default_total_fee_bps,
+			default_maker_fee_bps,
+			permissionless_pool_cration: None,
+			timelock_seconds: None,
+			cw20_wrapper: None,
+			erc20_wrapper: None,
+			reject_duplicate_underlying_pairs: None,
+			max_referral_fee_bps,
+		},
+	)
+}
+
+#[test]
+fn instantiate_rejects_invalid_fee_bps() {
+	let mut deps = deps();
+	let res = instantiate(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryInstantiateMsg {
+			config: PoolFactoryConfigJsonable {
+				admin: Addr::unchecked(AddressFactory::ADMIN),
+				fee_receiver: Addr::unchecked(AddressFactory::FEE_RECEIVER),
+				pair_code_id: 1,
+				lp_incentives: None,
+				default_total_fee_bps: 100,
+				default_maker_fee_bps: 50,
+				permissionless_pool_cration: false,
+				timelock_seconds: 0,
+				reject_duplicate_underlying_pairs: false,
+				max_referral_fee_bps: 10_001,
+			},
+			guardian: None,
+		},
+	);
+	assert_eq!(res, Err(PoolFactoryContractError::InvalidFeeBps));
+}
+
+#[test]
+fn update_config_rejects_invalid_fee_bps() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	for (default_total_fee_bps, default_maker_fee_bps, max_referral_fee_bps) in [
+		(Some(10_001), None, None),
+		(None, Some(101), None),
+		(Some(49), None, None),
+		(None, None, Some(10_001)),
+	] {
+		assert_eq!(
+			update_fees(
+				&mut deps,
+				default_total_fee_bps,
+				default_maker_fee_bps,
+				max_referral_fee_bps
+			),
+			Err(PoolFactoryContractError::InvalidFeeBps)
+		);
+	}
+
+	update_fees(&mut deps, None, None, Some(10_000)).unwrap();
+	assert_eq!(
+		PoolFactoryConfig::load_non_empty().unwrap().max_referral_fee_bps,
+		10_000
+	);
+}
+
+#[test]
+fn queued_changes_are_kept() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	let update = |deps: &mut TestDeps,
+	              seconds: u64,
+	              pair_code_id: Option<u64>,
+	              default_total_fee_bps: Option<u16>,
+	              timelock_seconds: Option<u32>| {
+		let mut env = mock_env();
+		env.block.time = env.block.time.plus_seconds(seconds);
+		execute(
+			deps.as_mut(),
+			env,
+			mock_info(AddressFactory::ADMIN, &[]),
+			PoolFactoryExecuteMsg::UpdateConfig {
+				fee_receiver: None,
+				pair_code_id,
+				lp_incentives: None,
+				default_total_fee_bps,
+				default_maker_fee_bps: None,
+				permissionless_pool_cration: None,
+				timelock_seconds,
+				cw20_wrapper: None,
+				erc20_wrapper: None,
+				reject_duplicate_underlying_pairs: None,
+				max_referral_fee_bps: None,
+			},
+		)
+		.unwrap()
+	};
+	let pending = || {
+		PoolFactoryPendingConfig::load()
+			.unwrap()
+			.map(|pending| pending.into_inner())
+	};
+	let now = mock_env().block.time.seconds();
+
+	update(&mut deps, 0, None, None, Some(3600));
+	update(&mut deps, 0, None, Some(200), None);
+	update(&mut deps, 100, Some(2), None, None);
+	// Queuing another change restarts the timelock for everything
+	assert_eq!(
+		pending(),
+		Some(PoolFactoryPendingConfig {
+			pair_code_id: Some(2),
+			default_total_fee_bps: Some(200),
+			apply_after: now + 3700,
+			..Default::default()
+		})
+	);
+
+	// Lowering the fee takes effect immediately, and the queued increase no longer applies
+	update(&mut deps, 200, None, Some(50), None);
+	assert_eq!(
+		pending(),
+		Some(PoolFactoryPendingConfig {
+			pair_code_id: Some(2),
+			apply_after: now + 3700,
+			..Default::default()
+		})
+	);
+	assert_eq!(PoolFactoryConfig::load_non_empty().unwrap().default_total_fee_bps, 50);
+
+	// Nothing is left queued once the code ID is set back
+	update(&mut deps, 300, Some(1), None, None);
+	assert_eq!(pending(), None);
+}
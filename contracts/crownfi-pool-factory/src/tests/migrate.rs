@@ -0,0 +1,19 @@
+use cosmwasm_std::testing::mock_env;
+use cw2::{get_contract_version, set_contract_version};
+
+use crate::{
+	contract::migrate,
+	msg::PoolFactoryMigrateMsg,
+	tests::{deps, init},
+};
+
+#[test]
+fn updates_contract_version() {
+	let mut deps = deps();
+	init(&mut deps, false);
+	set_contract_version(&mut deps.storage, "crownfi-pool-factory", "0.0.0").unwrap();
+
+	migrate(deps.as_mut(), mock_env(), PoolFactoryMigrateMsg {}).unwrap();
+	let c_version = get_contract_version(&deps.storage).unwrap();
+	assert_eq!(c_version.version, env!("CARGO_PKG_VERSION"));
+}
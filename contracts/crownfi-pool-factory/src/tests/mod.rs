@@ -0,0 +1,147 @@
+use cosmwasm_std::{
+	coin, from_json, testing::*, to_json_binary, Addr, ContractResult, MemoryStorage, OwnedDeps, Response, SystemError,
+	SystemResult, WasmQuery,
+};
+use crownfi_cw20_wrapper::msg::CW20WrapperQueryMsg;
+use crownfi_erc20_wrapper::msg::ERC20WrapperQueryMsg;
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
+
+use crate::contract::*;
+use crate::error::*;
+use crate::msg::*;
+use crate::state::*;
+
+mod execute;
+mod migrate;
+mod query;
+
+const CW20_WRAPPER: &str = "sei1242424242424242424242424242424242424242424242424242ss7lj9n";
+const ERC20_WRAPPER: &str = "sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a";
+const CW20_TOKEN: &str = "sei1zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zygskw0yqp";
+/// The CW20 wrapper's denom for `CW20_TOKEN`
+const CW20_DENOM: &str = "factory/sei1242424242424242424242424242424242424242424242424242ss7lj9n/cw20token";
+/// An ERC20 token which is the EVM pointer of `POINTEE_DENOM`
+const ERC20_TOKEN: &str = "0xabababababababababababababababababababab";
+/// The ERC20 wrapper's denom for `ERC20_TOKEN`
+const ERC20_DENOM: &str = "factory/sei1g3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zqttnl0a/crwnABABABABABABABABABABABABABABABABABABABAB";
+const POINTEE_DENOM: &str = "uatom";
+
+type TestDeps = OwnedDeps<MemoryStorage, MockApi, MockQuerier<SeiQueryWrapper>, SeiQueryWrapper>;
+
+enum AddressFactory {}
+impl AddressFactory {
+	/// supposed to be used as the contract owner/fee receiver etc
+	pub(crate) const ADMIN: &'static str = "sei1zgfgerl8qt9uldlr0y9w7qe97p7zyv5kwg2pge";
+	pub(crate) const FEE_RECEIVER: &'static str = "sei1grzhksjfvg2s8mvgetmkncv67pr90kk37cfdhq";
+}
+
+fn deps() -> TestDeps {
+	let mut querier = MockQuerier::<SeiQueryWrapper>::new(&[]);
+	// The wrappers only know of `CW20_TOKEN` and `ERC20_TOKEN`
+	querier.update_wasm(|query| match query {
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == CW20_WRAPPER => {
+			let CW20WrapperQueryMsg::UnwrappedAddrOf { denom } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			let result = (denom == CW20_DENOM).then(|| Addr::unchecked(CW20_TOKEN));
+			SystemResult::Ok(ContractResult::Ok(to_json_binary(&result).unwrap()))
+		}
+		WasmQuery::Smart { contract_addr, msg } if contract_addr == ERC20_WRAPPER => {
+			let ERC20WrapperQueryMsg::UnwrappedAddrOf { denom } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			// The wrapper returns the checksummed address
+			let result = (denom == ERC20_DENOM).then(|| "0xABABABABABABABABABABABABABABABABABABABAB".to_string());
+			SystemResult::Ok(ContractResult::Ok(to_json_binary(&result).unwrap()))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+
+	let mem = Box::new(MockStorage::default());
+	let mem_ptr = Box::leak(mem) as *mut MockStorage;
+	crownfi_cw_common::storage::base::set_global_storage(unsafe { Box::from_raw(mem_ptr) });
+
+	OwnedDeps {
+		querier,
+		storage: unsafe { mem_ptr.read() },
+		api: MockApi::default(),
+		custom_query_type: Default::default(),
+	}
+}
+
+/// Instantiates the factory with both wrappers configured, and `ERC20_TOKEN` registered as the pointer of
+/// `POINTEE_DENOM`.
+fn init(deps: &mut TestDeps, reject_duplicate_underlying_pairs: bool) -> Response<SeiMsg> {
+	let res = instantiate(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryInstantiateMsg {
+			config: PoolFactoryConfigJsonable {
+				admin: Addr::unchecked(AddressFactory::ADMIN),
+				fee_receiver: Addr::unchecked(AddressFactory::FEE_RECEIVER),
+				pair_code_id: 1,
+				lp_incentives: None,
+				default_total_fee_bps: 100,
+				default_maker_fee_bps: 50,
+				permissionless_pool_cration: false,
+				timelock_seconds: 0,
+				reject_duplicate_underlying_pairs,
+				max_referral_fee_bps: 0,
+			},
+			guardian: None,
+		},
+	)
+	.unwrap();
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			pair_code_id: None,
+			lp_incentives: None,
+			default_total_fee_bps: None,
+			default_maker_fee_bps: None,
+			permissionless_pool_cration: None,
+			timelock_seconds: None,
+			cw20_wrapper: Some(Addr::unchecked(CW20_WRAPPER)),
+			erc20_wrapper: Some(Addr::unchecked(ERC20_WRAPPER)),
+			reject_duplicate_underlying_pairs: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolFactoryExecuteMsg::SetTokenMetadata {
+			denom: POINTEE_DENOM.into(),
+			metadata: Some(PoolFactoryTokenMetadata {
+				display_name: "Atom".into(),
+				symbol: "ATOM".into(),
+				decimals: 6,
+				logo_uri: None,
+				evm_pointer: Some(ERC20_TOKEN.into()),
+				wrapper_origin: None,
+			}),
+		},
+	)
+	.unwrap();
+	res
+}
+
+fn create_pool(deps: &mut TestDeps, denoms: [&str; 2]) -> Result<Response<SeiMsg>, PoolFactoryContractError> {
+	let mut funds = vec![coin(1000, denoms[0]), coin(1000, denoms[1])];
+	funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &funds),
+		PoolFactoryExecuteMsg::CreatePool {
+			left_denom: denoms[0].into(),
+			initial_shares_receiver: None,
+		},
+	)
+}
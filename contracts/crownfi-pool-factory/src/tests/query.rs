@@ -0,0 +1,30 @@
+use cosmwasm_std::{coin, testing::mock_env};
+
+use crate::{
+	contract::query,
+	error::PoolFactoryContractError,
+	msg::{PoolFactoryBatchSimulateRequest, PoolFactoryQueryMsg},
+	tests::{deps, init},
+};
+
+#[test]
+fn batch_simulate_limits_the_amount_of_requests() {
+	let mut deps = deps();
+	init(&mut deps, false);
+
+	let requests = vec![
+		PoolFactoryBatchSimulateRequest {
+			pair: ["usei".into(), "uusdc".into()],
+			offer: coin(1000, "usei"),
+		};
+		101
+	];
+	assert_eq!(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolFactoryQueryMsg::BatchSimulate { requests }
+		),
+		Err(PoolFactoryContractError::TooManyRequests(100))
+	);
+}
@@ -1,4 +1,6 @@
 use cosmwasm_std::{attr, Addr, Attribute, Coin, Uint128};
+
+use crate::contract::pool::PoolPairCalcSwapResult;
 // Note: These are used instead of events because you can filter by _contract_addr as well the attributes when querying
 
 #[inline]
@@ -49,24 +51,150 @@ pub fn attr_withdraw_and_split_liquidity(
 	]
 }
 
+#[inline]
+pub fn attr_flash_swap(sender: Addr, receiver: Addr, lent_assets: [&Coin; 2]) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "flash_swap"),
+		attr("sender", sender),
+		attr("receiver", receiver),
+		attr("lent_assets", format!("{}, {}", lent_assets[0], lent_assets[1])),
+	]
+}
+
+#[inline]
+pub fn attr_flash_swap_repay(
+	repaid_assets: [&Coin; 2],
+	maker_fee_assets: [&Coin; 2],
+) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "flash_swap_repay"),
+		attr("repaid_assets", format!("{}, {}", repaid_assets[0], repaid_assets[1])),
+		attr(
+			"maker_fee_assets",
+			format!("{}, {}", maker_fee_assets[0], maker_fee_assets[1]),
+		),
+	]
+}
+
 #[inline]
 pub fn attr_swap(
 	sender: Addr,
 	receiver: Addr,
 	in_coin: &Coin,
 	out_coin: &Coin,
-	spread_amount: Uint128,
-	total_fee_amount: Uint128,
-	maker_fee_amount: Uint128,
+	swap_result: &PoolPairCalcSwapResult,
+	fee_denom: &str,
+	referrer: Option<&Addr>,
 ) -> impl IntoIterator<Item = Attribute> {
-	vec![
+	let mut attrs = vec![
 		attr("action", "swap"),
 		attr("sender", sender),
 		attr("receiver", receiver),
 		attr("in_coin", in_coin.to_string()),
 		attr("out_coin", out_coin.to_string()),
-		attr("spread_amount", spread_amount),
-		attr("total_fee_amount", total_fee_amount),
-		attr("maker_fee_amount", maker_fee_amount),
+		attr("spread_amount", swap_result.spread_amount),
+		attr("total_fee_amount", swap_result.total_fee_amount),
+		attr("maker_fee_amount", swap_result.maker_fee_amount),
+		attr("fee_denom", fee_denom),
+	];
+	if let Some(referrer) = referrer {
+		attrs.push(attr("referrer", referrer));
+		attrs.push(attr("referral_fee_amount", swap_result.referral_fee_amount));
+	}
+	attrs
+}
+
+#[inline]
+pub fn attr_skim(sender: Addr, receiver: Addr, skimmed_assets: [&Coin; 2]) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "skim"),
+		attr("sender", sender),
+		attr("receiver", receiver),
+		attr(
+			"skimmed_assets",
+			format!("{}, {}", skimmed_assets[0], skimmed_assets[1]),
+		),
+	]
+}
+
+#[inline]
+pub fn attr_sync(sender: Addr, reserves: [&Coin; 2]) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "sync"),
+		attr("sender", sender),
+		attr("reserves", format!("{}, {}", reserves[0], reserves[1])),
+	]
+}
+
+#[inline]
+pub fn attr_reconcile_supply(
+	sender: Addr,
+	previous_supply: Uint128,
+	supply: Uint128,
+) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "reconcile_supply"),
+		attr("sender", sender),
+		attr("previous_supply", previous_supply),
+		attr("supply", supply),
+	]
+}
+
+#[inline]
+pub fn attr_place_long_term_order(
+	order_id: u64,
+	owner: Addr,
+	sell_rate: &Coin,
+	end_time: u64,
+	refund: &Coin,
+) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "place_long_term_order"),
+		attr("order_id", order_id.to_string()),
+		attr("owner", owner),
+		attr("sell_rate", sell_rate.to_string()),
+		attr("end_time", end_time.to_string()),
+		attr("refund", refund.to_string()),
+	]
+}
+
+#[inline]
+pub fn attr_cancel_long_term_order(
+	order_id: u64,
+	owner: Addr,
+	unsold: &Coin,
+	proceeds: &Coin,
+) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "cancel_long_term_order"),
+		attr("order_id", order_id.to_string()),
+		attr("owner", owner),
+		attr("unsold", unsold.to_string()),
+		attr("proceeds", proceeds.to_string()),
+	]
+}
+
+#[inline]
+pub fn attr_withdraw_long_term_order(
+	order_id: u64,
+	owner: Addr,
+	proceeds: &Coin,
+	completed: bool,
+) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "withdraw_long_term_order"),
+		attr("order_id", order_id.to_string()),
+		attr("owner", owner),
+		attr("proceeds", proceeds.to_string()),
+		attr("completed", completed.to_string()),
+	]
+}
+
+#[inline]
+pub fn attr_execute_virtual_orders(sender: Addr, last_settlement: u64) -> impl IntoIterator<Item = Attribute> {
+	vec![
+		attr("action", "execute_virtual_orders"),
+		attr("sender", sender),
+		attr("last_settlement", last_settlement.to_string()),
 	]
 }
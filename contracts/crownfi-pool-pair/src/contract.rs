@@ -2,12 +2,16 @@ use std::num::NonZeroU8;
 
 use bytemuck::Zeroable;
 use cosmwasm_std::{
-	coin, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
-	Uint128, WasmMsg,
+	attr, coin, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+	QuerierWrapper, Reply, ReplyOn, Response, StdError, SubMsg, Uint128, WasmMsg,
 };
-use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+use crownfi_lp_incentives_contract::msg::{LpIncentivesExecuteMsg, LpIncentivesPoolInfo, LpIncentivesQueryMsg};
 use crownfi_swaps_common::{
-	data_types::pair_id::{CanonicalPoolPairIdentifier, PoolPairIdentifier},
+	data_types::{
+		pair_id::{CanonicalPoolPairIdentifier, PoolPairIdentifier},
+		pending_admin::{PendingAdminTransfer, PendingAdminTransferJsonable},
+	},
 	error::CrownfiSwapsCommonError,
 	validation::msg::{must_pay_one_of_pair, must_pay_pair, two_coins},
 };
@@ -16,27 +20,58 @@ use cw_utils::{must_pay, nonpayable, PaymentError};
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 use crate::{
-	attributes::{attr_provide_liquidity, attr_swap, attr_withdraw_and_split_liquidity, attr_withdraw_liquidity},
+	attributes::{
+		attr_cancel_long_term_order, attr_execute_virtual_orders, attr_flash_swap, attr_flash_swap_repay,
+		attr_place_long_term_order, attr_provide_liquidity, attr_reconcile_supply, attr_skim, attr_swap, attr_sync,
+		attr_withdraw_and_split_liquidity, attr_withdraw_liquidity, attr_withdraw_long_term_order,
+	},
 	error::PoolPairContractError,
-	msg::{PoolPairExecuteMsg, PoolPairInstantiateMsg, PoolPairQueryMsg, PoolPairQuerySimulateDepositResponse},
-	state::{PoolPairConfig, PoolPairConfigFlags, PoolPairConfigJsonable, VolumeStatisticsCounter},
-	workarounds::{burn_token_workaround, mint_workaround, total_supply_workaround},
+	msg::{
+		PoolPairExecuteMsg, PoolPairInstantiateMsg, PoolPairMigrateMsg, PoolPairQueryLongTermOrderResponse,
+		PoolPairQueryLongTermOrdersResponse, PoolPairQueryMsg, PoolPairQueryPositionReportResponse,
+		PoolPairQueryReservesResponse, PoolPairQueryShareSupplyResponse, PoolPairQuerySimulateDepositResponse,
+		PoolPairSwapReferral,
+	},
+	state::{
+		get_long_term_order_expiries_store, get_long_term_order_owners_store, get_long_term_orders_store,
+		get_positions_store, PoolPairConfig, PoolPairConfigFlags, PoolPairConfigJsonable, PoolPairFeeGrowth,
+		PoolPairFlashSwapState, PoolPairLock, PoolPairLongTermOrder, PoolPairLongTermOrders, PoolPairReserves,
+		PoolPairStakingConfig, VolumeStatisticsCounter,
+	},
 };
 
 use self::{
 	pool::{
-		balances_into_share_value, calc_naive_swap, calc_shares_to_mint, calc_swap, get_pool_balance, DEFAULT_SLIPPAGE,
-		MAX_ALLOWED_TOLERANCE,
+		balances_into_share_value, calc_fee_amount, calc_maker_fee_amount, calc_naive_swap, calc_shares_to_mint,
+		calc_swap, check_flash_swap_invariant, check_referral_fee_bps, get_pool_balance, DEFAULT_SLIPPAGE,
+		MAX_ALLOWED_TOLERANCE, MINIMUM_INITIAL_SHARES,
+	},
+	positions::{
+		accrue_position_fees, add_lp_fees, impermanent_loss, record_deposit, record_lp_fees, record_withdrawal,
+		value_in_each_denom,
+	},
+	shares::{
+		bank_share_supply, burn_shares, lp_denom, mint_shares, set_tracked_share_supply, share_supply,
+		tracked_share_supply, LP_SUBDENOM,
+	},
+	twamm::{
+		long_term_order_end_time, settle_long_term_orders, settled_reserves, simulate_settlement,
+		LongTermOrdersSettlement, MAX_LONG_TERM_ORDERS_PER_OWNER, MAX_LONG_TERM_ORDER_DURATION_SECONDS,
 	},
-	shares::{lp_denom, LP_SUBDENOM},
 };
 
 pub mod pool;
+pub mod positions;
 pub mod shares;
+pub mod twamm;
 
 const CONTRACT_NAME: &str = "crownfi-pool-pair-contract";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// `reply` call code IDs used in a sub-message.
+pub(crate) const FLASH_SWAP_REPLY_ID: u64 = 0xe29aa1666c617368;
+pub(crate) const UNLOCK_REPLY_ID: u64 = 0xf09f94936c6f636b;
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 #[inline]
 pub fn instantiate(
@@ -47,7 +82,17 @@ pub fn instantiate(
 ) -> Result<Response<SeiMsg>, PoolPairContractError> {
 	let [left_coin, right_coin] = two_coins(&msg_info)?;
 
-	PoolPairConfig::try_from(&msg.config)?.save()?;
+	let config = PoolPairConfig::try_from(&msg.config)?;
+	if !config.valid_fee_bps() {
+		return Err(PoolPairContractError::InvalidFeeBps);
+	}
+	config.save()?;
+	if let Some(lp_staking) = msg.lp_staking {
+		PoolPairStakingConfig {
+			lp_staking: lp_staking.try_into()?,
+		}
+		.save()?;
+	}
 	let new_denom = lp_denom(&env);
 
 	let pool_id = PoolPairIdentifier {
@@ -58,6 +103,10 @@ pub fn instantiate(
 		.as_canonical()
 		.expect("incoming coins should already be canonical")
 		.save()?;
+	PoolPairReserves {
+		amounts: [left_coin.amount.u128(), right_coin.amount.u128()],
+	}
+	.save()?;
 
 	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 	let mint_amount = calc_shares_to_mint(
@@ -66,7 +115,14 @@ pub fn instantiate(
 		&[left_coin.amount, right_coin.amount],
 		Decimal::percent(1), // This value is not considered in initial mints anyway
 	)?;
-	Ok(mint_workaround(
+	if msg.config.position_tracking {
+		record_deposit(
+			&msg.shares_receiver,
+			mint_amount - MINIMUM_INITIAL_SHARES,
+			[left_coin.amount, right_coin.amount],
+		)?;
+	}
+	Ok(mint_shares(
 		Response::new().add_message(SeiMsg::CreateDenom {
 			subdenom: LP_SUBDENOM.to_string(),
 		}),
@@ -74,10 +130,31 @@ pub fn instantiate(
 	)?
 	.add_message(BankMsg::Send {
 		to_address: msg.shares_receiver.into_string(),
-		amount: vec![coin(mint_amount.u128(), new_denom)],
+		// The rest stays with this contract forever, so the share supply can never return to 0.
+		amount: vec![coin((mint_amount - MINIMUM_INITIAL_SHARES).u128(), new_denom)],
 	}))
 }
 
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn migrate(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	_msg: PoolPairMigrateMsg,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	// Pools from before the reserves were tracked used their balances as their reserves.
+	if PoolPairReserves::load()?.is_none() {
+		let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+		PoolPairReserves {
+			amounts: get_pool_balance(&deps.querier, &env, &pool_id)?.map(|coin| coin.amount.u128()),
+		}
+		.save()?;
+	}
+	// Pools from before `MINIMUM_INITIAL_SHARES` was introduced are left without any locked shares, as they can't be
+	// taken back from their holders.
+	set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+	Ok(Response::new().add_attributes(vec![attr("action", "migrate"), attr("version", CONTRACT_VERSION)]))
+}
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn execute(
 	deps: DepsMut<SeiQueryWrapper>,
@@ -85,19 +162,59 @@ pub fn execute(
 	info: MessageInfo,
 	msg: PoolPairExecuteMsg,
 ) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	if PoolPairLock::is_locked()? {
+		return Err(PoolPairContractError::PoolLocked);
+	}
+	// Long-term orders are settled first, so everything else sees the reserves as they are now. Only messages which
+	// trade against or add to the reserves require this to succeed, everything else carries on from the last settled
+	// state so a failing settlement can't lock anyone's funds.
+	match settle_long_term_orders(env.block.time.seconds()) {
+		Ok(settlement_fees) => record_position_fees(&deps.querier, &env, settlement_fees)?,
+		Err(err) if settlement_required(&msg) => return Err(err),
+		Err(_) => {}
+	}
 	match msg {
 		PoolPairExecuteMsg::UpdateConfig {
-			admin,
 			fee_receiver,
 			total_fee_bps,
 			maker_fee_bps,
 			endorsed,
-		} => process_update_config(deps, info, admin, fee_receiver, total_fee_bps, maker_fee_bps, endorsed),
+			lp_staking,
+			long_term_orders,
+			position_tracking,
+			fee_on_input,
+			max_referral_fee_bps,
+		} => process_update_config(
+			deps,
+			info,
+			fee_receiver,
+			total_fee_bps,
+			maker_fee_bps,
+			endorsed,
+			lp_staking,
+			long_term_orders,
+			position_tracking,
+			fee_on_input,
+			max_referral_fee_bps,
+		),
+		PoolPairExecuteMsg::ProposeAdmin { admin } => process_propose_admin(deps, env, info, admin),
+		PoolPairExecuteMsg::AcceptAdmin {} => process_accept_admin(deps, env, info),
+		PoolPairExecuteMsg::CancelAdminTransfer {} => process_cancel_admin_transfer(deps, info),
+		PoolPairExecuteMsg::SetPaused { swaps, deposits } => process_set_paused(deps, info, swaps, deposits),
 		PoolPairExecuteMsg::ProvideLiquidity {
 			slippage_tolerance,
 			receiver,
 			receiver_payload,
-		} => process_provide_liquidity(deps, env, info, slippage_tolerance, receiver, receiver_payload),
+			auto_stake,
+		} => process_provide_liquidity(
+			deps,
+			env,
+			info,
+			slippage_tolerance,
+			receiver,
+			receiver_payload,
+			auto_stake,
+		),
 		PoolPairExecuteMsg::WithdrawLiquidity {
 			receiver,
 			receiver_payload,
@@ -121,6 +238,7 @@ pub fn execute(
 			slippage_tolerance,
 			receiver,
 			receiver_payload,
+			referral,
 		} => process_swap(
 			deps,
 			env,
@@ -129,18 +247,82 @@ pub fn execute(
 			slippage_tolerance,
 			receiver,
 			receiver_payload,
+			referral,
 		),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts,
+			receiver,
+			payload,
+		} => process_flash_swap(deps, env, info, amounts, receiver, payload),
+		PoolPairExecuteMsg::Skim { receiver } => process_skim(deps, env, info, receiver),
+		PoolPairExecuteMsg::Sync {} => process_sync(deps, env, info),
+		PoolPairExecuteMsg::ReconcileSupply {} => process_reconcile_supply(deps, env, info),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration } => process_place_long_term_order(deps, env, info, duration),
+		PoolPairExecuteMsg::CancelLongTermOrder { order_id } => {
+			process_cancel_long_term_order(deps, env, info, order_id)
+		}
+		PoolPairExecuteMsg::WithdrawLongTermOrder { order_id } => {
+			process_withdraw_long_term_order(deps, env, info, order_id)
+		}
+		PoolPairExecuteMsg::ExecuteVirtualOrders {} => process_execute_virtual_orders(deps, env, info),
 	}
 }
 
+/// Whether the message can only be executed once the long-term orders have been settled up until now.
+fn settlement_required(msg: &PoolPairExecuteMsg) -> bool {
+	matches!(
+		msg,
+		PoolPairExecuteMsg::ProvideLiquidity { .. }
+			| PoolPairExecuteMsg::Swap { .. }
+			| PoolPairExecuteMsg::FlashSwap { .. }
+			| PoolPairExecuteMsg::Skim { .. }
+			| PoolPairExecuteMsg::Sync {}
+			| PoolPairExecuteMsg::PlaceLongTermOrder { .. }
+			| PoolPairExecuteMsg::ExecuteVirtualOrders {}
+	)
+}
+
+#[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
+pub fn reply(deps: DepsMut<SeiQueryWrapper>, env: Env, msg: Reply) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	match msg.id {
+		FLASH_SWAP_REPLY_ID => process_flash_swap_repay(deps, env),
+		UNLOCK_REPLY_ID => {
+			PoolPairLock::remove();
+			Ok(Response::new())
+		}
+		_ => Err(StdError::generic_err(format!("Reply ID {0} is unknown", msg.id)).into()),
+	}
+}
+
+/// Locks the pool until all of the response's messages have been executed. This must be used when any of them execute
+/// a contract specified by the user, see `PoolPairLock`.
+fn lock_until_complete(env: &Env, mut response: Response<SeiMsg>) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	PoolPairLock {
+		height: env.block.height,
+	}
+	.save()?;
+	let last_msg = response
+		.messages
+		.last_mut()
+		.expect("lock_until_complete should only be used on responses with messages");
+	last_msg.id = UNLOCK_REPLY_ID;
+	last_msg.reply_on = ReplyOn::Success;
+	Ok(response)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn process_update_config(
 	_deps: DepsMut<SeiQueryWrapper>,
 	msg_info: MessageInfo,
-	admin: Option<Addr>,
 	fee_receiver: Option<Addr>,
 	total_fee_bps: Option<u16>,
 	maker_fee_bps: Option<u16>,
 	endorsed: Option<bool>,
+	lp_staking: Option<Addr>,
+	long_term_orders: Option<bool>,
+	position_tracking: Option<bool>,
+	fee_on_input: Option<bool>,
+	max_referral_fee_bps: Option<u16>,
 ) -> Result<Response<SeiMsg>, PoolPairContractError> {
 	nonpayable(&msg_info)?;
 	let mut config = PoolPairConfig::load_non_empty()?;
@@ -149,9 +331,6 @@ fn process_update_config(
 			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
 		);
 	}
-	if let Some(admin) = admin {
-		config.admin = admin.try_into()?;
-	}
 	if let Some(fee_receiver) = fee_receiver {
 		config.fee_receiver = fee_receiver.try_into()?;
 	}
@@ -168,10 +347,122 @@ fn process_update_config(
 			config.flags &= !PoolPairConfigFlags::ENDORSED;
 		}
 	}
+	if let Some(long_term_orders) = long_term_orders {
+		config
+			.flags
+			.set(PoolPairConfigFlags::LONG_TERM_ORDERS, long_term_orders);
+	}
+	if let Some(position_tracking) = position_tracking {
+		config
+			.flags
+			.set(PoolPairConfigFlags::POSITION_TRACKING, position_tracking);
+	}
+	if let Some(fee_on_input) = fee_on_input {
+		config.flags.set(PoolPairConfigFlags::FEE_ON_INPUT, fee_on_input);
+	}
+	if let Some(max_referral_fee_bps) = max_referral_fee_bps {
+		config.max_referral_fee_bps = max_referral_fee_bps;
+	}
+	if !config.valid_fee_bps() {
+		return Err(PoolPairContractError::InvalidFeeBps);
+	}
 	config.save()?;
+	if let Some(lp_staking) = lp_staking {
+		PoolPairStakingConfig {
+			lp_staking: lp_staking.try_into()?,
+		}
+		.save()?;
+	}
 	Ok(Response::new().add_attribute("action", "update_config"))
 }
 
+fn process_propose_admin(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	admin: Addr,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolPairConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	PendingAdminTransfer {
+		new_admin: (&admin).try_into()?,
+		accept_after: env.block.time.seconds(),
+	}
+	.save()?;
+	Ok(Response::new().add_attributes(vec![attr("action", "propose_admin"), attr("new_admin", admin)]))
+}
+
+fn process_accept_admin(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let pending = PendingAdminTransfer::load_acceptable::<PoolPairContractError>(
+		&(&msg_info.sender).try_into()?,
+		env.block.time.seconds(),
+	)?;
+	let mut config = PoolPairConfig::load_non_empty()?;
+	config.admin = pending.new_admin;
+	config.save()?;
+	PendingAdminTransfer::remove();
+	Ok(Response::new().add_attributes(vec![attr("action", "accept_admin"), attr("new_admin", msg_info.sender)]))
+}
+
+fn process_cancel_admin_transfer(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let config = PoolPairConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	PendingAdminTransfer::load_non_empty::<PoolPairContractError>()?;
+	PendingAdminTransfer::remove();
+	Ok(Response::new().add_attribute("action", "cancel_admin_transfer"))
+}
+
+fn process_set_paused(
+	_deps: DepsMut<SeiQueryWrapper>,
+	msg_info: MessageInfo,
+	swaps: Option<bool>,
+	deposits: Option<bool>,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let mut config = PoolPairConfig::load_non_empty()?;
+	if config.admin != msg_info.sender.try_into()? {
+		return Err(
+			CrownfiSwapsCommonError::Unauthorized("Sender is not the currently configured admin".into()).into(),
+		);
+	}
+	if let Some(swaps) = swaps {
+		config.flags.set(PoolPairConfigFlags::SWAPS_PAUSED, swaps);
+	}
+	if let Some(deposits) = deposits {
+		config.flags.set(PoolPairConfigFlags::DEPOSITS_PAUSED, deposits);
+	}
+	config.save()?;
+	Ok(Response::new().add_attributes(vec![
+		attr("action", "set_paused"),
+		attr(
+			"swaps_paused",
+			config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED).to_string(),
+		),
+		attr(
+			"deposits_paused",
+			config.flags.contains(PoolPairConfigFlags::DEPOSITS_PAUSED).to_string(),
+		),
+	]))
+}
+
 pub fn process_provide_liquidity(
 	deps: DepsMut<SeiQueryWrapper>,
 	env: Env,
@@ -179,52 +470,92 @@ pub fn process_provide_liquidity(
 	slippage_tolerance: Option<Decimal>,
 	receiver: Option<Addr>,
 	receiver_payload: Option<Binary>,
+	auto_stake: bool,
 ) -> Result<Response<SeiMsg>, PoolPairContractError> {
 	let slippage_tolerance = slippage_tolerance.unwrap_or(DEFAULT_SLIPPAGE);
 	if slippage_tolerance > MAX_ALLOWED_TOLERANCE {
 		return Err(PoolPairContractError::ToleranceTooHigh);
 	}
+	let pool_config = PoolPairConfig::load_non_empty()?;
+	if pool_config.flags.contains(PoolPairConfigFlags::DEPOSITS_PAUSED) {
+		return Err(PoolPairContractError::DepositsPaused);
+	}
 	let receiver = receiver.unwrap_or(msg_info.sender.clone());
-	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 	let pool_lp_denom = lp_denom(&env);
+	let lp_staking = if auto_stake {
+		if receiver_payload.is_some() {
+			return Err(PoolPairContractError::AutoStakeWithPayload);
+		}
+		let lp_staking =
+			PoolPairStakingConfig::lp_staking_addr()?.ok_or(PoolPairContractError::AutoStakeUnavailable)?;
+		// Staking is only possible once the pool has had a reward program, until then the shares are just minted.
+		let incentive_pool: Option<LpIncentivesPoolInfo> = deps.querier.query_wasm_smart(
+			&lp_staking,
+			&LpIncentivesQueryMsg::Pool {
+				lp_denom: pool_lp_denom.clone(),
+			},
+		)?;
+		incentive_pool.map(|_| lp_staking)
+	} else {
+		None
+	};
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 
-	// The balance has been added before this function is called.
-	let current_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
+	let mut reserves = PoolPairReserves::load_non_empty()?;
 	let incoming_assets = must_pay_pair(&msg_info, &pool_id)?;
 
 	let mint_amount = calc_shares_to_mint(
-		total_supply_workaround(&pool_lp_denom),
-		&[
-			current_balances[0].amount - incoming_assets[0].amount,
-			current_balances[1].amount - incoming_assets[1].amount,
-		],
+		share_supply(&deps.querier, &pool_lp_denom),
+		&reserves.amounts(),
 		&[incoming_assets[0].amount, incoming_assets[1].amount],
 		slippage_tolerance,
 	)?;
 	if mint_amount.is_zero() {
 		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
 	}
-	Ok(
-		mint_workaround(Response::new(), coin(mint_amount.u128(), pool_lp_denom.clone()))?
-			.add_attributes(attr_provide_liquidity(
-				msg_info.sender.clone(),
-				receiver.clone(),
-				incoming_assets,
-				mint_amount,
-			))
-			.add_message(if let Some(receiver_payload) = receiver_payload {
-				CosmosMsg::from(WasmMsg::Execute {
-					contract_addr: receiver.into_string(),
-					msg: receiver_payload,
-					funds: vec![coin(mint_amount.u128(), pool_lp_denom)],
-				})
-			} else {
-				CosmosMsg::from(BankMsg::Send {
-					to_address: receiver.into_string(),
-					amount: vec![coin(mint_amount.u128(), pool_lp_denom)],
-				})
-			}),
-	)
+	reserves.amounts[0] += incoming_assets[0].amount.u128();
+	reserves.amounts[1] += incoming_assets[1].amount.u128();
+	reserves.save()?;
+	if pool_config.flags.contains(PoolPairConfigFlags::POSITION_TRACKING) {
+		record_deposit(
+			&receiver,
+			mint_amount,
+			[incoming_assets[0].amount, incoming_assets[1].amount],
+		)?;
+	}
+	let executes_receiver = receiver_payload.is_some();
+	let response = mint_shares(Response::new(), coin(mint_amount.u128(), pool_lp_denom.clone()))?
+		.add_attributes(attr_provide_liquidity(
+			msg_info.sender.clone(),
+			receiver.clone(),
+			incoming_assets,
+			mint_amount,
+		))
+		.add_message(if let Some(lp_staking) = lp_staking {
+			CosmosMsg::from(WasmMsg::Execute {
+				contract_addr: lp_staking.into_string(),
+				msg: to_json_binary(&LpIncentivesExecuteMsg::Stake {
+					receiver: Some(receiver),
+				})?,
+				funds: vec![coin(mint_amount.u128(), pool_lp_denom)],
+			})
+		} else if let Some(receiver_payload) = receiver_payload {
+			CosmosMsg::from(WasmMsg::Execute {
+				contract_addr: receiver.into_string(),
+				msg: receiver_payload,
+				funds: vec![coin(mint_amount.u128(), pool_lp_denom)],
+			})
+		} else {
+			CosmosMsg::from(BankMsg::Send {
+				to_address: receiver.into_string(),
+				amount: vec![coin(mint_amount.u128(), pool_lp_denom)],
+			})
+		});
+	if executes_receiver {
+		lock_until_complete(&env, response)
+	} else {
+		Ok(response)
+	}
 }
 
 pub fn process_withdraw_liquidity(
@@ -239,18 +570,20 @@ pub fn process_withdraw_liquidity(
 	let pool_lp_denom = lp_denom(&env);
 
 	let withdrawn_share_amount = must_pay(&msg_info, &pool_lp_denom)?;
-	let total_share_supply = total_supply_workaround(&pool_lp_denom);
-
-	// The balance has been added before this function is called.
-	let refund_assets = balances_into_share_value(
-		withdrawn_share_amount,
-		total_share_supply,
-		get_pool_balance(&deps.querier, &env, &pool_id)?,
-	);
+	let total_share_supply = share_supply(&deps.querier, &pool_lp_denom);
+
+	let mut reserves = PoolPairReserves::load_non_empty()?;
+	let refund_assets =
+		balances_into_share_value(withdrawn_share_amount, total_share_supply, reserves.as_coins(&pool_id));
 	if refund_assets[0].amount.is_zero() || refund_assets[1].amount.is_zero() {
 		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
 	}
-	Ok(burn_token_workaround(Response::new(), msg_info.funds[0].clone())?
+	reserves.amounts[0] -= refund_assets[0].amount.u128();
+	reserves.amounts[1] -= refund_assets[1].amount.u128();
+	reserves.save()?;
+	record_withdrawal(&msg_info.sender, withdrawn_share_amount)?;
+	let executes_receiver = receiver_payload.is_some();
+	let response = burn_shares(Response::new(), msg_info.funds[0].clone())?
 		.add_attributes(attr_withdraw_liquidity(
 			msg_info.sender,
 			receiver.clone(),
@@ -268,7 +601,12 @@ pub fn process_withdraw_liquidity(
 				to_address: receiver.into_string(),
 				amount: refund_assets.into(),
 			})
-		}))
+		});
+	if executes_receiver {
+		lock_until_complete(&env, response)
+	} else {
+		Ok(response)
+	}
 }
 
 pub fn process_withdraw_and_split_liquidity(
@@ -286,18 +624,20 @@ pub fn process_withdraw_and_split_liquidity(
 	let pool_lp_denom = lp_denom(&env);
 
 	let withdrawn_share_amount = must_pay(&msg_info, &pool_lp_denom)?;
-	let total_share_supply = total_supply_workaround(&pool_lp_denom);
-
-	// The balance has been added before this function is called.
-	let refund_assets = balances_into_share_value(
-		withdrawn_share_amount,
-		total_share_supply,
-		get_pool_balance(&deps.querier, &env, &pool_id)?,
-	);
+	let total_share_supply = share_supply(&deps.querier, &pool_lp_denom);
+
+	let mut reserves = PoolPairReserves::load_non_empty()?;
+	let refund_assets =
+		balances_into_share_value(withdrawn_share_amount, total_share_supply, reserves.as_coins(&pool_id));
 	if refund_assets[0].amount.is_zero() || refund_assets[1].amount.is_zero() {
 		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
 	}
-	Ok(burn_token_workaround(Response::new(), msg_info.funds[0].clone())?
+	reserves.amounts[0] -= refund_assets[0].amount.u128();
+	reserves.amounts[1] -= refund_assets[1].amount.u128();
+	reserves.save()?;
+	record_withdrawal(&msg_info.sender, withdrawn_share_amount)?;
+	let executes_receiver = left_receiver_payload.is_some() || right_receiver_payload.is_some();
+	let response = burn_shares(Response::new(), msg_info.funds[0].clone())?
 		.add_attributes(attr_withdraw_and_split_liquidity(
 			msg_info.sender,
 			[&left_receiver, &right_receiver],
@@ -327,9 +667,15 @@ pub fn process_withdraw_and_split_liquidity(
 				to_address: right_receiver.into_string(),
 				amount: refund_assets[1..].into(),
 			})
-		}))
+		});
+	if executes_receiver {
+		lock_until_complete(&env, response)
+	} else {
+		Ok(response)
+	}
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_swap(
 	deps: DepsMut<SeiQueryWrapper>,
 	env: Env,
@@ -338,6 +684,7 @@ pub fn process_swap(
 	slippage_tolerance: Option<Decimal>,
 	receiver: Option<Addr>,
 	receiver_payload: Option<Binary>,
+	referral: Option<PoolPairSwapReferral>,
 ) -> Result<Response<SeiMsg>, PoolPairContractError> {
 	let receiver = receiver.unwrap_or(msg_info.sender.clone());
 	let slippage_tolerance = slippage_tolerance.unwrap_or(DEFAULT_SLIPPAGE);
@@ -346,14 +693,17 @@ pub fn process_swap(
 	}
 	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 	let pool_config = PoolPairConfig::load_non_empty()?;
+	if pool_config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED) {
+		return Err(PoolPairContractError::SwapsPaused);
+	}
 	let payment = must_pay_one_of_pair(&msg_info, &pool_id)?;
+	let referral_fee_bps = referral.as_ref().map(|referral| referral.fee_bps).unwrap_or_default();
+	check_referral_fee_bps(referral_fee_bps, pool_config.max_referral_fee_bps)?;
 
-	let mut pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
-	// The exchange calculations must be done from when before the funds where recieved.
-	pool_balances[payment.inverse as usize].amount -= payment.amount;
-
+	let fee_on_input = pool_config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT);
+	let mut reserves = PoolPairReserves::load_non_empty()?;
 	let swap_result = calc_swap(
-		&pool_balances.map(|coin| coin.amount),
+		&reserves.amounts(),
 		payment.amount,
 		pool_config.total_fee_bps,
 		if pool_config.fee_receiver == Zeroable::zeroed() {
@@ -361,6 +711,8 @@ pub fn process_swap(
 		} else {
 			pool_config.maker_fee_bps
 		},
+		referral_fee_bps,
+		fee_on_input,
 		payment.inverse,
 		expected_result,
 		slippage_tolerance,
@@ -369,11 +721,34 @@ pub fn process_swap(
 		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
 	}
 
-	let total_output = swap_result.result_amount + swap_result.maker_fee_amount;
+	// The maker fee leaves the pool from whichever side the fee was taken from, the rest of the fee stays in it. The
+	// referral fee is always part of the output.
+	let fee_is_right = if fee_on_input {
+		payment.inverse
+	} else {
+		!payment.inverse
+	};
+	let (total_input, total_output) = if fee_on_input {
+		(
+			payment.amount - swap_result.maker_fee_amount,
+			swap_result.result_amount + swap_result.referral_fee_amount,
+		)
+	} else {
+		(
+			payment.amount,
+			swap_result.result_amount + swap_result.referral_fee_amount + swap_result.maker_fee_amount,
+		)
+	};
+	reserves.amounts[payment.inverse as usize] += total_input.u128();
+	reserves.amounts[(!payment.inverse) as usize] -= total_output.u128();
+	reserves.save()?;
+	let mut lp_fees = [Uint128::zero(); 2];
+	lp_fees[fee_is_right as usize] = swap_result.total_fee_amount - swap_result.maker_fee_amount;
+	record_position_fees(&deps.querier, &env, lp_fees)?;
 	let (amt_left, amt_right) = payment
 		.inverse
-		.then(|| (total_output, payment.amount))
-		.unwrap_or_else(|| (payment.amount, total_output));
+		.then(|| (total_output, total_input))
+		.unwrap_or_else(|| (total_input, total_output));
 
 	VolumeStatisticsCounter::new()?.update_volumes(
 		env.block.time,
@@ -390,21 +765,30 @@ pub fn process_swap(
 		// Because a function that takes `&mut self` and returns `&mut self` is too 5head apparently
 		Response::new().add_message(BankMsg::Send {
 			to_address: Addr::try_from(pool_config.fee_receiver)?.into_string(),
+			amount: vec![coin(swap_result.maker_fee_amount.u128(), pool_id.denom(fee_is_right))],
+		})
+	};
+	let referrer = referral.map(|referral| referral.address);
+	let response = match referrer.as_ref() {
+		Some(referrer) if !swap_result.referral_fee_amount.is_zero() => response.add_message(BankMsg::Send {
+			to_address: referrer.to_string(),
 			amount: vec![coin(
-				swap_result.maker_fee_amount.u128(),
+				swap_result.referral_fee_amount.u128(),
 				pool_id.denom(!payment.inverse),
 			)],
-		})
+		}),
+		_ => response,
 	};
-	Ok(response
+	let executes_receiver = receiver_payload.is_some();
+	let response = response
 		.add_attributes(attr_swap(
 			msg_info.sender,
 			receiver.clone(),
 			&msg_info.funds[0],
 			&out_coin,
-			swap_result.spread_amount,
-			swap_result.total_fee_amount,
-			swap_result.maker_fee_amount,
+			&swap_result,
+			pool_id.denom(fee_is_right),
+			referrer.as_ref(),
 		))
 		.add_message(if let Some(receiver_payload) = receiver_payload {
 			CosmosMsg::from(WasmMsg::Execute {
@@ -417,11 +801,506 @@ pub fn process_swap(
 				to_address: receiver.into_string(),
 				amount: vec![out_coin],
 			})
+		});
+	if executes_receiver {
+		lock_until_complete(&env, response)
+	} else {
+		Ok(response)
+	}
+}
+
+pub fn process_flash_swap(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	amounts: [Uint128; 2],
+	receiver: Option<Addr>,
+	payload: Binary,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	if PoolPairConfig::load_non_empty()?
+		.flags
+		.contains(PoolPairConfigFlags::SWAPS_PAUSED)
+	{
+		return Err(PoolPairContractError::SwapsPaused);
+	}
+	if amounts[0].is_zero() && amounts[1].is_zero() {
+		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+	}
+	let reserves = PoolPairReserves::load_non_empty()?;
+	if amounts[0].u128() >= reserves.amounts[0] || amounts[1].u128() >= reserves.amounts[1] {
+		return Err(PoolPairContractError::FlashSwapTooLarge);
+	}
+	PoolPairLock {
+		height: env.block.height,
+	}
+	.save()?;
+	PoolPairFlashSwapState {
+		balances_before: reserves.amounts,
+		amounts: [amounts[0].u128(), amounts[1].u128()],
+	}
+	.save()?;
+
+	let lent_assets = [
+		coin(amounts[0].u128(), pool_id.left.clone()),
+		coin(amounts[1].u128(), pool_id.right.clone()),
+	];
+	Ok(Response::new()
+		.add_attributes(attr_flash_swap(
+			msg_info.sender,
+			receiver.clone(),
+			[&lent_assets[0], &lent_assets[1]],
+		))
+		.add_submessage(SubMsg::reply_on_success(
+			WasmMsg::Execute {
+				contract_addr: receiver.into_string(),
+				msg: payload,
+				funds: lent_assets.into_iter().filter(|coin| !coin.amount.is_zero()).collect(),
+			},
+			FLASH_SWAP_REPLY_ID,
+		)))
+}
+
+fn process_flash_swap_repay(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	let flash_swap = PoolPairFlashSwapState::load()?
+		.ok_or(StdError::not_found("PoolPairFlashSwapState"))?
+		.into_inner();
+	PoolPairFlashSwapState::remove();
+	PoolPairLock::remove();
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let pool_config = PoolPairConfig::load_non_empty()?;
+
+	let balances_before = flash_swap.balances_before.map(Uint128::new);
+	// What's held on behalf of long-term orders isn't part of the pool.
+	let escrow = PoolPairLongTermOrders::load_escrow()?;
+	let balances_after = get_pool_balance(&deps.querier, &env, &pool_id)?;
+	let balances_after = [0, 1].map(|index| balances_after[index].amount.saturating_sub(escrow[index]));
+	// Anything above what was left in the pool after lending is considered to be paid back.
+	let amounts_in = [0, 1].map(|index| {
+		balances_after[index].saturating_sub(balances_before[index] - Uint128::new(flash_swap.amounts[index]))
+	});
+	check_flash_swap_invariant(
+		&balances_before,
+		&balances_after,
+		&amounts_in,
+		pool_config.total_fee_bps,
+	)?;
+
+	let maker_fee_bps = if pool_config.fee_receiver == Zeroable::zeroed() {
+		0
+	} else {
+		pool_config.maker_fee_bps
+	};
+	let repaid_assets = [
+		coin(amounts_in[0].u128(), pool_id.left.clone()),
+		coin(amounts_in[1].u128(), pool_id.right.clone()),
+	];
+	// The fees are taken from what was paid back, rounded the same way as they are for swaps.
+	let mut maker_fee_assets = repaid_assets.clone();
+	let mut lp_fees = [Uint128::zero(); 2];
+	for (index, amount_in) in amounts_in.iter().enumerate() {
+		maker_fee_assets[index].amount = calc_maker_fee_amount(*amount_in, maker_fee_bps)?;
+		lp_fees[index] =
+			calc_fee_amount(*amount_in, pool_config.total_fee_bps)?.saturating_sub(maker_fee_assets[index].amount);
+	}
+	// Whatever the receiver sent back, minus the maker fees, is now part of the pool.
+	PoolPairReserves {
+		amounts: [0, 1].map(|index| (balances_after[index] - maker_fee_assets[index].amount).u128()),
+	}
+	.save()?;
+	record_position_fees(&deps.querier, &env, lp_fees)?;
+	let response = Response::new().add_attributes(attr_flash_swap_repay(
+		[&repaid_assets[0], &repaid_assets[1]],
+		[&maker_fee_assets[0], &maker_fee_assets[1]],
+	));
+	let maker_fee_assets: Vec<Coin> = maker_fee_assets
+		.into_iter()
+		.filter(|coin| !coin.amount.is_zero())
+		.collect();
+	if maker_fee_assets.is_empty() {
+		return Ok(response);
+	}
+	Ok(response.add_message(BankMsg::Send {
+		to_address: Addr::try_from(pool_config.fee_receiver)?.into_string(),
+		amount: maker_fee_assets,
+	}))
+}
+
+pub fn process_skim(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	receiver: Option<Addr>,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let receiver = receiver.unwrap_or(msg_info.sender.clone());
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let reserves = PoolPairReserves::load_non_empty()?.amounts();
+	let escrow = PoolPairLongTermOrders::load_escrow()?;
+
+	let mut skimmed_assets = get_pool_balance(&deps.querier, &env, &pool_id)?;
+	skimmed_assets[0].amount = skimmed_assets[0].amount.saturating_sub(reserves[0] + escrow[0]);
+	skimmed_assets[1].amount = skimmed_assets[1].amount.saturating_sub(reserves[1] + escrow[1]);
+	if skimmed_assets[0].amount.is_zero() && skimmed_assets[1].amount.is_zero() {
+		return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+	}
+	Ok(Response::new()
+		.add_attributes(attr_skim(
+			msg_info.sender,
+			receiver.clone(),
+			[&skimmed_assets[0], &skimmed_assets[1]],
+		))
+		.add_message(BankMsg::Send {
+			to_address: receiver.into_string(),
+			amount: skimmed_assets
+				.into_iter()
+				.filter(|coin| !coin.amount.is_zero())
+				.collect(),
 		}))
 }
 
+pub fn process_sync(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let escrow = PoolPairLongTermOrders::load_escrow()?;
+	let mut pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
+	pool_balances[0].amount = pool_balances[0].amount.saturating_sub(escrow[0]);
+	pool_balances[1].amount = pool_balances[1].amount.saturating_sub(escrow[1]);
+	PoolPairReserves {
+		amounts: [pool_balances[0].amount.u128(), pool_balances[1].amount.u128()],
+	}
+	.save()?;
+	Ok(Response::new().add_attributes(attr_sync(msg_info.sender, [&pool_balances[0], &pool_balances[1]])))
+}
+
+pub fn process_reconcile_supply(
+	deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let pool_lp_denom = lp_denom(&env);
+	let supply =
+		bank_share_supply(&deps.querier, &pool_lp_denom).ok_or(PoolPairContractError::ShareSupplyUnavailable)?;
+	let previous_supply = tracked_share_supply(&pool_lp_denom);
+	set_tracked_share_supply(&pool_lp_denom, supply);
+	Ok(Response::new().add_attributes(attr_reconcile_supply(msg_info.sender, previous_supply, supply)))
+}
+
+pub fn process_place_long_term_order(
+	_deps: DepsMut<SeiQueryWrapper>,
+	env: Env,
+	msg_info: MessageInfo,
+	duration: u64,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let pool_config = PoolPairConfig::load_non_empty()?;
+	if !pool_config.flags.contains(PoolPairConfigFlags::LONG_TERM_ORDERS) {
+		return Err(PoolPairContractError::LongTermOrdersDisabled);
+	}
+	if pool_config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED) {
+		return Err(PoolPairContractError::SwapsPaused);
+	}
+	if duration == 0 || duration > MAX_LONG_TERM_ORDER_DURATION_SECONDS {
+		return Err(PoolPairContractError::InvalidLongTermOrderDuration);
+	}
+	let payment = must_pay_one_of_pair(&msg_info, &pool_id)?;
+	let now = env.block.time.seconds();
+	let end_time = long_term_order_end_time(now, duration);
+	let sell_rate = payment.amount.u128() / (end_time - now) as u128;
+	if sell_rate == 0 {
+		return Err(PoolPairContractError::LongTermOrderTooSmall);
+	}
+	let escrowed_amount = sell_rate * (end_time - now) as u128;
+
+	let owner_orders = get_long_term_order_owners_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&msg_info.sender)?;
+	let mut owner_order_ids = owner_orders
+		.get(&owner_addr)?
+		.map(|order_ids| order_ids.into_inner())
+		.unwrap_or_default();
+	if owner_order_ids.order_ids.len() >= MAX_LONG_TERM_ORDERS_PER_OWNER {
+		return Err(PoolPairContractError::TooManyLongTermOrders);
+	}
+
+	let index = payment.inverse as usize;
+	// Orders are always settled up until now before this is called, so a newly created state is up-to-date as well.
+	let mut orders = PoolPairLongTermOrders::load()?
+		.map(|orders| orders.into_inner())
+		.unwrap_or_else(|| PoolPairLongTermOrders::new(now));
+	let order_id = orders.next_order_id;
+	orders.next_order_id += 1;
+	orders.sell_rates[index] += sell_rate;
+	orders.escrow[index] += escrowed_amount;
+	orders.save()?;
+
+	let expiries = get_long_term_order_expiries_store();
+	let mut expiry = expiries
+		.get(&end_time.into())?
+		.map(|expiry| expiry.into_inner())
+		.unwrap_or_default();
+	expiry.sell_rates[index] += sell_rate;
+	expiries.set(&end_time.into(), &expiry)?;
+
+	get_long_term_orders_store().set(
+		&order_id.into(),
+		&PoolPairLongTermOrder {
+			owner: msg_info.sender.to_string(),
+			inverse: payment.inverse,
+			sell_rate,
+			start_time: now,
+			end_time,
+			proceeds_per_rate_checkpoint: orders.proceeds_per_rate[index],
+			withdrawn_proceeds: 0,
+		},
+	)?;
+	owner_order_ids.order_ids.push(order_id);
+	owner_orders.set(&owner_addr, &owner_order_ids)?;
+
+	let refund = coin(payment.amount.u128() - escrowed_amount, pool_id.denom(payment.inverse));
+	let response = Response::new().add_attributes(attr_place_long_term_order(
+		order_id,
+		msg_info.sender.clone(),
+		&coin(sell_rate, pool_id.denom(payment.inverse)),
+		end_time,
+		&refund,
+	));
+	if refund.amount.is_zero() {
+		return Ok(response);
+	}
+	Ok(response.add_message(BankMsg::Send {
+		to_address: msg_info.sender.into_string(),
+		amount: vec![refund],
+	}))
+}
+
+/// Loads the order, ensuring the sender owns it
+fn load_owned_long_term_order(
+	msg_info: &MessageInfo,
+	order_id: u64,
+) -> Result<PoolPairLongTermOrder, PoolPairContractError> {
+	let order = get_long_term_orders_store()
+		.get(&order_id.into())?
+		.ok_or(PoolPairContractError::LongTermOrderNotFound(order_id))?
+		.into_inner();
+	if msg_info.sender.as_str() != order.owner {
+		return Err(CrownfiSwapsCommonError::Unauthorized("Sender is not the owner of this order".into()).into());
+	}
+	Ok(order)
+}
+
+/// Removes the order from storage, including the owner index
+fn remove_long_term_order(order_id: u64, order: &PoolPairLongTermOrder) -> Result<(), PoolPairContractError> {
+	get_long_term_orders_store().remove(&order_id.into());
+	let owner_orders = get_long_term_order_owners_store();
+	let owner_addr = SeiCanonicalAddr::try_from(&Addr::unchecked(&order.owner))?;
+	let mut owner_order_ids = owner_orders
+		.get(&owner_addr)?
+		.map(|order_ids| order_ids.into_inner())
+		.unwrap_or_default();
+	owner_order_ids.order_ids.retain(|id| *id != order_id);
+	if owner_order_ids.order_ids.is_empty() {
+		owner_orders.remove(&owner_addr);
+	} else {
+		owner_orders.set(&owner_addr, &owner_order_ids)?;
+	}
+	Ok(())
+}
+
+/// The long-term orders as of their last settlement, which is up until now unless settling them failed.
+fn settled_long_term_orders() -> Result<LongTermOrdersSettlement, PoolPairContractError> {
+	Ok(LongTermOrdersSettlement {
+		orders: PoolPairLongTermOrders::load()?
+			.ok_or_else(|| StdError::not_found("PoolPairLongTermOrders"))?
+			.into_inner(),
+		reserves: PoolPairReserves::load_non_empty()?.amounts(),
+		lp_fees: [Uint128::zero(); 2],
+		expired: Vec::new(),
+	})
+}
+
+/// Adds the LP fees to the fee growth used by `PositionReport` if position tracking is enabled. The fees are divided by
+/// the same share supply the report values shares with.
+fn record_position_fees(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	env: &Env,
+	lp_fees: [Uint128; 2],
+) -> Result<(), PoolPairContractError> {
+	if lp_fees == [Uint128::zero(); 2]
+		|| !PoolPairConfig::load_non_empty()?
+			.flags
+			.contains(PoolPairConfigFlags::POSITION_TRACKING)
+	{
+		return Ok(());
+	}
+	record_lp_fees(lp_fees, share_supply(querier, &lp_denom(env)))
+}
+
+pub fn process_cancel_long_term_order(
+	_deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+	order_id: u64,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let order = load_owned_long_term_order(&msg_info, order_id)?;
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let mut settlement = settled_long_term_orders()?;
+	let proceeds = settlement.claimable_proceeds(&order)?;
+
+	// Whatever hasn't been settled yet is refunded, even if that's behind the current time.
+	let settled_until = settlement.orders.last_settlement;
+	let index = order.inverse as usize;
+	let unsold = order.sell_rate * order.end_time.saturating_sub(settled_until) as u128;
+	if order.end_time > settled_until {
+		// The order hasn't reached its expiry yet, so it's still being sold.
+		settlement.orders.sell_rates[index] -= order.sell_rate;
+		let expiries = get_long_term_order_expiries_store();
+		if let Some(expiry) = expiries.get(&order.end_time.into())? {
+			let mut expiry = expiry.into_inner();
+			expiry.sell_rates[index] -= order.sell_rate;
+			if expiry.sell_rates == [0, 0] {
+				expiries.remove(&order.end_time.into());
+			} else {
+				expiries.set(&order.end_time.into(), &expiry)?;
+			}
+		}
+	}
+	settlement.orders.escrow[index] -= unsold;
+	settlement.orders.escrow[index ^ 1] -= proceeds.u128();
+	settlement.orders.save()?;
+	remove_long_term_order(order_id, &order)?;
+
+	let unsold = coin(unsold, pool_id.denom(order.inverse));
+	let proceeds = coin(proceeds.u128(), pool_id.denom(!order.inverse));
+	let response = Response::new().add_attributes(attr_cancel_long_term_order(
+		order_id,
+		msg_info.sender.clone(),
+		&unsold,
+		&proceeds,
+	));
+	let refund_assets: Vec<Coin> = [unsold, proceeds]
+		.into_iter()
+		.filter(|coin| !coin.amount.is_zero())
+		.collect();
+	if refund_assets.is_empty() {
+		return Ok(response);
+	}
+	Ok(response.add_message(BankMsg::Send {
+		to_address: msg_info.sender.into_string(),
+		amount: refund_assets,
+	}))
+}
+
+pub fn process_withdraw_long_term_order(
+	_deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+	order_id: u64,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	let mut order = load_owned_long_term_order(&msg_info, order_id)?;
+	let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+	let mut settlement = settled_long_term_orders()?;
+	let proceeds = settlement.claimable_proceeds(&order)?;
+
+	let completed = order.end_time <= settlement.orders.last_settlement;
+	if completed {
+		remove_long_term_order(order_id, &order)?;
+	} else {
+		if proceeds.is_zero() {
+			return Err(CrownfiSwapsCommonError::PayoutIsZero.into());
+		}
+		order.proceeds_per_rate_checkpoint = settlement.orders.proceeds_per_rate[order.inverse as usize];
+		order.withdrawn_proceeds += proceeds.u128();
+		get_long_term_orders_store().set(&order_id.into(), &order)?;
+	}
+	settlement.orders.escrow[(!order.inverse) as usize] -= proceeds.u128();
+	settlement.orders.save()?;
+
+	let proceeds = coin(proceeds.u128(), pool_id.denom(!order.inverse));
+	let response = Response::new().add_attributes(attr_withdraw_long_term_order(
+		order_id,
+		msg_info.sender.clone(),
+		&proceeds,
+		completed,
+	));
+	if proceeds.amount.is_zero() {
+		return Ok(response);
+	}
+	Ok(response.add_message(BankMsg::Send {
+		to_address: msg_info.sender.into_string(),
+		amount: vec![proceeds],
+	}))
+}
+
+pub fn process_execute_virtual_orders(
+	_deps: DepsMut<SeiQueryWrapper>,
+	_env: Env,
+	msg_info: MessageInfo,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	nonpayable(&msg_info)?;
+	// The orders have already been settled by the time this is called.
+	let orders = PoolPairLongTermOrders::load()?.ok_or_else(|| StdError::not_found("PoolPairLongTermOrders"))?;
+	Ok(Response::new().add_attributes(attr_execute_virtual_orders(msg_info.sender, orders.last_settlement)))
+}
+
+/// Describes the order's progress, `settlement` must be up until now.
+fn long_term_order_info(
+	pool_id: &CanonicalPoolPairIdentifier,
+	settlement: &LongTermOrdersSettlement,
+	order_id: u64,
+	order: PoolPairLongTermOrder,
+) -> Result<PoolPairQueryLongTermOrderResponse, PoolPairContractError> {
+	let now = settlement.orders.last_settlement;
+	let claimable_proceeds = settlement.claimable_proceeds(&order)?;
+	Ok(PoolPairQueryLongTermOrderResponse {
+		order_id,
+		owner: Addr::unchecked(order.owner),
+		sell_denom: pool_id.denom(order.inverse).to_owned(),
+		buy_denom: pool_id.denom(!order.inverse).to_owned(),
+		sell_rate: order.sell_rate.into(),
+		start_time: order.start_time,
+		end_time: order.end_time,
+		sold: (order.sell_rate * (now.min(order.end_time) - order.start_time) as u128).into(),
+		remaining: (order.sell_rate * order.end_time.saturating_sub(now) as u128).into(),
+		claimable_proceeds,
+		withdrawn_proceeds: order.withdrawn_proceeds.into(),
+	})
+}
+
 #[cfg_attr(not(feature = "library"), cosmwasm_std::entry_point)]
 pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: PoolPairQueryMsg) -> Result<Binary, PoolPairContractError> {
+	if matches!(
+		msg,
+		PoolPairQueryMsg::TotalShares
+			| PoolPairQueryMsg::ShareSupply
+			| PoolPairQueryMsg::ShareValue { .. }
+			| PoolPairQueryMsg::Reserves
+			| PoolPairQueryMsg::SimulateProvideLiquidity { .. }
+			| PoolPairQueryMsg::SimulateSwap { .. }
+			| PoolPairQueryMsg::SimulateNaiveSwap { .. }
+			| PoolPairQueryMsg::ExchangeRateHourly { .. }
+			| PoolPairQueryMsg::ExchangeRateDaily { .. }
+			| PoolPairQueryMsg::ExchangeRateAllTime
+			| PoolPairQueryMsg::EstimateApy { .. }
+			| PoolPairQueryMsg::LongTermOrders
+			| PoolPairQueryMsg::LongTermOrder { .. }
+			| PoolPairQueryMsg::LongTermOrdersByOwner { .. }
+			| PoolPairQueryMsg::PositionReport { .. }
+	) && PoolPairLock::is_locked()?
+	{
+		return Err(PoolPairContractError::PoolLocked);
+	}
 	Ok(match msg {
 		PoolPairQueryMsg::PairDenoms {} => {
 			let config = PoolPairConfig::load_non_empty()?;
@@ -448,66 +1327,100 @@ pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: PoolPairQueryMsg) -> Re
 		PoolPairQueryMsg::Config {} => to_json_binary(&PoolPairConfigJsonable::try_from(
 			PoolPairConfig::load_non_empty()?.as_ref(),
 		)?)?,
-		PoolPairQueryMsg::TotalShares => to_json_binary(&total_supply_workaround(&lp_denom(&env)))?,
+		PoolPairQueryMsg::PendingAdmin => to_json_binary(
+			&PendingAdminTransfer::load()?
+				.map(|pending| PendingAdminTransferJsonable::try_from(pending.as_ref()))
+				.transpose()?,
+		)?,
+		PoolPairQueryMsg::LpStaking => to_json_binary(&PoolPairStakingConfig::lp_staking_addr()?)?,
+		PoolPairQueryMsg::TotalShares => to_json_binary(&share_supply(&deps.querier, &lp_denom(&env)))?,
+		PoolPairQueryMsg::ShareSupply => {
+			let pool_lp_denom = lp_denom(&env);
+			to_json_binary(&PoolPairQueryShareSupplyResponse {
+				tracked: tracked_share_supply(&pool_lp_denom),
+				bank: bank_share_supply(&deps.querier, &pool_lp_denom),
+			})?
+		}
 		PoolPairQueryMsg::ShareValue { amount } => {
 			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
-			let share_supply = total_supply_workaround(&lp_denom(&env));
-			let pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
-			to_json_binary(&balances_into_share_value(amount, share_supply, pool_balances))?
+			let total_shares = share_supply(&deps.querier, &lp_denom(&env));
+			let reserves = settled_reserves(env.block.time.seconds())?.as_coins(&pool_id);
+			to_json_binary(&balances_into_share_value(amount, total_shares, reserves))?
+		}
+		PoolPairQueryMsg::Reserves => {
+			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+			to_json_binary(&PoolPairQueryReservesResponse {
+				tracked: settled_reserves(env.block.time.seconds())?.as_coins(&pool_id),
+				actual: get_pool_balance(&deps.querier, &env, &pool_id)?,
+			})?
 		}
 		PoolPairQueryMsg::SimulateProvideLiquidity { offer } => {
 			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 			if offer[0].denom != pool_id.left || offer[1].denom != pool_id.right {
 				return Err(PoolPairContractError::DepositQueryDenomMismatch);
 			}
-			let mut share_supply = total_supply_workaround(&lp_denom(&env));
-			let mut pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
+			let mut total_shares = share_supply(&deps.querier, &lp_denom(&env));
+			let mut pool_balances = settled_reserves(env.block.time.seconds())?.as_coins(&pool_id);
 			let new_shares = calc_shares_to_mint(
-				share_supply,
+				total_shares,
 				&[pool_balances[0].amount, pool_balances[1].amount],
 				&[offer[0].amount, offer[1].amount],
 				Decimal::one(),
 			)?;
 			// Uint128 type implicitly panics on overflow
-			share_supply += new_shares;
+			total_shares += new_shares;
 			pool_balances[0].amount += offer[0].amount;
 			pool_balances[1].amount += offer[1].amount;
 			to_json_binary(&PoolPairQuerySimulateDepositResponse {
 				share_amount: new_shares,
-				share_value: balances_into_share_value(new_shares, share_supply, pool_balances),
+				share_value: balances_into_share_value(new_shares, total_shares, pool_balances),
 			})?
 		}
-		PoolPairQueryMsg::SimulateSwap { offer } => {
+		PoolPairQueryMsg::SimulateSwap {
+			offer,
+			referral_fee_bps,
+		} => {
 			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 			if !pool_id.is_in_pair(&offer.denom) {
 				return Err(PaymentError::ExtraDenom(offer.denom).into());
 			}
 			let config = PoolPairConfig::load_non_empty()?;
 			let config = config.as_ref();
-			let pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
+			let referral_fee_bps = referral_fee_bps.unwrap_or_default();
+			check_referral_fee_bps(referral_fee_bps, config.max_referral_fee_bps)?;
+			let reserves = settled_reserves(env.block.time.seconds())?.amounts();
 			to_json_binary(&calc_swap(
-				&pool_balances.map(|coin| coin.amount),
+				&reserves,
 				offer.amount,
 				config.total_fee_bps,
 				config.maker_fee_bps,
+				referral_fee_bps,
+				config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT),
 				offer.denom == pool_id.right,
 				None,
 				Decimal::MAX,
 			)?)?
 		}
-		PoolPairQueryMsg::SimulateNaiveSwap { offer } => {
+		PoolPairQueryMsg::SimulateNaiveSwap {
+			offer,
+			referral_fee_bps,
+		} => {
 			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
 			if !pool_id.is_in_pair(&offer.denom) {
 				return Err(PaymentError::ExtraDenom(offer.denom).into());
 			}
 			let config = PoolPairConfig::load_non_empty()?;
 			let config = config.as_ref();
-			let pool_balances = get_pool_balance(&deps.querier, &env, &pool_id)?;
+			let referral_fee_bps = referral_fee_bps.unwrap_or_default();
+			check_referral_fee_bps(referral_fee_bps, config.max_referral_fee_bps)?;
+			let reserves = settled_reserves(env.block.time.seconds())?.amounts();
 			to_json_binary(&calc_naive_swap(
-				&pool_balances.map(|coin| coin.amount),
+				&reserves,
 				offer.amount,
 				config.total_fee_bps,
 				config.maker_fee_bps,
+				referral_fee_bps,
+				config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT),
 				offer.denom == pool_id.right,
 			)?)?
 		}
@@ -537,14 +1450,8 @@ pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: PoolPairQueryMsg) -> Re
 		}
 		PoolPairQueryMsg::ExchangeRateHourly { past_hours } => {
 			let volume_stats = VolumeStatisticsCounter::new()?;
-			let fallback = || {
-				get_pool_balance(
-					&deps.querier,
-					&env,
-					CanonicalPoolPairIdentifier::load_non_empty()?.as_ref(),
-				)
-				.map(|coins| coins.map(|coin| coin.amount.u128()))
-			};
+			let reserves = settled_reserves(env.block.time.seconds())?.amounts;
+			let fallback = || Ok(reserves);
 			to_json_binary(
 				&if let Some(past_hours) = NonZeroU8::new(past_hours.unwrap_or_default()) {
 					volume_stats.get_exchange_rate_per_hours(env.block.time, past_hours, fallback)?
@@ -555,14 +1462,8 @@ pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: PoolPairQueryMsg) -> Re
 		}
 		PoolPairQueryMsg::ExchangeRateDaily { past_days } => {
 			let volume_stats = VolumeStatisticsCounter::new()?;
-			let fallback = || {
-				get_pool_balance(
-					&deps.querier,
-					&env,
-					CanonicalPoolPairIdentifier::load_non_empty()?.as_ref(),
-				)
-				.map(|coins| coins.map(|coin| coin.amount.u128()))
-			};
+			let reserves = settled_reserves(env.block.time.seconds())?.amounts;
+			let fallback = || Ok(reserves);
 			to_json_binary(
 				&if let Some(past_days) = NonZeroU8::new(past_days.unwrap_or_default()) {
 					volume_stats.get_exchange_rate_per_days(env.block.time, past_days, fallback)?
@@ -573,30 +1474,130 @@ pub fn query(deps: Deps<SeiQueryWrapper>, env: Env, msg: PoolPairQueryMsg) -> Re
 		}
 		PoolPairQueryMsg::ExchangeRateAllTime => {
 			let volume_stats = VolumeStatisticsCounter::new()?;
-			let fallback = || {
-				get_pool_balance(
-					&deps.querier,
-					&env,
-					CanonicalPoolPairIdentifier::load_non_empty()?.as_ref(),
-				)
-				.map(|coins| coins.map(|coin| coin.amount.u128()))
-			};
+			let reserves = settled_reserves(env.block.time.seconds())?.amounts;
+			let fallback = || Ok(reserves);
 			to_json_binary(&volume_stats.get_exchange_rate_all_time(env.block.time, fallback)?)?
 		}
 		PoolPairQueryMsg::EstimateApy { past_days } => {
 			let config = PoolPairConfig::load_non_empty()?;
 			let config = config.as_ref();
 			let volume_stats = VolumeStatisticsCounter::new()?;
-			let left_token_balance = deps.querier.query_balance(
-				&env.contract.address,
-				&CanonicalPoolPairIdentifier::load_non_empty()?.left,
-			)?;
-			to_json_binary(&volume_stats.estimate_apy(
-				env.block.time,
-				left_token_balance.amount.u128(),
-				config,
-				past_days,
-			)?)?
+			let reserves = settled_reserves(env.block.time.seconds())?;
+			to_json_binary(&volume_stats.estimate_apy(env.block.time, reserves.amounts[0], config, past_days)?)?
+		}
+		PoolPairQueryMsg::LongTermOrders => {
+			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+			let orders = simulate_settlement(env.block.time.seconds())?
+				.map(|settlement| settlement.orders)
+				.unwrap_or_else(|| PoolPairLongTermOrders::new(env.block.time.seconds()));
+			to_json_binary(&PoolPairQueryLongTermOrdersResponse {
+				sell_rates: [
+					coin(orders.sell_rates[0], pool_id.left.clone()),
+					coin(orders.sell_rates[1], pool_id.right.clone()),
+				],
+				escrow: [
+					coin(orders.escrow[0], pool_id.left.clone()),
+					coin(orders.escrow[1], pool_id.right.clone()),
+				],
+				last_settlement: orders.last_settlement,
+			})?
+		}
+		PoolPairQueryMsg::LongTermOrder { order_id } => {
+			let Some(order) = get_long_term_orders_store().get(&order_id.into())? else {
+				return Ok(to_json_binary(&None::<PoolPairQueryLongTermOrderResponse>)?);
+			};
+			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+			// An order existing means orders have been placed before.
+			let settlement = simulate_settlement(env.block.time.seconds())?
+				.ok_or_else(|| StdError::not_found("PoolPairLongTermOrders"))?;
+			to_json_binary(&Some(long_term_order_info(
+				&pool_id,
+				&settlement,
+				order_id,
+				order.into_inner(),
+			)?))?
+		}
+		PoolPairQueryMsg::LongTermOrdersByOwner { owner } => {
+			let owner_order_ids = get_long_term_order_owners_store()
+				.get(&(&owner).try_into()?)?
+				.map(|order_ids| order_ids.into_inner())
+				.unwrap_or_default();
+			if owner_order_ids.order_ids.is_empty() {
+				return Ok(to_json_binary(&Vec::<PoolPairQueryLongTermOrderResponse>::new())?);
+			}
+			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+			let settlement = simulate_settlement(env.block.time.seconds())?
+				.ok_or_else(|| StdError::not_found("PoolPairLongTermOrders"))?;
+			let orders = get_long_term_orders_store();
+			to_json_binary(
+				&owner_order_ids
+					.order_ids
+					.into_iter()
+					.map(|order_id| {
+						let order = orders
+							.get(&order_id.into())?
+							.ok_or(PoolPairContractError::LongTermOrderNotFound(order_id))?
+							.into_inner();
+						long_term_order_info(&pool_id, &settlement, order_id, order)
+					})
+					.collect::<Result<Vec<_>, PoolPairContractError>>()?,
+			)?
+		}
+		PoolPairQueryMsg::PositionReport { address } => {
+			let Some(position) = get_positions_store().get(&(&address).try_into()?)? else {
+				return Ok(to_json_binary(&None::<PoolPairQueryPositionReportResponse>)?);
+			};
+			let mut position = position.into_inner();
+			let pool_id = CanonicalPoolPairIdentifier::load_non_empty()?;
+			let total_shares = share_supply(&deps.querier, &lp_denom(&env));
+			let mut fee_growth = PoolPairFeeGrowth::load_or_zeroed()?;
+			let reserves = match simulate_settlement(env.block.time.seconds())? {
+				Some(settlement) => {
+					// Long-term order fees are only recorded once they're settled, so the ones up until now are added
+					// here the same way.
+					if PoolPairConfig::load_non_empty()?
+						.flags
+						.contains(PoolPairConfigFlags::POSITION_TRACKING)
+					{
+						add_lp_fees(&mut fee_growth, settlement.lp_fees, total_shares);
+					}
+					PoolPairReserves {
+						amounts: settlement.reserves.map(|amount| amount.u128()),
+					}
+				}
+				None => PoolPairReserves::load_non_empty()?.into_inner(),
+			};
+			accrue_position_fees(&mut position, &fee_growth)?;
+			let share_value =
+				balances_into_share_value(position.shares.into(), total_shares, reserves.as_coins(&pool_id));
+			let reserves = reserves.amounts();
+			let share_value_total = value_in_each_denom([share_value[0].amount, share_value[1].amount], &reserves)?;
+			let hodl_value_total = value_in_each_denom(position.cost_basis.map(Uint128::new), &reserves)?;
+			let fees_total = value_in_each_denom(position.fees_earned.map(Uint128::new), &reserves)?;
+			to_json_binary(&Some(PoolPairQueryPositionReportResponse {
+				shares: position.shares.into(),
+				share_value,
+				cost_basis: [
+					coin(position.cost_basis[0], pool_id.left.clone()),
+					coin(position.cost_basis[1], pool_id.right.clone()),
+				],
+				share_value_total: [
+					coin(share_value_total[0].u128(), pool_id.left.clone()),
+					coin(share_value_total[1].u128(), pool_id.right.clone()),
+				],
+				hodl_value_total: [
+					coin(hodl_value_total[0].u128(), pool_id.left.clone()),
+					coin(hodl_value_total[1].u128(), pool_id.right.clone()),
+				],
+				fees_earned: [
+					coin(position.fees_earned[0], pool_id.left.clone()),
+					coin(position.fees_earned[1], pool_id.right.clone()),
+				],
+				impermanent_loss: impermanent_loss(
+					share_value_total[0].saturating_sub(fees_total[0]),
+					hodl_value_total[0],
+				)?,
+			}))?
 		}
 	})
 }
@@ -5,7 +5,10 @@ use sei_cosmwasm::SeiQueryWrapper;
 
 use crate::error::PoolPairContractError;
 
-/// Minimum initial LP share
+/// Minimum initial LP share. This amount is locked in the pool when it's created, which prevents anyone from inflating
+/// the value of a share by withdrawing nearly everything and donating to the pool.
+///
+/// Pools created before this was introduced don't have any shares locked, and migrating them doesn't change that.
 pub const MINIMUM_INITIAL_SHARES: Uint128 = Uint128::new(1000);
 /// The default swap slippage
 pub const DEFAULT_SLIPPAGE: Decimal = Decimal::bps(50);
@@ -46,7 +49,7 @@ pub fn calc_shares_to_mint(
 	if current_share_supply.is_zero() {
 		let initial_share =
 			Uint128::try_from(incoming_funds[0].full_mul(incoming_funds[1]).isqrt()).unwrap_or(Uint128::MAX); // Uint256::MAX.isqrt() == Uint128::MAX.into(), so the unwrap always succeeds anyway.
-		if initial_share < MINIMUM_INITIAL_SHARES {
+		if initial_share <= MINIMUM_INITIAL_SHARES {
 			return Err(PoolPairContractError::MinimumSharesAmountError);
 		}
 		Ok(initial_share)
@@ -68,71 +71,144 @@ pub fn calc_shares_to_mint(
 	}
 }
 
+/// The fee (in bps) of `amount`. This is rounded up, so even the smallest swaps pay something.
+#[inline]
+pub fn calc_fee_amount(amount: Uint128, fee_bps: u16) -> Result<Uint128, PoolPairContractError> {
+	Ok(amount.checked_mul_ceil(Decimal::bps(fee_bps.into()))?)
+}
+
+/// The part of `calc_fee_amount` which goes to the maker. This is rounded down, so any rounding goes to the LPs.
+#[inline]
+pub fn calc_maker_fee_amount(amount: Uint128, maker_fee_bps: u16) -> Result<Uint128, PoolPairContractError> {
+	Ok(amount.checked_mul_floor(Decimal::bps(maker_fee_bps.into()))?)
+}
+
+/// The part of a swap's result which is paid to the referrer. This is rounded down, as it's paid by the swapper.
+#[inline]
+pub fn calc_referral_fee_amount(
+	result_amount: Uint128,
+	referral_fee_bps: u16,
+) -> Result<Uint128, PoolPairContractError> {
+	Ok(result_amount.checked_mul_floor(Decimal::bps(referral_fee_bps.into()))?)
+}
+
+/// Ensures the referral fee doesn't exceed the pool's configured maximum.
+#[inline]
+pub fn check_referral_fee_bps(referral_fee_bps: u16, max_referral_fee_bps: u16) -> Result<(), PoolPairContractError> {
+	if referral_fee_bps > max_referral_fee_bps {
+		return Err(PoolPairContractError::ReferralFeeTooHigh(max_referral_fee_bps));
+	}
+	Ok(())
+}
+
+/// The amount received for swapping `incoming_amount` with the pool before any fees are taken. What's left in the pool
+/// is rounded up, so the constant product never decreases.
+pub fn calc_swap_output(
+	balance_in: Uint128,
+	balance_out: Uint128,
+	incoming_amount: Uint128,
+) -> Result<Uint128, PoolPairContractError> {
+	// result = pool[1] - ceil((pool[0] * pool[1]) / (pool[0] + incoming))
+	let product = balance_in.full_mul(balance_out);
+	let new_balance_in = Uint256::from(balance_in) + Uint256::from(incoming_amount);
+	let mut new_balance_out = product.checked_div(new_balance_in)?;
+	if !product.checked_rem(new_balance_in)?.is_zero() {
+		new_balance_out += Uint256::one();
+	}
+	Ok(balance_out.saturating_sub(new_balance_out.try_into()?))
+}
+
 #[cw_serde]
 pub struct PoolPairCalcSwapResult {
-	/// The amount of coin after the swap, minus the `total_fee_amount`.
+	/// The amount of coin after the swap, minus the `referral_fee_amount` and the `total_fee_amount` if the fee is
+	/// taken from the result.
 	pub result_amount: Uint128,
-	/// The discrepancy between `result_amount + total_fee_amount` and `incoming_amount * exchange_rate`
+	/// The discrepancy between what was received from the pool and `swapped_amount * exchange_rate`, where
+	/// `swapped_amount` is the offered amount minus the `total_fee_amount` if the fee is taken from the input.
 	pub spread_amount: Uint128,
 	/// `maker_fee_amount + liquidity_provider_fee_amount`
 	pub total_fee_amount: Uint128,
 	/// How much CrownFi is skimming off the top
 	pub maker_fee_amount: Uint128,
+	/// If true, the fees are in the offered denom rather than the resulting denom.
+	pub fee_on_input: bool,
+	/// How much of the result is paid to the referrer, this is always in the resulting denom.
+	pub referral_fee_amount: Uint128,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn calc_swap(
 	pool_balances: &[Uint128; 2],
 	incoming_amount: Uint128,
 	total_fee_bps: u16,
 	maker_fee_bps: u16,
+	referral_fee_bps: u16,
+	fee_on_input: bool,
 	inverse_swap: bool,
 	expected_result: Option<Uint128>,
 	slippage_tolerance: Decimal,
 ) -> Result<PoolPairCalcSwapResult, PoolPairContractError> {
+	let balance_in = pool_balances[inverse_swap as usize];
+	let balance_out = pool_balances[(!inverse_swap) as usize];
+	// Only what's left after the fee is swapped if the fee is taken from the input.
+	let swapped_amount = if fee_on_input {
+		incoming_amount.saturating_sub(calc_fee_amount(incoming_amount, total_fee_bps)?)
+	} else {
+		incoming_amount
+	};
 	// Always needed for spread amount in result
-	let naive_result = incoming_amount
-		.full_mul(pool_balances[(!inverse_swap) as usize])
-		.checked_div(pool_balances[inverse_swap as usize].into())
+	let naive_result = swapped_amount
+		.full_mul(balance_out)
+		.checked_div(balance_in.into())
 		.map_err(|_| PoolPairContractError::NoLiquidity)? // Payout is 0 if we have nothing to pay out
 		.try_into()
 		.unwrap_or(Uint128::MAX); // guaranteed to fail slip tolerance
 	let expected_result = expected_result.unwrap_or(naive_result);
 
-	// result = pool[1] - ((pool[0] * pool[1]) / (pool[0] + incoming))
-	let actual_result = pool_balances[(!inverse_swap) as usize].saturating_sub(
-		pool_balances[inverse_swap as usize]
-			.full_mul(pool_balances[(!inverse_swap) as usize])
-			.checked_div(Uint256::from(pool_balances[inverse_swap as usize]) + Uint256::from(incoming_amount))?
-			.try_into()?,
-	);
+	let actual_result = calc_swap_output(balance_in, balance_out, swapped_amount)?;
 
-	// abs_diff is used because the "expected_result" can be whatever the user wants.
-	let slippage = Decimal::from_ratio(actual_result, expected_result).abs_diff(Decimal::one());
+	// abs_diff is used because the "expected_result" can be whatever the user wants. The expected result may also be
+	// 0 for the tiniest of swaps, in which case the swap pays out nothing anyway.
+	let slippage = Decimal::checked_from_ratio(actual_result, expected_result)
+		.map_or(Decimal::MAX, |ratio| ratio.abs_diff(Decimal::one()));
 	if slippage > slippage_tolerance {
 		return Err(PoolPairContractError::SlippageTooHigh(slippage));
 	}
 
 	// Results!
-	let maker_fee_amount = actual_result.checked_mul(maker_fee_bps.into())? / Uint128::new(10000);
-	let total_fee_amount = actual_result.checked_mul(total_fee_bps.into())? / Uint128::new(10000);
+	let fee_base = if fee_on_input { incoming_amount } else { actual_result };
+	let maker_fee_amount = calc_maker_fee_amount(fee_base, maker_fee_bps)?;
+	let total_fee_amount = calc_fee_amount(fee_base, total_fee_bps)?;
 	let spread_amount = naive_result.saturating_sub(actual_result);
-	let result_amount = actual_result.saturating_sub(total_fee_amount);
+	let result_amount = if fee_on_input {
+		actual_result
+	} else {
+		actual_result.saturating_sub(total_fee_amount)
+	};
+	let referral_fee_amount = calc_referral_fee_amount(result_amount, referral_fee_bps)?;
 	Ok(PoolPairCalcSwapResult {
-		result_amount,
+		result_amount: result_amount - referral_fee_amount,
 		spread_amount,
 		total_fee_amount,
 		maker_fee_amount,
+		fee_on_input,
+		referral_fee_amount,
 	})
 }
 
 #[cw_serde]
 pub struct PoolPairCalcNaiveSwapResult {
-	/// The amount of coin after the swap, minus the `total_fee_amount`.
+	/// The amount of coin after the swap, minus the `referral_fee_amount` and the `total_fee_amount` if the fee is
+	/// taken from the result.
 	pub result_amount: Uint128,
 	/// `maker_fee_amount + liquidity_provider_fee_amount`
 	pub total_fee_amount: Uint128,
 	/// How much CrownFi is skimming off the top
 	pub maker_fee_amount: Uint128,
+	/// If true, the fees are in the offered denom rather than the resulting denom.
+	pub fee_on_input: bool,
+	/// How much of the result is paid to the referrer, this is always in the resulting denom.
+	pub referral_fee_amount: Uint128,
 }
 
 pub fn calc_naive_swap(
@@ -140,27 +216,68 @@ pub fn calc_naive_swap(
 	incoming_amount: Uint128,
 	total_fee_bps: u16,
 	maker_fee_bps: u16,
+	referral_fee_bps: u16,
+	fee_on_input: bool,
 	inverse_swap: bool,
 ) -> Result<PoolPairCalcNaiveSwapResult, PoolPairContractError> {
-	// Always needed for spread amount in result
-	let naive_result = incoming_amount
-		.full_mul(pool_balances[(!inverse_swap) as usize])
-		.checked_div(pool_balances[inverse_swap as usize].into())
-		.map_err(|_| PoolPairContractError::NoLiquidity)? // Payout is 0 if we have nothing to pay out
-		.try_into()
-		.unwrap_or(Uint128::MAX); // guaranteed to fail slip tolerance
+	let naive_swap = |amount: Uint128| -> Result<Uint128, PoolPairContractError> {
+		Ok(amount
+			.full_mul(pool_balances[(!inverse_swap) as usize])
+			.checked_div(pool_balances[inverse_swap as usize].into())
+			.map_err(|_| PoolPairContractError::NoLiquidity)? // Payout is 0 if we have nothing to pay out
+			.try_into()
+			.unwrap_or(Uint128::MAX)) // guaranteed to fail slip tolerance
+	};
 
 	// Results!
-	let maker_fee_amount = naive_result.checked_mul(maker_fee_bps.into())? / Uint128::new(10000);
-	let total_fee_amount = naive_result.checked_mul(total_fee_bps.into())? / Uint128::new(10000);
-	let result_amount = naive_result.saturating_sub(total_fee_amount);
+	let (result_amount, total_fee_amount, maker_fee_amount) = if fee_on_input {
+		let total_fee_amount = calc_fee_amount(incoming_amount, total_fee_bps)?;
+		(
+			naive_swap(incoming_amount.saturating_sub(total_fee_amount))?,
+			total_fee_amount,
+			calc_maker_fee_amount(incoming_amount, maker_fee_bps)?,
+		)
+	} else {
+		let naive_result = naive_swap(incoming_amount)?;
+		let total_fee_amount = calc_fee_amount(naive_result, total_fee_bps)?;
+		(
+			naive_result.saturating_sub(total_fee_amount),
+			total_fee_amount,
+			calc_maker_fee_amount(naive_result, maker_fee_bps)?,
+		)
+	};
+	let referral_fee_amount = calc_referral_fee_amount(result_amount, referral_fee_bps)?;
 	Ok(PoolPairCalcNaiveSwapResult {
-		result_amount,
+		result_amount: result_amount - referral_fee_amount,
 		total_fee_amount,
 		maker_fee_amount,
+		fee_on_input,
+		referral_fee_amount,
 	})
 }
 
+/// Ensures that the pool's constant product didn't decrease after a flash swap, with the swap fee taken from the
+/// amounts paid back.
+pub fn check_flash_swap_invariant(
+	balances_before: &[Uint128; 2],
+	balances_after: &[Uint128; 2],
+	amounts_in: &[Uint128; 2],
+	total_fee_bps: u16,
+) -> Result<(), PoolPairContractError> {
+	// amounts_in is never greater than balances_after, and `PoolPairConfig::valid_fee_bps` is checked whenever the
+	// config is set, so total_fee_bps is never greater than 10000.
+	let adjusted_balance =
+		|index: usize| balances_after[index].full_mul(10000u128) - amounts_in[index].full_mul(total_fee_bps as u128);
+	if adjusted_balance(0).full_mul(adjusted_balance(1))
+		< balances_before[0]
+			.full_mul(balances_before[1])
+			.full_mul(100_000_000u128)
+	{
+		return Err(PoolPairContractError::FlashSwapNotRepaid);
+	}
+	Ok(())
+}
+
 pub(crate) fn check_deposit_slippage_tolerance(
 	old_balances: &[Uint128; 2],
 	incoming_funds: &[Uint128; 2],
@@ -0,0 +1,118 @@
+use bytemuck::Zeroable;
+use cosmwasm_std::{Addr, Decimal, Decimal256, Uint128, Uint256};
+use crownfi_cw_common::{data_types::canonical_addr::SeiCanonicalAddr, storage::item::StoredItem};
+
+use crate::{
+	error::PoolPairContractError,
+	state::{get_positions_store, PoolPairFeeGrowth, PoolPairPosition},
+};
+
+/// Adds LP fees, in the same order as the reserves, to the fee growth. Nothing is added if there are no shares.
+pub fn add_lp_fees(fee_growth: &mut PoolPairFeeGrowth, lp_fees: [Uint128; 2], share_supply: Uint128) {
+	if share_supply.is_zero() {
+		return;
+	}
+	for index in [0, 1] {
+		if !lp_fees[index].is_zero() {
+			fee_growth.set_per_share(
+				index,
+				fee_growth.per_share(index) + Decimal256::from_ratio(lp_fees[index], share_supply),
+			);
+		}
+	}
+}
+
+/// Adds the LP fees taken from swaps, flash swaps or long-term orders to the stored fee growth.
+pub fn record_lp_fees(lp_fees: [Uint128; 2], share_supply: Uint128) -> Result<(), PoolPairContractError> {
+	if lp_fees == [Uint128::zero(); 2] || share_supply.is_zero() {
+		return Ok(());
+	}
+	let mut fee_growth = PoolPairFeeGrowth::load_or_zeroed()?;
+	add_lp_fees(&mut fee_growth, lp_fees, share_supply);
+	fee_growth.save()?;
+	Ok(())
+}
+
+/// Adds the fees earned since the position's checkpoint to `fees_earned`, and moves the checkpoint to now.
+pub fn accrue_position_fees(
+	position: &mut PoolPairPosition,
+	fee_growth: &PoolPairFeeGrowth,
+) -> Result<(), PoolPairContractError> {
+	for index in [0, 1] {
+		let fees: Uint128 = Uint256::from(position.shares)
+			.checked_mul_floor(fee_growth.per_share(index) - position.fee_growth_checkpoint(index))?
+			.try_into()?;
+		position.fees_earned[index] += fees.u128();
+		position.fee_growth_checkpoint[index] = fee_growth.per_share[index];
+	}
+	Ok(())
+}
+
+/// Adds the deposit to the address's position.
+pub fn record_deposit(address: &Addr, shares: Uint128, deposit: [Uint128; 2]) -> Result<(), PoolPairContractError> {
+	let positions = get_positions_store();
+	let address = SeiCanonicalAddr::try_from(address)?;
+	let fee_growth = PoolPairFeeGrowth::load_or_zeroed()?;
+	let mut position = match positions.get(&address)? {
+		Some(position) => position.into_inner(),
+		None => PoolPairPosition {
+			fee_growth_checkpoint: fee_growth.per_share,
+			..Zeroable::zeroed()
+		},
+	};
+	accrue_position_fees(&mut position, &fee_growth)?;
+	position.shares += shares.u128();
+	position.cost_basis[0] += deposit[0].u128();
+	position.cost_basis[1] += deposit[1].u128();
+	positions.set(&address, &position)?;
+	Ok(())
+}
+
+/// Reduces the address's position proportionally to the shares withdrawn, if it has one. This happens regardless of
+/// whether position tracking is still enabled.
+pub fn record_withdrawal(address: &Addr, shares: Uint128) -> Result<(), PoolPairContractError> {
+	let positions = get_positions_store();
+	let address = SeiCanonicalAddr::try_from(address)?;
+	let Some(position) = positions.get(&address)? else {
+		return Ok(());
+	};
+	let mut position = position.into_inner();
+	// Shares received from elsewhere aren't part of the position.
+	let withdrawn_shares = shares.u128().min(position.shares);
+	if withdrawn_shares == position.shares {
+		positions.remove(&address);
+		return Ok(());
+	}
+	accrue_position_fees(&mut position, &PoolPairFeeGrowth::load_or_zeroed()?)?;
+	for index in [0, 1] {
+		position.cost_basis[index] -= Uint128::new(position.cost_basis[index])
+			.multiply_ratio(withdrawn_shares, position.shares)
+			.u128();
+		position.fees_earned[index] -= Uint128::new(position.fees_earned[index])
+			.multiply_ratio(withdrawn_shares, position.shares)
+			.u128();
+	}
+	position.shares -= withdrawn_shares;
+	positions.set(&address, &position)?;
+	Ok(())
+}
+
+/// Converts the amounts entirely into each denom at the exchange rate implied by the reserves.
+pub fn value_in_each_denom(
+	amounts: [Uint128; 2],
+	reserves: &[Uint128; 2],
+) -> Result<[Uint128; 2], PoolPairContractError> {
+	Ok([
+		amounts[0].checked_add(amounts[1].checked_multiply_ratio(reserves[0], reserves[1])?)?,
+		amounts[1].checked_add(amounts[0].checked_multiply_ratio(reserves[1], reserves[0])?)?,
+	])
+}
+
+/// How much less the position is worth compared to holding the cost basis, not counting the fees earned. Both values
+/// must be in the same denom.
+pub fn impermanent_loss(value_without_fees: Uint128, hodl_value: Uint128) -> Result<Decimal, PoolPairContractError> {
+	if hodl_value.is_zero() {
+		return Ok(Decimal::zero());
+	}
+	Ok(Decimal::one().saturating_sub(Decimal::checked_from_ratio(value_without_fees, hodl_value)?))
+}
@@ -1,4 +1,6 @@
-use cosmwasm_std::{Coin, Env};
+use cosmwasm_std::{BankMsg, Coin, Env, QuerierWrapper, Response, StdResult, Uint128};
+use crownfi_cw_common::storage::base::{storage_read, storage_write};
+use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
 pub static LP_SUBDENOM: &str = "lp";
 
@@ -11,3 +13,47 @@ pub fn lp_denom(env: &Env) -> String {
 pub fn lp_coin(env: &Env, amount: u128) -> Coin {
 	Coin::new(amount, lp_denom(env))
 }
+
+// The bank module can't always be relied upon to report the supply of our LP tokens, see
+// https://github.com/sei-protocol/sei-wasmd/issues/38. So we keep track of what we've minted and burned ourselves,
+// stored as a u128 (LE) under the LP denom. This misses any tokens burned by their holders, which `ReconcileSupply`
+// corrects whenever the bank module does report the supply.
+
+pub fn mint_shares(response: Response<SeiMsg>, coin: Coin) -> StdResult<Response<SeiMsg>> {
+	let cur_supply = tracked_share_supply(&coin.denom);
+	set_tracked_share_supply(&coin.denom, cur_supply.checked_add(coin.amount)?);
+	Ok(response.add_message(SeiMsg::MintTokens { amount: coin }))
+}
+
+pub fn burn_shares(response: Response<SeiMsg>, coin: Coin) -> StdResult<Response<SeiMsg>> {
+	let cur_supply = tracked_share_supply(&coin.denom);
+	set_tracked_share_supply(&coin.denom, cur_supply.checked_sub(coin.amount)?);
+	Ok(response.add_message(BankMsg::Burn { amount: vec![coin] }))
+}
+
+/// The supply according to what this contract has minted and burned
+pub fn tracked_share_supply(denom: &str) -> Uint128 {
+	Uint128::new(u128::from_le_bytes(
+		storage_read(denom.as_bytes())
+			.map(|vec| vec.try_into().unwrap_or_default())
+			.unwrap_or_default(),
+	))
+}
+
+pub(crate) fn set_tracked_share_supply(denom: &str, supply: Uint128) {
+	storage_write(denom.as_bytes(), &supply.u128().to_le_bytes());
+}
+
+/// The supply according to the bank module, or `None` if it isn't reporting any.
+pub fn bank_share_supply(querier: &QuerierWrapper<SeiQueryWrapper>, denom: &str) -> Option<Uint128> {
+	querier
+		.query_supply(denom)
+		.ok()
+		.map(|coin| coin.amount)
+		.filter(|amount| !amount.is_zero())
+}
+
+/// The supply according to the bank module if available, otherwise the tracked supply.
+pub fn share_supply(querier: &QuerierWrapper<SeiQueryWrapper>, denom: &str) -> Uint128 {
+	bank_share_supply(querier, denom).unwrap_or_else(|| tracked_share_supply(denom))
+}
@@ -0,0 +1,208 @@
+use cosmwasm_std::{Decimal256, StdError, Uint128, Uint256};
+use crownfi_cw_common::storage::item::StoredItem;
+
+use crate::{
+	contract::pool::{calc_fee_amount, calc_swap_output},
+	error::PoolPairContractError,
+	state::{
+		get_long_term_order_expiries_store, PoolPairConfig, PoolPairConfigFlags, PoolPairLongTermOrder,
+		PoolPairLongTermOrderExpiry, PoolPairLongTermOrders, PoolPairReserves,
+	},
+};
+
+/// Long-term orders always end on a multiple of this, so orders ending around the same time are settled together.
+pub const LONG_TERM_ORDER_INTERVAL_SECONDS: u64 = 3600;
+/// The longest a long-term order can run for
+pub const MAX_LONG_TERM_ORDER_DURATION_SECONDS: u64 = 365 * 24 * 3600;
+/// The maximum amount of long-term orders an account can have in a pool at once
+pub const MAX_LONG_TERM_ORDERS_PER_OWNER: usize = 32;
+
+/// The end time of an order placed now, which is rounded up to the next interval.
+#[inline]
+pub fn long_term_order_end_time(now: u64, duration: u64) -> u64 {
+	(now + duration).div_ceil(LONG_TERM_ORDER_INTERVAL_SECONDS) * LONG_TERM_ORDER_INTERVAL_SECONDS
+}
+
+/// The result of settling the long-term orders, see `settle_virtual_orders`.
+pub struct LongTermOrdersSettlement {
+	pub orders: PoolPairLongTermOrders,
+	pub reserves: [Uint128; 2],
+	/// The fees taken from the orders' proceeds which were added to `reserves`, in the same order
+	pub lp_fees: [Uint128; 2],
+	/// The expiries which were passed, keyed by their end time
+	pub expired: Vec<(u64, PoolPairLongTermOrderExpiry)>,
+}
+impl LongTermOrdersSettlement {
+	pub fn save(&self) -> Result<(), PoolPairContractError> {
+		self.orders.save()?;
+		PoolPairReserves {
+			amounts: self.reserves.map(|amount| amount.u128()),
+		}
+		.save()?;
+		let expiries = get_long_term_order_expiries_store();
+		for (end_time, expiry) in self.expired.iter() {
+			expiries.set(&(*end_time).into(), expiry)?;
+		}
+		Ok(())
+	}
+	/// The proceeds the order can withdraw as of this settlement.
+	pub fn claimable_proceeds(&self, order: &PoolPairLongTermOrder) -> Result<Uint128, PoolPairContractError> {
+		let index = order.inverse as usize;
+		let proceeds_per_rate = if order.end_time <= self.orders.last_settlement {
+			self.expired
+				.iter()
+				.find(|(end_time, _)| *end_time == order.end_time)
+				.map(|(_, expiry)| *expiry)
+				.or(get_long_term_order_expiries_store()
+					.get(&order.end_time.into())?
+					.map(|expiry| expiry.into_inner()))
+				.ok_or_else(|| StdError::not_found("PoolPairLongTermOrderExpiry"))?
+				.proceeds_per_rate(index)
+		} else {
+			self.orders.proceeds_per_rate(index)
+		};
+		Ok(Uint256::from(order.sell_rate)
+			.checked_mul_floor(proceeds_per_rate - order.proceeds_per_rate_checkpoint())?
+			.try_into()?)
+	}
+}
+
+/// Settles the long-term orders up to `now` without saving anything, or returns `None` if no long-term order has
+/// ever been placed.
+///
+/// Settlement is frozen while swaps are paused, nothing is sold in the meantime and `last_settlement` stays where it
+/// was. The orders pick up from there once swaps are unpaused.
+pub fn simulate_settlement(now: u64) -> Result<Option<LongTermOrdersSettlement>, PoolPairContractError> {
+	let Some(orders) = PoolPairLongTermOrders::load()? else {
+		return Ok(None);
+	};
+	let mut orders = orders.into_inner();
+	let mut reserves = PoolPairReserves::load_non_empty()?.amounts();
+	let mut lp_fees = [Uint128::zero(); 2];
+	let config = PoolPairConfig::load_non_empty()?;
+	let expired = if config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED) {
+		Vec::new()
+	} else {
+		settle_virtual_orders(&mut orders, &mut reserves, &mut lp_fees, config.total_fee_bps, now)?
+	};
+	Ok(Some(LongTermOrdersSettlement {
+		orders,
+		reserves,
+		lp_fees,
+		expired,
+	}))
+}
+
+/// Settles and saves the long-term orders up to `now`, this must happen before anything touches the reserves. Returns
+/// the fees which were added to the reserves.
+pub fn settle_long_term_orders(now: u64) -> Result<[Uint128; 2], PoolPairContractError> {
+	let Some(settlement) = simulate_settlement(now)? else {
+		return Ok([Uint128::zero(); 2]);
+	};
+	settlement.save()?;
+	Ok(settlement.lp_fees)
+}
+
+/// The reserves as they would be after the long-term orders are settled up to `now`.
+pub fn settled_reserves(now: u64) -> Result<PoolPairReserves, PoolPairContractError> {
+	Ok(match simulate_settlement(now)? {
+		Some(settlement) => PoolPairReserves {
+			amounts: settlement.reserves.map(|amount| amount.u128()),
+		},
+		None => PoolPairReserves::load_non_empty()?.into_inner(),
+	})
+}
+
+/// Executes the long-term orders against each other and the pool until `now`. The sell rates only change when orders
+/// end, so this jumps straight from one expiry to the next instead of stepping through every interval in between.
+/// Orders ending along the way stop selling, and the expiries they were recorded in are returned so they can be saved.
+///
+/// Whatever both sides are selling to each other at the current price is exchanged directly, and only the remainder
+/// of the larger side is swapped with the pool. The total fee is taken from each side's proceeds and is added to the
+/// reserves as well as `lp_fees`, no maker fee is taken. These trades aren't counted in the volume statistics.
+pub fn settle_virtual_orders(
+	orders: &mut PoolPairLongTermOrders,
+	reserves: &mut [Uint128; 2],
+	lp_fees: &mut [Uint128; 2],
+	total_fee_bps: u16,
+	now: u64,
+) -> Result<Vec<(u64, PoolPairLongTermOrderExpiry)>, PoolPairContractError> {
+	let expiries = get_long_term_order_expiries_store();
+	let mut expired = Vec::new();
+	while orders.last_settlement < now {
+		if orders.sell_rates == [0, 0] {
+			// Nothing is being sold, so there's nothing to settle until another order is placed.
+			orders.last_settlement = now;
+			break;
+		}
+		// Every order still selling has an expiry after the last settlement, so there's always a next one.
+		let (next_expiry, mut expiry) = expiries
+			.iter_range(Some(orders.last_settlement.into()), None)?
+			.map(|(end_time, expiry)| (u64::from(end_time), expiry.into_inner()))
+			.find(|(end_time, _)| *end_time > orders.last_settlement)
+			.ok_or_else(|| StdError::not_found("PoolPairLongTermOrderExpiry"))?;
+		let settle_until = next_expiry.min(now);
+		settle_segment(
+			orders,
+			reserves,
+			lp_fees,
+			total_fee_bps,
+			settle_until - orders.last_settlement,
+		)?;
+		orders.last_settlement = settle_until;
+		if settle_until != next_expiry {
+			break;
+		}
+		orders.sell_rates[0] -= expiry.sell_rates[0];
+		orders.sell_rates[1] -= expiry.sell_rates[1];
+		expiry.proceeds_per_rate = orders.proceeds_per_rate;
+		expired.push((next_expiry, expiry));
+	}
+	Ok(expired)
+}
+
+fn settle_segment(
+	orders: &mut PoolPairLongTermOrders,
+	reserves: &mut [Uint128; 2],
+	lp_fees: &mut [Uint128; 2],
+	total_fee_bps: u16,
+	seconds: u64,
+) -> Result<(), PoolPairContractError> {
+	let sold = [
+		Uint128::new(orders.sell_rates[0]).checked_mul(seconds.into())?,
+		Uint128::new(orders.sell_rates[1]).checked_mul(seconds.into())?,
+	];
+	// The "heavy" side is the one selling more than the other side can buy at the current price.
+	let heavy = (sold[0].full_mul(reserves[1]) < sold[1].full_mul(reserves[0])) as usize;
+	let light = heavy ^ 1;
+
+	// What the heavy side sells to the light side at the current price, the excess is swapped with the pool.
+	let matched: Uint128 = sold[light]
+		.full_mul(reserves[heavy])
+		.checked_div(reserves[light].into())
+		.map_err(|_| PoolPairContractError::NoLiquidity)?
+		.try_into()?;
+	let excess = sold[heavy] - matched;
+	let swap_result = calc_swap_output(reserves[heavy], reserves[light], excess)?;
+	reserves[heavy] += excess;
+	reserves[light] -= swap_result;
+
+	// Indexed by the side which is selling, so the proceeds are in the other denom.
+	let mut proceeds = [Uint128::zero(); 2];
+	proceeds[heavy] = sold[light] + swap_result;
+	proceeds[light] = matched;
+	for index in [0, 1] {
+		let fee_amount = calc_fee_amount(proceeds[index], total_fee_bps)?;
+		let net_proceeds = proceeds[index] - fee_amount;
+		reserves[index ^ 1] += fee_amount;
+		lp_fees[index ^ 1] += fee_amount;
+		orders.escrow[index] -= sold[index].u128();
+		orders.escrow[index ^ 1] += net_proceeds.u128();
+		if orders.sell_rates[index] != 0 {
+			let proceeds_per_rate =
+				orders.proceeds_per_rate(index) + Decimal256::from_ratio(net_proceeds, orders.sell_rates[index]);
+			orders.set_proceeds_per_rate(index, proceeds_per_rate);
+		}
+	}
+	Ok(())
+}
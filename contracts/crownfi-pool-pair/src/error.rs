@@ -3,7 +3,10 @@ use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_st
 use cw_utils::PaymentError;
 use thiserror::Error;
 
-use crate::contract::pool::{MAX_ALLOWED_TOLERANCE, MINIMUM_INITIAL_SHARES};
+use crate::contract::{
+	pool::{MAX_ALLOWED_TOLERANCE, MINIMUM_INITIAL_SHARES},
+	twamm::{MAX_LONG_TERM_ORDERS_PER_OWNER, MAX_LONG_TERM_ORDER_DURATION_SECONDS},
+};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum PoolPairContractError {
@@ -13,7 +16,7 @@ pub enum PoolPairContractError {
 	SwapsCommonError(#[from] CrownfiSwapsCommonError),
 	#[error("Payment error: {0}")]
 	PaymentError(#[from] PaymentError),
-	#[error("Initial shares minted must be at least {}", MINIMUM_INITIAL_SHARES)]
+	#[error("Initial shares minted must be more than {}", MINIMUM_INITIAL_SHARES)]
 	MinimumSharesAmountError,
 	#[error("This pool has no liquidity!")]
 	NoLiquidity,
@@ -25,6 +28,39 @@ pub enum PoolPairContractError {
 	SlippageTooHigh(Decimal),
 	#[error("The denoms of the coins provided must match that which was returned by \"canonical_pair_denoms\"")]
 	DepositQueryDenomMismatch,
+	#[error("Swaps are currently paused for this pool")]
+	SwapsPaused,
+	#[error("Deposits are currently paused for this pool")]
+	DepositsPaused,
+	#[error("This pool has no LP staking contract configured")]
+	AutoStakeUnavailable,
+	#[error("auto_stake cannot be used alongside a receiver_payload")]
+	AutoStakeWithPayload,
+	#[error("This pool is locked while another contract it has executed is running")]
+	PoolLocked,
+	#[error("Flash swaps must leave some of each of the pool's assets behind")]
+	FlashSwapTooLarge,
+	#[error("Flash swap did not pay back enough to cover what was borrowed and the swap fee")]
+	FlashSwapNotRepaid,
+	#[error("The bank module isn't reporting the supply of this pool's shares")]
+	ShareSupplyUnavailable,
+	#[error("Long-term orders are disabled for this pool")]
+	LongTermOrdersDisabled,
+	#[error(
+		"Long-term order duration must be between 1 and {} seconds",
+		MAX_LONG_TERM_ORDER_DURATION_SECONDS
+	)]
+	InvalidLongTermOrderDuration,
+	#[error("Long-term order is too small to be sold over its duration")]
+	LongTermOrderTooSmall,
+	#[error("Long-term order {0} does not exist")]
+	LongTermOrderNotFound(u64),
+	#[error("Cannot have more than {} long-term orders at once", MAX_LONG_TERM_ORDERS_PER_OWNER)]
+	TooManyLongTermOrders,
+	#[error("Referral fee cannot exceed {0} bps")]
+	ReferralFeeTooHigh(u16),
+	#[error("Fees cannot exceed 10000 bps, and the maker fee cannot exceed the total fee")]
+	InvalidFeeBps,
 }
 
 impl_from_cosmwasm_std_error_common!(PoolPairContractError);
@@ -2,8 +2,8 @@ pub mod attributes;
 pub mod contract;
 pub mod error;
 pub mod msg;
+pub mod raw_query;
 pub mod state;
-pub mod workarounds;
 
 #[cfg(test)]
 mod tests;
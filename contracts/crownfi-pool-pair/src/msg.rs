@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Binary, Coin, Decimal, Uint128};
+use crownfi_swaps_common::data_types::pending_admin::PendingAdminTransferJsonable;
 
 use crate::{
 	contract::pool::{PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult},
@@ -11,15 +12,20 @@ use crate::{
 pub struct PoolPairInstantiateMsg {
 	pub shares_receiver: Addr,
 	pub config: PoolPairConfigJsonable,
+	/// The contract LP tokens are staked with when `auto_stake` is specified.
+	#[serde(default)]
+	pub lp_staking: Option<Addr>,
 }
 
+/// Sent by the factory when upgrading the pool contract. Currently there's nothing to configure.
+#[cw_serde]
+pub struct PoolPairMigrateMsg {}
+
 /// This structure describes the execute messages available in the contract.
 #[cw_serde]
 pub enum PoolPairExecuteMsg {
 	/// Update the pair configuration
 	UpdateConfig {
-		/// The head honcho, this is usually the factory contract
-		admin: Option<Addr>,
 		/// Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
 		fee_receiver: Option<Addr>,
 		/// The total fees (in bps) charged by a pair of this type
@@ -28,6 +34,34 @@ pub enum PoolPairExecuteMsg {
 		maker_fee_bps: Option<u16>,
 		/// If true, this has been endorsed by the admin.
 		endorsed: Option<bool>,
+		/// The contract LP tokens are staked with, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+		lp_staking: Option<Addr>,
+		/// If true, long-term orders can be placed. Existing orders are always settled.
+		long_term_orders: Option<bool>,
+		/// If true, the cost basis of each address's deposits and the fees earned by LPs are recorded, see
+		/// `PoolPairQueryMsg::PositionReport`.
+		position_tracking: Option<bool>,
+		/// If true, swap fees are taken from the offered amount rather than the result.
+		fee_on_input: Option<bool>,
+		/// The highest referral fee (in bps) swaps can pay
+		max_referral_fee_bps: Option<u16>,
+	},
+	/// Proposes a new admin, the transfer only takes effect once they accept it with `AcceptAdmin`. This replaces any
+	/// previously proposed admin.
+	ProposeAdmin {
+		/// The proposed head honcho
+		admin: Addr,
+	},
+	/// Accepts a previously proposed admin transfer. Must be sent by the proposed admin.
+	AcceptAdmin {},
+	/// Cancels a previously proposed admin transfer.
+	CancelAdminTransfer {},
+	/// Pauses or unpauses swaps and deposits. Withdrawals are always allowed.
+	SetPaused {
+		/// If specified, enables or disables swaps
+		swaps: Option<bool>,
+		/// If specified, enables or disables providing liquidity
+		deposits: Option<bool>,
 	},
 	/// ProvideLiquidity allows someone to provide liquidity in the pool
 	ProvideLiquidity {
@@ -37,6 +71,11 @@ pub enum PoolPairExecuteMsg {
 		receiver: Option<Addr>,
 		/// If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
 		receiver_payload: Option<Binary>,
+		/// If true, the pool shares are staked on behalf of the receiver with the LP staking contract configured on
+		/// this pool. Cannot be used with `receiver_payload`. If the staking contract has no reward programs for this
+		/// pool, the shares are sent to the receiver instead.
+		#[serde(default)]
+		auto_stake: bool,
 	},
 	/// Withdraw liquidity from the pool
 	WithdrawLiquidity {
@@ -69,7 +108,60 @@ pub enum PoolPairExecuteMsg {
 		receiver: Option<Addr>,
 		/// If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
 		receiver_payload: Option<Binary>,
+		/// Pays part of the result to whoever referred the swap, e.g. the frontend it was made from.
+		referral: Option<PoolPairSwapReferral>,
+	},
+	/// Lends out the specified amounts of the pool's reserves and executes the receiver with the specified payload.
+	/// By the time that execution ends, the receiver must have sent enough funds back to this contract so that the
+	/// pool's constant product doesn't decrease, after the swap fee is taken from what was sent back.
+	///
+	/// The amounts correspond to the denoms in canonical, that is, lexicographical order.
+	FlashSwap {
+		amounts: [Uint128; 2],
+		/// The contract receiving the loan, defaults to the sender
+		receiver: Option<Addr>,
+		/// The message the receiver is executed with
+		payload: Binary,
+	},
+	/// Sends anything this contract holds beyond its tracked reserves, e.g. tokens sent to it directly, to the
+	/// receiver. Anyone can do this.
+	Skim {
+		/// The account receiving the surplus, defaults to the sender
+		receiver: Option<Addr>,
 	},
+	/// Sets the tracked reserves to this contract's actual balances, adding any surplus to the pool's liquidity.
+	/// Anyone can do this.
+	Sync {},
+	/// Sets the LP token supply tracked by this contract to what the bank module reports, correcting for any LP
+	/// tokens burned by their holders. Anyone can do this, though it fails if the bank module isn't reporting a supply.
+	ReconcileSupply {},
+	/// Sells the coin sent over the specified duration (in seconds), at a constant rate, by splitting it into
+	/// infinitely many virtual swaps. The end time is rounded up to the next hour, and whatever can't be sold at a
+	/// constant rate until then is refunded.
+	///
+	/// Long-term orders selling opposite sides of the pair are matched with each other before the pool is used, and
+	/// they're settled whenever anyone interacts with the pool. The total fee is taken from the proceeds.
+	PlaceLongTermOrder {
+		/// How long the order should run for
+		duration: u64,
+	},
+	/// Cancels a long-term order, refunding what hasn't been sold yet along with the proceeds which haven't been
+	/// withdrawn. Must be sent by the order's owner.
+	CancelLongTermOrder { order_id: u64 },
+	/// Withdraws the proceeds of a long-term order so far. The order is removed once it has ended. Must be sent by the
+	/// order's owner.
+	WithdrawLongTermOrder { order_id: u64 },
+	/// Settles the long-term orders up until now. Anyone can do this, though this happens anyway whenever anyone
+	/// interacts with the pool.
+	ExecuteVirtualOrders {},
+}
+
+#[cw_serde]
+pub struct PoolPairSwapReferral {
+	/// The account receiving the referral fee
+	pub address: Addr,
+	/// The referral fee (in bps) taken from the result, this can't exceed the pool's `max_referral_fee_bps`.
+	pub fee_bps: u16,
 }
 
 #[cw_serde]
@@ -108,21 +200,42 @@ pub enum PoolPairQueryMsg {
 	/// Config returns contract settings specified in the custom [`ConfigResponse`] structure.
 	#[returns(PoolPairConfigJsonable)]
 	Config,
+	/// Returns the proposed admin transfer, if any.
+	#[returns(Option<PendingAdminTransferJsonable>)]
+	PendingAdmin,
+	/// Returns the contract LP tokens are staked with when `auto_stake` is specified, if any.
+	#[returns(Option<Addr>)]
+	LpStaking,
 	/// Returns the total amount of shares known to the contract
 	#[returns(Uint128)]
 	TotalShares,
+	/// Returns the LP token supply tracked by this contract alongside what the bank module reports, if anything.
+	#[returns(PoolPairQueryShareSupplyResponse)]
+	ShareSupply,
 	/// Returns the current value of shares
 	#[returns([Coin; 2])]
 	ShareValue { amount: Uint128 },
+	/// Returns the reserves the pool is tracking, with long-term orders settled up until now, alongside the balances it
+	/// actually holds.
+	#[returns(PoolPairQueryReservesResponse)]
+	Reserves,
 	/// Simulates a deposit and tells you how many pool shares you'd recieve, along with their value.
 	#[returns(PoolPairQuerySimulateDepositResponse)]
 	SimulateProvideLiquidity { offer: [Coin; 2] },
 	/// Simulates a swap and tells you how much you'd get in return, the spread, and the fees involved.
 	#[returns(PoolPairCalcSwapResult)]
-	SimulateSwap { offer: Coin },
+	SimulateSwap {
+		offer: Coin,
+		/// The referral fee (in bps) taken from the result, if any
+		referral_fee_bps: Option<u16>,
+	},
 	/// Simulates a swap assuming infinite liquidity, i.e. having no effect on the exchange rate.
 	#[returns(PoolPairCalcNaiveSwapResult)]
-	SimulateNaiveSwap { offer: Coin },
+	SimulateNaiveSwap {
+		offer: Coin,
+		/// The referral fee (in bps) taken from the result, if any
+		referral_fee_bps: Option<u16>,
+	},
 	/// If past_hours is specified and is greater than 0, returns the total volume in the past specified hours.
 	/// e.g. 24 means volume over the past 24 hours, updated every hour (UTC).
 	///
@@ -170,6 +283,22 @@ pub enum PoolPairQueryMsg {
 	///
 	/// Data older than 30 days is not guaranteed.
 	EstimateApy { past_days: u8 },
+	/// Returns the combined state of all long-term orders, settled up until now.
+	#[returns(PoolPairQueryLongTermOrdersResponse)]
+	LongTermOrders,
+	/// Returns the progress of the specified long-term order, if it exists.
+	#[returns(Option<PoolPairQueryLongTermOrderResponse>)]
+	LongTermOrder { order_id: u64 },
+	/// Returns the progress of all long-term orders owned by the specified account.
+	#[returns(Vec<PoolPairQueryLongTermOrderResponse>)]
+	LongTermOrdersByOwner { owner: Addr },
+	/// Returns what the position recorded for the specified address is worth compared to what was deposited, if
+	/// position tracking was enabled when the address provided liquidity.
+	///
+	/// Only deposits made with `ProvideLiquidity` and withdrawals sent by the address itself are recorded, so shares
+	/// transferred elsewhere are still counted as part of the position.
+	#[returns(Option<PoolPairQueryPositionReportResponse>)]
+	PositionReport { address: Addr },
 }
 
 #[cw_serde]
@@ -177,3 +306,73 @@ pub struct PoolPairQuerySimulateDepositResponse {
 	pub share_amount: Uint128,
 	pub share_value: [Coin; 2],
 }
+
+#[cw_serde]
+pub struct PoolPairQueryShareSupplyResponse {
+	/// What this contract has minted minus what it has burned, as of the last `ReconcileSupply`
+	pub tracked: Uint128,
+	/// What the bank module reports, this is what `TotalShares` returns when available
+	pub bank: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct PoolPairQueryReservesResponse {
+	/// What all swaps and share values are calculated from
+	pub tracked: [Coin; 2],
+	/// Any amount above `tracked`, and what's held on behalf of long-term orders, can be taken with `Skim` or added to
+	/// the reserves with `Sync`
+	pub actual: [Coin; 2],
+}
+
+#[cw_serde]
+pub struct PoolPairQueryLongTermOrdersResponse {
+	/// The amount of each denom being sold per second
+	pub sell_rates: [Coin; 2],
+	/// What this contract holds on behalf of long-term orders, this is excluded from the reserves
+	pub escrow: [Coin; 2],
+	/// Unix timestamp (in seconds) of when the orders were last settled
+	pub last_settlement: u64,
+}
+
+#[cw_serde]
+pub struct PoolPairQueryLongTermOrderResponse {
+	pub order_id: u64,
+	pub owner: Addr,
+	/// The denom being sold
+	pub sell_denom: String,
+	/// The denom being bought
+	pub buy_denom: String,
+	/// The amount sold per second
+	pub sell_rate: Uint128,
+	/// Unix timestamp (in seconds) of when the order was placed
+	pub start_time: u64,
+	/// Unix timestamp (in seconds) of when the order ends
+	pub end_time: u64,
+	/// The amount which has been sold so far
+	pub sold: Uint128,
+	/// The amount which has yet to be sold
+	pub remaining: Uint128,
+	/// The proceeds which can be withdrawn
+	pub claimable_proceeds: Uint128,
+	/// The proceeds which have been withdrawn so far
+	pub withdrawn_proceeds: Uint128,
+}
+
+#[cw_serde]
+pub struct PoolPairQueryPositionReportResponse {
+	/// The shares minted for the address's deposits, minus those it has withdrawn since
+	pub shares: Uint128,
+	/// What the shares are currently worth
+	pub share_value: [Coin; 2],
+	/// What was deposited for the shares, reduced proportionally on withdrawals
+	pub cost_basis: [Coin; 2],
+	/// `share_value` converted entirely into each denom at the current exchange rate
+	pub share_value_total: [Coin; 2],
+	/// `cost_basis` converted entirely into each denom at the current exchange rate, i.e. what the deposits would be
+	/// worth had they been held instead
+	pub hodl_value_total: [Coin; 2],
+	/// An estimate of the LP fees from swaps which the shares have earned. These are already part of `share_value`.
+	pub fees_earned: [Coin; 2],
+	/// How much less `share_value_total` is worth compared to `hodl_value_total`, not counting `fees_earned`.
+	pub impermanent_loss: Decimal,
+}
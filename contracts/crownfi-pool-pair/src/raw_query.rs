@@ -0,0 +1,188 @@
+//! Helper functions for other contracts which read a pool pair's state using raw queries. These are much cheaper than
+//! smart queries, at the cost of being tied to this contract's storage layout.
+use cosmwasm_std::{Addr, Coin, Decimal, QuerierWrapper, StdError, Uint128};
+use crownfi_cw_common::storage::{item::StoredItem, SerializableItem};
+use crownfi_swaps_common::data_types::pair_id::CanonicalPoolPairIdentifier;
+use cw_utils::PaymentError;
+use sei_cosmwasm::SeiQueryWrapper;
+
+use crate::{
+	contract::{
+		pool::{
+			calc_naive_swap, calc_swap, check_referral_fee_bps, PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult,
+		},
+		shares::{bank_share_supply, LP_SUBDENOM},
+	},
+	error::PoolPairContractError,
+	msg::{PoolPairQueryMsg, PoolPairQueryReservesResponse},
+	state::{
+		PoolPairConfig, PoolPairConfigFlags, PoolPairLock, PoolPairLongTermOrders, PoolPairReserves, TradingVolume,
+		VOLUME_STATS_ALL_TIME_NAMESPACE,
+	},
+};
+
+fn query_raw_item<T: SerializableItem>(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+	key: &[u8],
+) -> Result<Option<T>, StdError> {
+	querier
+		.query_wasm_raw(pool_addr, key)?
+		.map(|bytes| T::deserialize_to_owned(&bytes))
+		.transpose()
+}
+
+/// Reads the pool's config
+pub fn query_pool_config(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+) -> Result<PoolPairConfig, StdError> {
+	query_raw_item(querier, pool_addr, PoolPairConfig::namespace())?.ok_or(StdError::not_found("PoolPairConfig"))
+}
+
+/// Reads the pool's denoms
+pub fn query_pool_pair_id(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+) -> Result<CanonicalPoolPairIdentifier, StdError> {
+	query_raw_item(querier, pool_addr, CanonicalPoolPairIdentifier::namespace())?
+		.ok_or(StdError::not_found("CanonicalPoolPairIdentifier"))
+}
+
+/// Returns the pool's LP token supply, the same way the pool itself does, see
+/// [share_supply](crate::contract::shares::share_supply)
+pub fn query_pool_total_shares(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+) -> Result<Uint128, StdError> {
+	let lp_denom = format!("factory/{}/{}", pool_addr, LP_SUBDENOM);
+	if let Some(supply) = bank_share_supply(querier, &lp_denom) {
+		return Ok(supply);
+	}
+	Ok(Uint128::new(u128::from_le_bytes(
+		querier
+			.query_wasm_raw(pool_addr, lp_denom.as_bytes())?
+			.and_then(|supply_bytes| supply_bytes.try_into().ok())
+			.unwrap_or_default(),
+	)))
+}
+
+/// Returns true if the pool is waiting on another contract to finish executing, in which case its reserves shouldn't
+/// be relied upon. See [PoolPairLock].
+pub fn query_pool_locked(querier: &QuerierWrapper<SeiQueryWrapper>, pool_addr: &Addr) -> Result<bool, StdError> {
+	Ok(querier.query_wasm_raw(pool_addr, PoolPairLock::namespace())?.is_some())
+}
+
+/// Reads the pool's tracked reserves, in the same order as `pair_id`. See [PoolPairReserves].
+///
+/// If the pool has long-term orders which are still selling, the stored reserves are only up-to-date as of their last
+/// settlement. The pool is asked for its reserves instead in that case, as it settles the orders up to now first.
+pub fn query_pool_reserves(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+	pair_id: &CanonicalPoolPairIdentifier,
+) -> Result<[Uint128; 2], StdError> {
+	let long_term_orders =
+		query_raw_item::<PoolPairLongTermOrders>(querier, pool_addr, PoolPairLongTermOrders::namespace())?;
+	if long_term_orders.is_some_and(|orders| orders.sell_rates != [0, 0]) {
+		let reserves: PoolPairQueryReservesResponse =
+			querier.query_wasm_smart(pool_addr, &PoolPairQueryMsg::Reserves)?;
+		return Ok(reserves.tracked.map(|coin| coin.amount));
+	}
+	if let Some(reserves) = query_raw_item::<PoolPairReserves>(querier, pool_addr, PoolPairReserves::namespace())? {
+		return Ok(reserves.amounts());
+	}
+	// Pools which haven't been migrated since reserves started being tracked still use their balances.
+	Ok([
+		querier.query_balance(pool_addr, &pair_id.left)?.amount,
+		querier.query_balance(pool_addr, &pair_id.right)?.amount,
+	])
+}
+
+/// Reads the pool's all-time trading volume, if any trades have happened
+pub fn query_pool_total_volume(
+	querier: &QuerierWrapper<SeiQueryWrapper>,
+	pool_addr: &Addr,
+) -> Result<Option<TradingVolume>, StdError> {
+	query_raw_item(querier, pool_addr, VOLUME_STATS_ALL_TIME_NAMESPACE)
+}
+
+/// Everything needed to simulate swaps against a pool
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolPairRawState {
+	pub address: Addr,
+	pub pair_id: CanonicalPoolPairIdentifier,
+	pub config: PoolPairConfig,
+	/// The pool's tracked reserves, in the same order as `pair_id`, with long-term orders settled up to now
+	pub balances: [Uint128; 2],
+}
+impl PoolPairRawState {
+	pub fn query(querier: &QuerierWrapper<SeiQueryWrapper>, pool_addr: Addr) -> Result<Self, StdError> {
+		let pair_id = query_pool_pair_id(querier, &pool_addr)?;
+		Self::query_with_pair_id(querier, pool_addr, pair_id)
+	}
+	/// Same as [query](Self::query), for when the pool's denoms are already known
+	pub fn query_with_pair_id(
+		querier: &QuerierWrapper<SeiQueryWrapper>,
+		pool_addr: Addr,
+		pair_id: CanonicalPoolPairIdentifier,
+	) -> Result<Self, StdError> {
+		if query_pool_locked(querier, &pool_addr)? {
+			return Err(StdError::generic_err(PoolPairContractError::PoolLocked.to_string()));
+		}
+		Ok(Self {
+			config: query_pool_config(querier, &pool_addr)?,
+			balances: query_pool_reserves(querier, &pool_addr, &pair_id)?,
+			address: pool_addr,
+			pair_id,
+		})
+	}
+	pub fn reserves(&self) -> [Coin; 2] {
+		[
+			Coin::new(self.balances[0].u128(), self.pair_id.left.clone()),
+			Coin::new(self.balances[1].u128(), self.pair_id.right.clone()),
+		]
+	}
+	/// Same result as the pool's `SimulateSwap` query
+	pub fn simulate_swap(
+		&self,
+		offer: &Coin,
+		referral_fee_bps: u16,
+	) -> Result<PoolPairCalcSwapResult, PoolPairContractError> {
+		if !self.pair_id.is_in_pair(&offer.denom) {
+			return Err(PaymentError::ExtraDenom(offer.denom.clone()).into());
+		}
+		check_referral_fee_bps(referral_fee_bps, self.config.max_referral_fee_bps)?;
+		calc_swap(
+			&self.balances,
+			offer.amount,
+			self.config.total_fee_bps,
+			self.config.maker_fee_bps,
+			referral_fee_bps,
+			self.config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT),
+			offer.denom == self.pair_id.right,
+			None,
+			Decimal::MAX,
+		)
+	}
+	/// Same result as the pool's `SimulateNaiveSwap` query
+	pub fn simulate_naive_swap(
+		&self,
+		offer: &Coin,
+		referral_fee_bps: u16,
+	) -> Result<PoolPairCalcNaiveSwapResult, PoolPairContractError> {
+		if !self.pair_id.is_in_pair(&offer.denom) {
+			return Err(PaymentError::ExtraDenom(offer.denom.clone()).into());
+		}
+		check_referral_fee_bps(referral_fee_bps, self.config.max_referral_fee_bps)?;
+		calc_naive_swap(
+			&self.balances,
+			offer.amount,
+			self.config.total_fee_bps,
+			self.config.maker_fee_bps,
+			referral_fee_bps,
+			self.config.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT),
+			offer.denom == self.pair_id.right,
+		)
+	}
+}
@@ -2,19 +2,22 @@ use core::f64;
 use std::{cmp::Ordering, num::NonZeroU8, u64};
 
 use bitflags::bitflags;
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
-use cosmwasm_std::{Addr, Decimal, StdError, Timestamp};
+use cosmwasm_std::{coin, Addr, Coin, Decimal, Decimal256, StdError, Timestamp, Uint128, Uint256};
 use crownfi_cw_common::{
 	data_types::canonical_addr::SeiCanonicalAddr,
 	extentions::timestamp::TimestampExtentions,
-	impl_serializable_as_ref,
+	impl_serializable_as_ref, impl_serializable_borsh,
 	storage::{
 		base::{storage_read_item, storage_write_item},
 		item::StoredItem,
+		map::StoredMap,
 		queue::StoredVecDeque,
 		OZeroCopy, SerializableItem,
 	},
 };
+use crownfi_swaps_common::data_types::pair_id::PoolPairIdentifier;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +35,16 @@ bitflags! {
 		const INVERSE = 0b00000001u8;
 		/// If true, this has been endorsed by the market maker (probably CrownFi)
 		const ENDORSED = 0b00000010u8;
+		/// If true, swaps are disabled
+		const SWAPS_PAUSED = 0b00000100u8;
+		/// If true, liquidity cannot be provided. Withdrawals are always allowed.
+		const DEPOSITS_PAUSED = 0b00001000u8;
+		/// If true, long-term orders can be placed. Existing orders are always settled.
+		const LONG_TERM_ORDERS = 0b00010000u8;
+		/// If true, the cost basis of each address's deposits and the fees earned by LPs are recorded.
+		const POSITION_TRACKING = 0b00100000u8;
+		/// If true, swap fees are taken from the offered amount rather than the result.
+		const FEE_ON_INPUT = 0b01000000u8;
 	}
 }
 
@@ -46,7 +59,9 @@ pub struct PoolPairConfig {
 	pub total_fee_bps: u16,
 	/// The amount of fees (in bps) collected by the Maker contract from this pair type
 	pub maker_fee_bps: u16,
-	_unused_1: [u8; 4], // Possible lower-bound fees
+	/// The highest referral fee (in bps) swaps can pay, this is configured by the factory
+	pub max_referral_fee_bps: u16,
+	_unused_1: [u8; 2], // Possible lower-bound fees
 	/// Collection of boolean values
 	pub flags: PoolPairConfigFlags, // Possible lower-bound fees
 	_unused_3: [u8; 7], // bit flags may be extended upon (plus we need the padding)
@@ -66,6 +81,24 @@ pub struct PoolPairConfigJsonable {
 	pub inverse: bool,
 	/// If true, this has been endorsed by the market maker (probably CrownFi)
 	pub endorsed: bool,
+	/// If true, swaps are disabled
+	#[serde(default)]
+	pub swaps_paused: bool,
+	/// If true, liquidity cannot be provided. Withdrawals are always allowed.
+	#[serde(default)]
+	pub deposits_paused: bool,
+	/// If true, long-term orders can be placed. Existing orders are always settled.
+	#[serde(default)]
+	pub long_term_orders: bool,
+	/// If true, the cost basis of each address's deposits and the fees earned by LPs are recorded.
+	#[serde(default)]
+	pub position_tracking: bool,
+	/// If true, swap fees are taken from the offered amount rather than the result, and are paid in the offered denom.
+	#[serde(default)]
+	pub fee_on_input: bool,
+	/// The highest referral fee (in bps) swaps can pay, this is configured by the factory
+	#[serde(default)]
+	pub max_referral_fee_bps: u16,
 }
 
 impl_serializable_as_ref!(PoolPairConfig);
@@ -88,7 +121,9 @@ impl PoolPairConfig {
 	}
 
 	pub fn valid_fee_bps(&self) -> bool {
-		self.total_fee_bps <= MAX_TOTAL_FEE_BPS && self.maker_fee_bps <= self.total_fee_bps
+		self.total_fee_bps <= MAX_TOTAL_FEE_BPS
+			&& self.maker_fee_bps <= self.total_fee_bps
+			&& self.max_referral_fee_bps <= MAX_TOTAL_FEE_BPS
 	}
 }
 impl TryFrom<&PoolPairConfigJsonable> for PoolPairConfig {
@@ -101,12 +136,28 @@ impl TryFrom<&PoolPairConfigJsonable> for PoolPairConfig {
 		if value.inverse {
 			flags = flags.union(PoolPairConfigFlags::INVERSE);
 		}
+		if value.swaps_paused {
+			flags = flags.union(PoolPairConfigFlags::SWAPS_PAUSED);
+		}
+		if value.deposits_paused {
+			flags = flags.union(PoolPairConfigFlags::DEPOSITS_PAUSED);
+		}
+		if value.long_term_orders {
+			flags = flags.union(PoolPairConfigFlags::LONG_TERM_ORDERS);
+		}
+		if value.position_tracking {
+			flags = flags.union(PoolPairConfigFlags::POSITION_TRACKING);
+		}
+		if value.fee_on_input {
+			flags = flags.union(PoolPairConfigFlags::FEE_ON_INPUT);
+		}
 
 		Ok(PoolPairConfig {
 			admin: (&value.admin).try_into()?,
 			fee_receiver: (&value.fee_receiver).try_into()?,
 			total_fee_bps: value.total_fee_bps,
 			maker_fee_bps: value.maker_fee_bps,
+			max_referral_fee_bps: value.max_referral_fee_bps,
 			flags,
 			..Zeroable::zeroed()
 		})
@@ -122,11 +173,309 @@ impl TryFrom<&PoolPairConfig> for PoolPairConfigJsonable {
 			maker_fee_bps: value.maker_fee_bps,
 			inverse: value.flags.contains(PoolPairConfigFlags::INVERSE),
 			endorsed: value.flags.contains(PoolPairConfigFlags::ENDORSED),
+			swaps_paused: value.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED),
+			deposits_paused: value.flags.contains(PoolPairConfigFlags::DEPOSITS_PAUSED),
+			long_term_orders: value.flags.contains(PoolPairConfigFlags::LONG_TERM_ORDERS),
+			position_tracking: value.flags.contains(PoolPairConfigFlags::POSITION_TRACKING),
+			fee_on_input: value.flags.contains(PoolPairConfigFlags::FEE_ON_INPUT),
+			max_referral_fee_bps: value.max_referral_fee_bps,
 		})
 	}
 }
 
-const VOLUME_STATS_ALL_TIME_NAMESPACE: &[u8] = "volA".as_bytes();
+pub const STAKING_CONFIG_NAMESPACE: &str = "stk_cfg";
+/// Stored separately from `PoolPairConfig` as there's no room left in its padding for another address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairStakingConfig {
+	/// Where LP tokens are staked when `auto_stake` is specified. If == Zeroable::zeroed(), None
+	pub lp_staking: SeiCanonicalAddr,
+}
+impl_serializable_as_ref!(PoolPairStakingConfig);
+impl StoredItem for PoolPairStakingConfig {
+	fn namespace() -> &'static [u8] {
+		STAKING_CONFIG_NAMESPACE.as_bytes()
+	}
+}
+impl PoolPairStakingConfig {
+	/// Returns the configured LP staking contract, if any.
+	pub fn lp_staking_addr() -> Result<Option<Addr>, StdError> {
+		let Some(config) = Self::load()? else {
+			return Ok(None);
+		};
+		if config.lp_staking == Zeroable::zeroed() {
+			return Ok(None);
+		}
+		Ok(Some(config.lp_staking.try_into()?))
+	}
+}
+
+pub const RESERVES_NAMESPACE: &str = "reserves";
+/// The pool's own accounting of the assets it holds, which is what all swaps and share values are calculated from.
+/// Anything sent to this contract outside of its execute messages isn't counted until `PoolPairExecuteMsg::Sync` is
+/// used, and can be taken with `PoolPairExecuteMsg::Skim` until then.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairReserves {
+	/// In the same order as the canonical pair
+	pub amounts: [u128; 2],
+}
+impl_serializable_as_ref!(PoolPairReserves);
+impl StoredItem for PoolPairReserves {
+	fn namespace() -> &'static [u8] {
+		RESERVES_NAMESPACE.as_bytes()
+	}
+}
+impl PoolPairReserves {
+	pub fn load_non_empty() -> Result<OZeroCopy<Self>, StdError>
+	where
+		Self: Sized,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(StdError::NotFound {
+				kind: "PoolPairReserves".into(),
+			}),
+		}
+	}
+	#[inline]
+	pub fn amounts(&self) -> [Uint128; 2] {
+		self.amounts.map(Uint128::new)
+	}
+	pub fn as_coins(&self, pair: &PoolPairIdentifier) -> [Coin; 2] {
+		[
+			coin(self.amounts[0], pair.left.clone()),
+			coin(self.amounts[1], pair.right.clone()),
+		]
+	}
+}
+
+pub const LOCK_NAMESPACE: &str = "lock";
+/// Exists while this contract is waiting on another contract which may call back into it, i.e. when executing a
+/// `receiver_payload` or during a flash swap. State-changing messages, and queries which depend on the pool's
+/// reserves, are rejected while this exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairLock {
+	/// The block height the lock was acquired at
+	pub height: u64,
+}
+impl_serializable_as_ref!(PoolPairLock);
+impl StoredItem for PoolPairLock {
+	fn namespace() -> &'static [u8] {
+		LOCK_NAMESPACE.as_bytes()
+	}
+}
+impl PoolPairLock {
+	pub fn is_locked() -> Result<bool, StdError> {
+		Ok(Self::load()?.is_some())
+	}
+}
+
+pub const FLASH_SWAP_NAMESPACE: &str = "flash";
+/// Exists while a flash swap's receiver is being executed, see `PoolPairExecuteMsg::FlashSwap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairFlashSwapState {
+	/// The pool's reserves before anything was lent out
+	pub balances_before: [u128; 2],
+	/// The amounts lent out
+	pub amounts: [u128; 2],
+}
+impl_serializable_as_ref!(PoolPairFlashSwapState);
+impl StoredItem for PoolPairFlashSwapState {
+	fn namespace() -> &'static [u8] {
+		FLASH_SWAP_NAMESPACE.as_bytes()
+	}
+}
+
+pub const LONG_TERM_ORDERS_NAMESPACE: &str = "lto";
+/// The state of all long-term orders, see `PoolPairExecuteMsg::PlaceLongTermOrder`. Arrays are in the same order as
+/// the canonical pair, and are indexed by the denom being sold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairLongTermOrders {
+	/// The combined amount sold per second
+	pub sell_rates: [u128; 2],
+	/// What this contract holds on behalf of long-term orders, i.e. what's left to be sold plus unclaimed proceeds.
+	/// This is held separately from the reserves.
+	pub escrow: [u128; 2],
+	/// Proceeds earned per unit of sell rate since the beginning of time, converted verbatim from a `Decimal256`
+	pub proceeds_per_rate: [[u8; 32]; 2],
+	/// Unix timestamp (in seconds) of when the orders were last settled
+	pub last_settlement: u64,
+	/// The ID which will be given to the next order placed. IDs start at 1.
+	pub next_order_id: u64,
+}
+impl_serializable_as_ref!(PoolPairLongTermOrders);
+impl StoredItem for PoolPairLongTermOrders {
+	fn namespace() -> &'static [u8] {
+		LONG_TERM_ORDERS_NAMESPACE.as_bytes()
+	}
+}
+impl PoolPairLongTermOrders {
+	pub fn new(now: u64) -> Self {
+		Self {
+			last_settlement: now,
+			next_order_id: 1,
+			..Zeroable::zeroed()
+		}
+	}
+	#[inline]
+	pub fn proceeds_per_rate(&self, index: usize) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.proceeds_per_rate[index]))
+	}
+	#[inline]
+	pub fn set_proceeds_per_rate(&mut self, index: usize, value: Decimal256) {
+		self.proceeds_per_rate[index] = value.atomics().to_le_bytes();
+	}
+	/// The amounts held on behalf of long-term orders, or 0 if none have ever been placed.
+	pub fn load_escrow() -> Result<[Uint128; 2], StdError> {
+		Ok(Self::load()?
+			.map(|orders| orders.escrow.map(Uint128::new))
+			.unwrap_or_default())
+	}
+}
+
+/// Timestamps stored as big-endian, so they're iterated in chronological order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(transparent)]
+pub struct PoolPairLongTermOrderKey([u8; 8]);
+impl_serializable_as_ref!(PoolPairLongTermOrderKey);
+impl From<u64> for PoolPairLongTermOrderKey {
+	fn from(value: u64) -> Self {
+		Self(value.to_be_bytes())
+	}
+}
+impl From<PoolPairLongTermOrderKey> for u64 {
+	fn from(value: PoolPairLongTermOrderKey) -> Self {
+		u64::from_be_bytes(value.0)
+	}
+}
+
+/// The long-term orders ending at a specific time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairLongTermOrderExpiry {
+	/// The combined sell rates of the orders ending at this time
+	pub sell_rates: [u128; 2],
+	/// `PoolPairLongTermOrders::proceeds_per_rate` as of this time, set once the time has passed.
+	pub proceeds_per_rate: [[u8; 32]; 2],
+}
+impl_serializable_as_ref!(PoolPairLongTermOrderExpiry);
+impl PoolPairLongTermOrderExpiry {
+	#[inline]
+	pub fn proceeds_per_rate(&self, index: usize) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.proceeds_per_rate[index]))
+	}
+}
+
+const LONG_TERM_ORDER_EXPIRIES_NAMESPACE: &str = "lto_exp";
+/// Keyed by the time the orders end at
+pub fn get_long_term_order_expiries_store() -> StoredMap<PoolPairLongTermOrderKey, PoolPairLongTermOrderExpiry> {
+	StoredMap::new(LONG_TERM_ORDER_EXPIRIES_NAMESPACE.as_ref())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolPairLongTermOrder {
+	pub owner: String,
+	/// If true, the right denom of the canonical pair is being sold
+	pub inverse: bool,
+	/// The amount sold per second
+	pub sell_rate: u128,
+	/// Unix timestamp (in seconds) of when the order was placed
+	pub start_time: u64,
+	/// Unix timestamp (in seconds) of when the order ends
+	pub end_time: u64,
+	/// `PoolPairLongTermOrders::proceeds_per_rate` as of the last time proceeds were withdrawn
+	pub proceeds_per_rate_checkpoint: [u8; 32],
+	/// The proceeds which have been withdrawn so far
+	pub withdrawn_proceeds: u128,
+}
+impl_serializable_borsh!(PoolPairLongTermOrder);
+impl PoolPairLongTermOrder {
+	#[inline]
+	pub fn proceeds_per_rate_checkpoint(&self) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.proceeds_per_rate_checkpoint))
+	}
+}
+
+const LONG_TERM_ORDERS_BY_ID_NAMESPACE: &str = "lto_ord";
+pub fn get_long_term_orders_store() -> StoredMap<PoolPairLongTermOrderKey, PoolPairLongTermOrder> {
+	StoredMap::new(LONG_TERM_ORDERS_BY_ID_NAMESPACE.as_ref())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct PoolPairLongTermOrderIds {
+	pub order_ids: Vec<u64>,
+}
+impl_serializable_borsh!(PoolPairLongTermOrderIds);
+
+const LONG_TERM_ORDER_OWNERS_NAMESPACE: &str = "lto_own";
+/// The IDs of each account's long-term orders, in the order they were placed.
+pub fn get_long_term_order_owners_store() -> StoredMap<SeiCanonicalAddr, PoolPairLongTermOrderIds> {
+	StoredMap::new(LONG_TERM_ORDER_OWNERS_NAMESPACE.as_ref())
+}
+
+pub const FEE_GROWTH_NAMESPACE: &str = "fee_growth";
+/// The LP fees collected from swaps per share while position tracking is enabled. Arrays are in the same order as the
+/// canonical pair, and are converted verbatim from `Decimal256`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairFeeGrowth {
+	pub per_share: [[u8; 32]; 2],
+}
+impl_serializable_as_ref!(PoolPairFeeGrowth);
+impl StoredItem for PoolPairFeeGrowth {
+	fn namespace() -> &'static [u8] {
+		FEE_GROWTH_NAMESPACE.as_bytes()
+	}
+}
+impl PoolPairFeeGrowth {
+	/// Returns the stored fee growth, or 0 if none has been recorded.
+	pub fn load_or_zeroed() -> Result<Self, StdError> {
+		Ok(Self::load()?
+			.map(|growth| growth.into_inner())
+			.unwrap_or_else(Zeroable::zeroed))
+	}
+	#[inline]
+	pub fn per_share(&self, index: usize) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.per_share[index]))
+	}
+	#[inline]
+	pub fn set_per_share(&mut self, index: usize, value: Decimal256) {
+		self.per_share[index] = value.atomics().to_le_bytes();
+	}
+}
+
+/// What an address has deposited, recorded while position tracking is enabled. Arrays are in the same order as the
+/// canonical pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PoolPairPosition {
+	/// The shares minted for the deposits, minus those withdrawn since
+	pub shares: u128,
+	/// The amounts deposited for the shares, reduced proportionally on withdrawals
+	pub cost_basis: [u128; 2],
+	/// The estimated fees earned by the shares up until the checkpoint
+	pub fees_earned: [u128; 2],
+	/// `PoolPairFeeGrowth::per_share` as of the last time the position changed
+	pub fee_growth_checkpoint: [[u8; 32]; 2],
+}
+impl_serializable_as_ref!(PoolPairPosition);
+impl PoolPairPosition {
+	#[inline]
+	pub fn fee_growth_checkpoint(&self, index: usize) -> Decimal256 {
+		Decimal256::new(Uint256::from_le_bytes(self.fee_growth_checkpoint[index]))
+	}
+}
+
+const POSITIONS_NAMESPACE: &str = "positions";
+pub fn get_positions_store() -> StoredMap<SeiCanonicalAddr, PoolPairPosition> {
+	StoredMap::new(POSITIONS_NAMESPACE.as_ref())
+}
+
+pub(crate) const VOLUME_STATS_ALL_TIME_NAMESPACE: &[u8] = "volA".as_bytes();
 const VOLUME_STATS_HOURLY_NAMESPACE: &[u8] = "volH".as_bytes();
 const VOLUME_STATS_DAILY_NAMESPACE: &[u8] = "volD".as_bytes();
 
@@ -0,0 +1,129 @@
+use cosmwasm_std::{
+	from_json,
+	testing::{mock_env, mock_info},
+	Addr,
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_swaps_common::{data_types::pending_admin::PendingAdminTransferJsonable, error::CrownfiSwapsCommonError};
+
+use crate::{
+	contract::{execute, query},
+	error::PoolPairContractError,
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
+	tests::{deps, init, AddressFactory, PoolPairConfig},
+};
+
+#[test]
+fn only_admin_can_propose() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::ProposeAdmin {
+			admin: Addr::unchecked(&sender),
+		},
+	);
+	assert!(matches!(
+		res,
+		Err(PoolPairContractError::SwapsCommonError(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+}
+
+#[test]
+fn transfer_requires_acceptance() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+
+	let new_admin = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::ProposeAdmin {
+			admin: Addr::unchecked(&new_admin),
+		},
+	)
+	.unwrap();
+
+	// Proposing doesn't change anything yet
+	let config = PoolPairConfig::load().unwrap().unwrap();
+	assert_eq!(config.admin, Addr::unchecked(AddressFactory::ADMIN).try_into().unwrap());
+	let pending: Option<PendingAdminTransferJsonable> =
+		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::PendingAdmin).unwrap()).unwrap();
+	assert_eq!(
+		pending.map(|pending| pending.new_admin),
+		Some(Addr::unchecked(&new_admin))
+	);
+
+	// Only the proposed admin can accept
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::AcceptAdmin {},
+	);
+	assert!(matches!(
+		res,
+		Err(PoolPairContractError::SwapsCommonError(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&new_admin, &[]),
+		PoolPairExecuteMsg::AcceptAdmin {},
+	)
+	.unwrap();
+	let config = PoolPairConfig::load().unwrap().unwrap();
+	assert_eq!(config.admin, Addr::unchecked(&new_admin).try_into().unwrap());
+	let pending: Option<PendingAdminTransferJsonable> =
+		from_json(query(deps.as_ref(), env, PoolPairQueryMsg::PendingAdmin).unwrap()).unwrap();
+	assert_eq!(pending, None);
+}
+
+#[test]
+fn cancelled_transfer_cannot_be_accepted() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+
+	let new_admin = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::ProposeAdmin {
+			admin: Addr::unchecked(&new_admin),
+		},
+	)
+	.unwrap();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::CancelAdminTransfer {},
+	)
+	.unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&new_admin, &[]),
+		PoolPairExecuteMsg::AcceptAdmin {},
+	);
+	assert_eq!(
+		res,
+		Err(PoolPairContractError::SwapsCommonError(
+			CrownfiSwapsCommonError::NoPendingAdmin
+		))
+	);
+}
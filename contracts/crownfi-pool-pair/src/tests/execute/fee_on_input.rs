@@ -0,0 +1,135 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	BankMsg, Decimal, SubMsg,
+};
+
+use crate::{
+	contract::{execute, query},
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQueryReservesResponse},
+	tests::{
+		deps, init,
+		pool::{PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult},
+		AddressFactory, TestDeps, LEFT_TOKEN_AMT, PAIR_DENOMS,
+	},
+};
+
+fn enable_fee_on_input(deps: &mut TestDeps) {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: None,
+			position_tracking: None,
+			fee_on_input: Some(true),
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+}
+
+#[test]
+fn fees_are_taken_from_the_offer() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_fee_on_input(&mut deps);
+
+	let simulate_swap: PoolPairCalcSwapResult = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: None,
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert!(simulate_swap.fee_on_input);
+
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&sender, &[coin(10000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: Some(Decimal::bps(1000)),
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	)
+	.unwrap();
+	// 100abc of fees are taken before the remaining 9900abc is swapped, the maker's half is paid in abc.
+	assert_eq!(
+		res.messages,
+		vec![
+			SubMsg::new(BankMsg::Send {
+				to_address: AddressFactory::FEE_RECEIVER.into(),
+				amount: vec![coin(50, PAIR_DENOMS[0])]
+			}),
+			SubMsg::new(BankMsg::Send {
+				to_address: sender.clone(),
+				amount: vec![coin(4901, PAIR_DENOMS[1])]
+			})
+		]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "swap"),
+			attr("sender", &sender),
+			attr("receiver", &sender),
+			attr("in_coin", coin(10000, PAIR_DENOMS[0]).to_string()),
+			attr("out_coin", coin(4901, PAIR_DENOMS[1]).to_string()),
+			attr("spread_amount", "49"),
+			attr("total_fee_amount", "100"),
+			attr("maker_fee_amount", "50"),
+			attr("fee_denom", PAIR_DENOMS[0]),
+		]
+	);
+	assert_eq!(simulate_swap.result_amount.u128(), 4901);
+	assert_eq!(simulate_swap.maker_fee_amount.u128(), 50);
+
+	let reserves: PoolPairQueryReservesResponse =
+		from_json(query(deps.as_ref(), mock_env(), PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+	assert_eq!(
+		reserves.tracked,
+		[
+			coin(LEFT_TOKEN_AMT + 10000 - 50, PAIR_DENOMS[0]),
+			coin(495_099, PAIR_DENOMS[1])
+		]
+	);
+}
+
+#[test]
+fn naive_swap_takes_fees_from_the_offer() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_fee_on_input(&mut deps);
+
+	let simulate_naive_swap: PoolPairCalcNaiveSwapResult = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateNaiveSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: None,
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(simulate_naive_swap.result_amount.u128(), 4950);
+	assert_eq!(simulate_naive_swap.total_fee_amount.u128(), 100);
+	assert_eq!(simulate_naive_swap.maker_fee_amount.u128(), 50);
+	assert!(simulate_naive_swap.fee_on_input);
+}
@@ -0,0 +1,200 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+	to_json_binary, Addr, BankMsg, Binary, Reply, Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::{execute, reply, FLASH_SWAP_REPLY_ID},
+	error::PoolPairContractError,
+	msg::PoolPairExecuteMsg,
+	tests::{deps, init, AddressFactory, LEFT_TOKEN_AMT, PAIR_DENOMS, RIGHT_TOKEN_AMT},
+};
+
+fn flash_swap_reply() -> Reply {
+	Reply {
+		id: FLASH_SWAP_REPLY_ID,
+		result: SubMsgResult::Ok(SubMsgResponse {
+			events: vec![],
+			data: None,
+		}),
+	}
+}
+
+/// Borrows the left asset, and has the pool end up with the specified balances before the reply
+fn borrow_and_repay(amount: u128, balances_after: [u128; 2]) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::new(amount), Uint128::zero()],
+			receiver: None,
+			payload: Binary::default(),
+		},
+	)?;
+	deps.querier.update_balance(
+		env.contract.address.clone(),
+		vec![
+			coin(balances_after[0], PAIR_DENOMS[0]),
+			coin(balances_after[1], PAIR_DENOMS[1]),
+		],
+	);
+	reply(deps.as_mut(), env, flash_swap_reply())
+}
+
+#[test]
+fn lends_funds_to_receiver() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	let receiver = AddressFactory::random_address();
+	let payload = to_json_binary("do_arbitrage").unwrap();
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::new(10000), Uint128::zero()],
+			receiver: Some(Addr::unchecked(&receiver)),
+			payload: payload.clone(),
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::reply_on_success(
+			WasmMsg::Execute {
+				contract_addr: receiver,
+				msg: payload,
+				funds: vec![coin(10000, PAIR_DENOMS[0])]
+			},
+			FLASH_SWAP_REPLY_ID
+		)]
+	);
+}
+
+#[test]
+fn cannot_borrow_entire_reserves_or_nothing() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::zero(), Uint128::new(RIGHT_TOKEN_AMT)],
+			receiver: None,
+			payload: Binary::default(),
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::FlashSwapTooLarge));
+
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::zero(), Uint128::zero()],
+			receiver: None,
+			payload: Binary::default(),
+		},
+	);
+	assert_eq!(res, Err(CrownfiSwapsCommonError::PayoutIsZero.into()));
+}
+
+#[test]
+fn pool_is_locked_until_repaid() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::new(10000), Uint128::zero()],
+			receiver: None,
+			payload: Binary::default(),
+		},
+	)
+	.unwrap();
+
+	// The receiver trying to swap its loan back in, while the pool balances haven't been settled.
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(10000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+
+	deps.querier.update_balance(
+		env.contract.address.clone(),
+		vec![
+			coin(LEFT_TOKEN_AMT + 102, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT, PAIR_DENOMS[1]),
+		],
+	);
+	reply(deps.as_mut(), env.clone(), flash_swap_reply()).unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[coin(10000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	);
+	assert!(res.is_ok());
+}
+
+#[test]
+fn repayment_must_cover_fees() {
+	// 10000 borrowed, 1% fee is 101.01
+	let res = borrow_and_repay(10000, [LEFT_TOKEN_AMT + 101, RIGHT_TOKEN_AMT]);
+	assert_eq!(res, Err(PoolPairContractError::FlashSwapNotRepaid));
+
+	let res = borrow_and_repay(10000, [LEFT_TOKEN_AMT + 102, RIGHT_TOKEN_AMT]).unwrap();
+	// Half of the fee, as configured in `init`
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: AddressFactory::FEE_RECEIVER.into(),
+			amount: vec![coin(50, PAIR_DENOMS[0])]
+		})]
+	);
+}
+
+#[test]
+fn can_be_repaid_with_the_other_asset() {
+	let res = borrow_and_repay(10000, [LEFT_TOKEN_AMT - 10000, RIGHT_TOKEN_AMT + 5101]);
+	assert_eq!(res, Err(PoolPairContractError::FlashSwapNotRepaid));
+
+	let res = borrow_and_repay(10000, [LEFT_TOKEN_AMT - 10000, RIGHT_TOKEN_AMT + 5102]);
+	assert!(res.is_ok());
+}
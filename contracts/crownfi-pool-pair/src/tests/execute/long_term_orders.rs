@@ -0,0 +1,398 @@
+use cosmwasm_std::{
+	coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, BankMsg, Env, StdError, SubMsg, Timestamp,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{execute, query},
+	error::PoolPairContractError,
+	msg::{
+		PoolPairExecuteMsg, PoolPairQueryLongTermOrderResponse, PoolPairQueryLongTermOrdersResponse, PoolPairQueryMsg,
+		PoolPairQueryReservesResponse,
+	},
+	state::get_long_term_order_expiries_store,
+	tests::{deps, init, AddressFactory, TestDeps, LEFT_TOKEN_AMT, PAIR_DENOMS, RIGHT_TOKEN_AMT},
+};
+
+/// Aligned to the hour, so orders don't have to be rounded up
+const START: u64 = 1_571_799_600;
+const DURATION: u64 = 36_000;
+
+fn env_at(seconds: u64) -> Env {
+	let mut env = mock_env();
+	env.block.time = Timestamp::from_seconds(seconds);
+	env
+}
+
+fn enable_long_term_orders(deps: &mut TestDeps) {
+	execute(
+		deps.as_mut(),
+		env_at(START),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: Some(true),
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+}
+
+fn place_order(deps: &mut TestDeps, owner: &str, offer: u128, denom: &str) {
+	let res = execute(
+		deps.as_mut(),
+		env_at(START),
+		mock_info(owner, &[coin(offer, denom)]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: DURATION },
+	)
+	.unwrap();
+	assert!(res.messages.is_empty());
+}
+
+fn order_progress(deps: &TestDeps, now: u64, order_id: u64) -> Option<PoolPairQueryLongTermOrderResponse> {
+	from_json(query(deps.as_ref(), env_at(now), PoolPairQueryMsg::LongTermOrder { order_id }).unwrap()).unwrap()
+}
+
+fn long_term_orders(deps: &TestDeps, now: u64) -> PoolPairQueryLongTermOrdersResponse {
+	from_json(query(deps.as_ref(), env_at(now), PoolPairQueryMsg::LongTermOrders).unwrap()).unwrap()
+}
+
+fn reserves(deps: &TestDeps, now: u64) -> PoolPairQueryReservesResponse {
+	from_json(query(deps.as_ref(), env_at(now), PoolPairQueryMsg::Reserves).unwrap()).unwrap()
+}
+
+#[test]
+fn must_be_enabled() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let res = execute(
+		deps.as_mut(),
+		env_at(START),
+		mock_info(&AddressFactory::random_address(), &[coin(72_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: DURATION },
+	);
+	assert_eq!(res, Err(PoolPairContractError::LongTermOrdersDisabled));
+}
+
+#[test]
+fn invalid_orders_are_rejected() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	for duration in [0, 365 * 24 * 3600 + 1] {
+		let res = execute(
+			deps.as_mut(),
+			env_at(START),
+			mock_info(&owner, &[coin(72_000, PAIR_DENOMS[0])]),
+			PoolPairExecuteMsg::PlaceLongTermOrder { duration },
+		);
+		assert_eq!(res, Err(PoolPairContractError::InvalidLongTermOrderDuration));
+	}
+	let res = execute(
+		deps.as_mut(),
+		env_at(START),
+		mock_info(&owner, &[coin(DURATION as u128 - 1, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: DURATION },
+	);
+	assert_eq!(res, Err(PoolPairContractError::LongTermOrderTooSmall));
+}
+
+#[test]
+fn end_time_is_rounded_up_and_remainder_refunded() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env_at(START + 1),
+		mock_info(&owner, &[coin(72_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder {
+			duration: DURATION - 10,
+		},
+	)
+	.unwrap();
+	// 72000 / 35999 seconds == 2 per second
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: owner.clone(),
+			amount: vec![coin(2, PAIR_DENOMS[0])],
+		})]
+	);
+	let order = order_progress(&deps, START + 1, 1).unwrap();
+	assert_eq!(order.owner, Addr::unchecked(owner));
+	assert_eq!(order.end_time, START + DURATION);
+	assert_eq!(order.sell_rate.u128(), 2);
+	assert_eq!(order.remaining.u128(), 71_998);
+	assert_eq!(
+		long_term_orders(&deps, START + 1).escrow,
+		[coin(71_998, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]
+	);
+}
+
+#[test]
+fn opposing_orders_are_netted() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	// Both sides are selling exactly what the other is buying at the pool's price, so the pool is never swapped
+	// with and only collects the fees.
+	let alice = AddressFactory::random_address();
+	let bob = AddressFactory::random_address();
+	place_order(&mut deps, &alice, 72_000, PAIR_DENOMS[0]);
+	place_order(&mut deps, &bob, 36_000, PAIR_DENOMS[1]);
+
+	let halfway = START + DURATION / 2;
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&alice, &[]),
+		PoolPairExecuteMsg::WithdrawLongTermOrder { order_id: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: alice.clone(),
+			amount: vec![coin(17_820, PAIR_DENOMS[1])],
+		})]
+	);
+	let order = order_progress(&deps, halfway, 1).unwrap();
+	assert_eq!(order.sold.u128(), 36_000);
+	assert_eq!(order.remaining.u128(), 36_000);
+	assert_eq!(order.claimable_proceeds.u128(), 0);
+	assert_eq!(order.withdrawn_proceeds.u128(), 17_820);
+
+	let end = START + DURATION;
+	for (owner, order_id, proceeds) in [
+		(&alice, 1, coin(17_820, PAIR_DENOMS[1])),
+		(&bob, 2, coin(71_280, PAIR_DENOMS[0])),
+	] {
+		let res = execute(
+			deps.as_mut(),
+			env_at(end),
+			mock_info(owner, &[]),
+			PoolPairExecuteMsg::WithdrawLongTermOrder { order_id },
+		)
+		.unwrap();
+		assert_eq!(
+			res.messages,
+			vec![SubMsg::new(BankMsg::Send {
+				to_address: owner.clone(),
+				amount: vec![proceeds],
+			})]
+		);
+		assert_eq!(order_progress(&deps, end, order_id), None);
+	}
+
+	assert_eq!(
+		reserves(&deps, end).tracked,
+		[
+			coin(LEFT_TOKEN_AMT + 720, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT + 360, PAIR_DENOMS[1])
+		]
+	);
+	let orders = long_term_orders(&deps, end);
+	assert_eq!(orders.sell_rates, [coin(0, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+	assert_eq!(orders.escrow, [coin(0, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+}
+
+#[test]
+fn cancelling_refunds_unsold_and_proceeds() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	place_order(&mut deps, &owner, 72_000, PAIR_DENOMS[0]);
+
+	let halfway = START + DURATION / 2;
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::CancelLongTermOrder { order_id: 1 },
+	);
+	assert!(matches!(
+		res,
+		Err(PoolPairContractError::SwapsCommonError(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+
+	let order = order_progress(&deps, halfway, 1).unwrap();
+	assert_eq!(order.sold.u128(), 36_000);
+	assert!(!order.claimable_proceeds.is_zero());
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&owner, &[]),
+		PoolPairExecuteMsg::CancelLongTermOrder { order_id: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: owner,
+			amount: vec![
+				coin(36_000, PAIR_DENOMS[0]),
+				coin(order.claimable_proceeds.u128(), PAIR_DENOMS[1])
+			],
+		})]
+	);
+	assert_eq!(order_progress(&deps, halfway, 1), None);
+
+	// Nothing is sold after the order is cancelled
+	assert_eq!(
+		reserves(&deps, START + DURATION).tracked[0],
+		coin(LEFT_TOKEN_AMT + 36_000, PAIR_DENOMS[0])
+	);
+	let orders = long_term_orders(&deps, START + DURATION);
+	assert_eq!(orders.sell_rates, [coin(0, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+	assert_eq!(orders.escrow[0], coin(0, PAIR_DENOMS[0]));
+}
+
+#[test]
+fn escrow_is_not_skimmed_or_synced() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	place_order(&mut deps, &owner, 72_000, PAIR_DENOMS[0]);
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![
+			coin(LEFT_TOKEN_AMT + 72_000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT, PAIR_DENOMS[1]),
+		],
+	);
+
+	let halfway = START + DURATION / 2;
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::Skim { receiver: None },
+	);
+	assert_eq!(res, Err(CrownfiSwapsCommonError::PayoutIsZero.into()));
+
+	let reserves_before = reserves(&deps, halfway).tracked;
+	execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::Sync {},
+	)
+	.unwrap();
+	assert_eq!(reserves(&deps, halfway).tracked, reserves_before);
+}
+
+#[test]
+fn orders_can_be_cancelled_when_settlement_fails() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	place_order(&mut deps, &owner, 72_000, PAIR_DENOMS[0]);
+	// Without its expiry, the order can't be settled past the point it was placed.
+	get_long_term_order_expiries_store().remove(&(START + DURATION).into());
+
+	let halfway = START + DURATION / 2;
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&AddressFactory::random_address(), &[coin(1000, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	);
+	assert!(matches!(
+		res,
+		Err(PoolPairContractError::Std(StdError::NotFound { .. }))
+	));
+
+	// Nothing was sold as of the last settlement, so everything is refunded.
+	let res = execute(
+		deps.as_mut(),
+		env_at(halfway),
+		mock_info(&owner, &[]),
+		PoolPairExecuteMsg::CancelLongTermOrder { order_id: 1 },
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: owner,
+			amount: vec![coin(72_000, PAIR_DENOMS[0])],
+		})]
+	);
+	let orders = long_term_orders(&deps, halfway);
+	assert_eq!(orders.escrow, [coin(0, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+	assert_eq!(
+		reserves(&deps, halfway).tracked,
+		[
+			coin(LEFT_TOKEN_AMT, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT, PAIR_DENOMS[1])
+		]
+	);
+}
+
+#[test]
+fn settlement_is_frozen_while_swaps_are_paused() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_long_term_orders(&mut deps);
+
+	let owner = AddressFactory::random_address();
+	place_order(&mut deps, &owner, 72_000, PAIR_DENOMS[0]);
+	let set_paused = |deps: &mut TestDeps, now: u64, swaps: bool| {
+		execute(
+			deps.as_mut(),
+			env_at(now),
+			mock_info(AddressFactory::ADMIN, &[]),
+			PoolPairExecuteMsg::SetPaused {
+				swaps: Some(swaps),
+				deposits: None,
+			},
+		)
+		.unwrap();
+	};
+	set_paused(&mut deps, START, true);
+
+	let halfway = START + DURATION / 2;
+	let orders = long_term_orders(&deps, halfway);
+	assert_eq!(orders.last_settlement, START);
+	assert_eq!(orders.escrow, [coin(72_000, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+	assert_eq!(
+		reserves(&deps, halfway).tracked[0],
+		coin(LEFT_TOKEN_AMT, PAIR_DENOMS[0])
+	);
+
+	// Once unpaused, the orders continue from where they were frozen.
+	set_paused(&mut deps, halfway, false);
+	assert_eq!(order_progress(&deps, halfway, 1).unwrap().sold.u128(), 36_000);
+	assert_eq!(
+		reserves(&deps, halfway).tracked[0],
+		coin(LEFT_TOKEN_AMT + 36_000, PAIR_DENOMS[0])
+	);
+}
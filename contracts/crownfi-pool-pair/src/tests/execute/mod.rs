@@ -1,4 +1,14 @@
+mod admin_transfer;
+mod fee_on_input;
+mod flash_swap;
+mod long_term_orders;
 mod provide_liquidity;
+mod reconcile_supply;
+mod reentrancy;
+mod referral;
+mod set_paused;
+mod share_inflation;
+mod skim_and_sync;
 mod swap;
 mod update_config;
 mod withdraw_and_split_liquidity;
@@ -1,20 +1,22 @@
 use cosmwasm_std::{
-	attr, coin,
+	attr, coin, from_json,
 	testing::{mock_env, mock_info},
-	Addr, BankMsg, Binary, Decimal, SubMsg, Uint128, WasmMsg,
+	to_json_binary, Addr, BankMsg, Binary, ContractResult, Decimal, SubMsg, SystemError, SystemResult, Uint128,
+	WasmMsg, WasmQuery,
 };
+use crownfi_lp_incentives_contract::msg::{LpIncentivesExecuteMsg, LpIncentivesPoolInfo, LpIncentivesQueryMsg};
 use crownfi_swaps_common::error::CrownfiSwapsCommonError;
 use cw_utils::PaymentError;
 use sei_cosmwasm::SeiMsg;
 
 use crate::{
-	contract::execute,
+	contract::{execute, shares::tracked_share_supply, UNLOCK_REPLY_ID},
 	error::PoolPairContractError,
 	msg::PoolPairExecuteMsg,
 	tests::{
-		calc_shares, deps, init, pool_balance, AddressFactory, LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS, RIGHT_TOKEN_AMT,
+		calc_shares, deps, init, pool_balance, AddressFactory, TestDeps, LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS,
+		RIGHT_TOKEN_AMT,
 	},
-	workarounds::total_supply_workaround,
 };
 
 #[test]
@@ -26,6 +28,7 @@ fn slipage_tolerance_must_not_exceed_limit() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let env = mock_env();
@@ -40,6 +43,7 @@ fn slipage_tolerance_must_not_exceed_limit() {
 		slippage_tolerance: Some(Decimal::bps(1000)),
 		receiver: None,
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let env = mock_env();
@@ -58,6 +62,7 @@ fn must_be_given_both_tokens() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let env = mock_env();
@@ -103,6 +108,7 @@ fn shares_are_correctly_minted_and_sent() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let env = mock_env();
@@ -137,6 +143,7 @@ fn contract_is_aware_of_new_shares() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let assets = [coin(5000, PAIR_DENOMS[0]), coin(2510, PAIR_DENOMS[1])];
@@ -144,7 +151,7 @@ fn contract_is_aware_of_new_shares() {
 	let info = mock_info(&sender, &assets);
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_supply = total_supply_workaround(LP_TOKEN);
+	let total_supply = tracked_share_supply(LP_TOKEN);
 	let lp_amt = calc_shares([500, 250], pb.clone());
 	let expected_lp_amt = std::cmp::min(
 		Uint128::new(500).multiply_ratio(total_supply.u128() + lp_amt, pb[0] - 500),
@@ -178,6 +185,7 @@ fn returns_wasm_message_when_payload_provided() {
 		slippage_tolerance: None,
 		receiver: Some(Addr::unchecked(&receiver)),
 		receiver_payload: Some(cosmwasm_std::Binary(b"anana".into())),
+		auto_stake: false,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]);
@@ -191,11 +199,14 @@ fn returns_wasm_message_when_payload_provided() {
 			SubMsg::new(SeiMsg::MintTokens {
 				amount: coin(lp_amt, LP_TOKEN)
 			}),
-			SubMsg::new(WasmMsg::Execute {
-				contract_addr: receiver.into(),
-				msg: Binary(b"anana".into()),
-				funds: vec![coin(lp_amt, LP_TOKEN)]
-			})
+			SubMsg::reply_on_success(
+				WasmMsg::Execute {
+					contract_addr: receiver.into(),
+					msg: Binary(b"anana".into()),
+					funds: vec![coin(lp_amt, LP_TOKEN)]
+				},
+				UNLOCK_REPLY_ID
+			)
 		]
 	);
 }
@@ -211,15 +222,15 @@ fn events_emitted_correctly() {
 		slippage_tolerance: None,
 		receiver: Some(Addr::unchecked(&receiver)),
 		receiver_payload: None,
+		auto_stake: false,
 	};
 
 	let assets = [coin(5000, PAIR_DENOMS[0]), coin(2510, PAIR_DENOMS[1])];
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &assets);
-	let res = execute(deps.as_mut(), env, info, provide_liquidity_msg).unwrap();
-
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
 	let share = calc_shares([5000, 2510], pb);
+	let res = execute(deps.as_mut(), env, info, provide_liquidity_msg).unwrap();
 
 	assert_eq!(
 		res.attributes,
@@ -232,3 +243,151 @@ fn events_emitted_correctly() {
 		]
 	)
 }
+
+#[test]
+fn auto_stake_requires_staking_contract() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+
+	let provide_liquidity_msg = PoolPairExecuteMsg::ProvideLiquidity {
+		slippage_tolerance: None,
+		receiver: None,
+		receiver_payload: None,
+		auto_stake: true,
+	};
+	let sender = AddressFactory::random_address();
+	let info = mock_info(&sender, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]);
+	let res = execute(deps.as_mut(), env, info, provide_liquidity_msg);
+	assert_eq!(res, Err(PoolPairContractError::AutoStakeUnavailable));
+}
+
+/// Configures an LP staking contract which reports whether this pool has had any reward programs
+fn set_lp_staking(deps: &mut TestDeps, has_reward_programs: bool) -> String {
+	let lp_staking = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: Some(Addr::unchecked(&lp_staking)),
+			long_term_orders: None,
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+	let staking_contract = lp_staking.clone();
+	deps.querier.update_wasm(move |query| match query {
+		WasmQuery::Smart { contract_addr, msg } if *contract_addr == staking_contract => {
+			let LpIncentivesQueryMsg::Pool { lp_denom } = from_json(msg).unwrap() else {
+				panic!("unexpected query");
+			};
+			assert_eq!(lp_denom, LP_TOKEN);
+			let result = has_reward_programs.then(|| LpIncentivesPoolInfo {
+				lp_denom,
+				total_staked: Uint128::zero(),
+				programs: vec![],
+			});
+			SystemResult::Ok(ContractResult::Ok(to_json_binary(&result).unwrap()))
+		}
+		_ => SystemResult::Err(SystemError::Unknown {}),
+	});
+	lp_staking
+}
+
+#[test]
+fn auto_stake_sends_shares_to_staking_contract() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+	let lp_staking = set_lp_staking(&mut deps, true);
+
+	let receiver = AddressFactory::random_address();
+	let sender = AddressFactory::random_address();
+	let info = mock_info(&sender, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]);
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		info.clone(),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: Some(Addr::unchecked(&receiver)),
+			receiver_payload: Some(Binary(b"anana".into())),
+			auto_stake: true,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::AutoStakeWithPayload));
+
+	let res = execute(
+		deps.as_mut(),
+		env,
+		info,
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: Some(Addr::unchecked(&receiver)),
+			receiver_payload: None,
+			auto_stake: true,
+		},
+	)
+	.unwrap();
+	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
+	let lp_amt = calc_shares([50u128, 25u128], pb);
+	assert_eq!(
+		res.messages,
+		vec![
+			SubMsg::new(SeiMsg::MintTokens {
+				amount: coin(lp_amt, LP_TOKEN)
+			}),
+			SubMsg::new(WasmMsg::Execute {
+				contract_addr: lp_staking,
+				msg: to_json_binary(&LpIncentivesExecuteMsg::Stake {
+					receiver: Some(Addr::unchecked(receiver))
+				})
+				.unwrap(),
+				funds: vec![coin(lp_amt, LP_TOKEN)]
+			})
+		]
+	);
+}
+
+#[test]
+fn auto_stake_without_reward_programs_sends_shares_to_receiver() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	set_lp_staking(&mut deps, false);
+
+	let receiver = AddressFactory::random_address();
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&sender, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: Some(Addr::unchecked(&receiver)),
+			receiver_payload: None,
+			auto_stake: true,
+		},
+	)
+	.unwrap();
+	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
+	let lp_amt = calc_shares([50u128, 25u128], pb);
+	assert_eq!(
+		res.messages,
+		vec![
+			SubMsg::new(SeiMsg::MintTokens {
+				amount: coin(lp_amt, LP_TOKEN)
+			}),
+			SubMsg::new(BankMsg::Send {
+				to_address: receiver,
+				amount: vec![coin(lp_amt, LP_TOKEN)]
+			})
+		]
+	);
+}
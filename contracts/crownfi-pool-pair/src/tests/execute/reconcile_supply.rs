@@ -0,0 +1,66 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	Uint128,
+};
+
+use crate::{
+	contract::{execute, query, shares::tracked_share_supply},
+	error::PoolPairContractError,
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
+	tests::{deps, init, AddressFactory, LP_TOKEN},
+};
+
+#[test]
+fn fails_if_bank_supply_is_unavailable() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::ReconcileSupply {},
+	);
+	assert_eq!(res, Err(PoolPairContractError::ShareSupplyUnavailable));
+}
+
+#[test]
+fn corrects_tokens_burned_by_holders() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	// sqrt(1000000 * 500000)
+	assert_eq!(tracked_share_supply(LP_TOKEN).u128(), 707106);
+	// The admin holds everything but the 1000 locked shares, and burns 1000 of their own
+	deps.querier
+		.update_balance(AddressFactory::ADMIN, vec![coin(705106, LP_TOKEN)]);
+	deps.querier
+		.update_balance(env.contract.address.clone(), vec![coin(1000, LP_TOKEN)]);
+
+	// The bank's supply is used as soon as it's available...
+	let total_shares: Uint128 =
+		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::TotalShares).unwrap()).unwrap();
+	assert_eq!(total_shares.u128(), 706106);
+
+	// ...but the tracked supply (which other contracts read directly) must be reconciled.
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::ReconcileSupply {},
+	)
+	.unwrap();
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "reconcile_supply"),
+			attr("sender", sender),
+			attr("previous_supply", "707106"),
+			attr("supply", "706106"),
+		]
+	);
+	assert_eq!(tracked_share_supply(LP_TOKEN).u128(), 706106);
+}
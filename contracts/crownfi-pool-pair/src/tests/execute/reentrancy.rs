@@ -0,0 +1,151 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+	Addr, Binary, Reply, ReplyOn, SubMsgResponse, SubMsgResult,
+};
+
+use crate::{
+	contract::{execute, query, reply, UNLOCK_REPLY_ID},
+	error::PoolPairContractError,
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
+	tests::{deps, init, AddressFactory, LP_TOKEN, PAIR_DENOMS},
+};
+
+fn swap_msg(receiver_payload: Option<Binary>) -> PoolPairExecuteMsg {
+	PoolPairExecuteMsg::Swap {
+		expected_result: None,
+		slippage_tolerance: None,
+		receiver: None,
+		receiver_payload,
+	}
+}
+
+fn unlock_reply() -> Reply {
+	Reply {
+		id: UNLOCK_REPLY_ID,
+		result: SubMsgResult::Ok(SubMsgResponse {
+			events: vec![],
+			data: None,
+		}),
+	}
+}
+
+#[test]
+fn receiver_payload_locks_pool_until_complete() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]),
+		swap_msg(Some(Binary(b"anana".into()))),
+	)
+	.unwrap();
+	let last_msg = res.messages.last().unwrap();
+	assert_eq!(last_msg.id, UNLOCK_REPLY_ID);
+	assert_eq!(last_msg.reply_on, ReplyOn::Success);
+
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]),
+		swap_msg(None),
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+
+	reply(deps.as_mut(), env.clone(), unlock_reply()).unwrap();
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]),
+		swap_msg(None),
+	);
+	assert!(res.is_ok());
+}
+
+#[test]
+fn no_lock_without_receiver_payload() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]),
+		swap_msg(None),
+	)
+	.unwrap();
+	assert!(res.messages.iter().all(|msg| msg.reply_on == ReplyOn::Never));
+
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]),
+		swap_msg(None),
+	);
+	assert!(res.is_ok());
+}
+
+#[test]
+fn malicious_receiver_cannot_use_stale_reserves() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let receiver = AddressFactory::random_address();
+	// The refund has been calculated, but the receiver is being executed before anything else happens to the pool.
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[coin(500, LP_TOKEN)]),
+		PoolPairExecuteMsg::WithdrawLiquidity {
+			receiver: Some(Addr::unchecked(&receiver)),
+			receiver_payload: Some(Binary(b"anana".into())),
+			referral: None,
+		},
+	)
+	.unwrap();
+
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&receiver, &[coin(500, PAIR_DENOMS[0])]),
+		swap_msg(None),
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&receiver, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			auto_stake: false,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+
+	let res = query(
+		deps.as_ref(),
+		env.clone(),
+		PoolPairQueryMsg::SimulateSwap {
+			offer: coin(500, PAIR_DENOMS[0]),
+			referral_fee_bps: None,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+	let res = query(
+		deps.as_ref(),
+		env.clone(),
+		PoolPairQueryMsg::ShareValue { amount: 500u128.into() },
+	);
+	assert_eq!(res, Err(PoolPairContractError::PoolLocked));
+	// Queries which don't depend on the reserves still work
+	assert!(query(deps.as_ref(), env, PoolPairQueryMsg::Config {}).is_ok());
+}
@@ -0,0 +1,201 @@
+use cosmwasm_std::{
+	attr, coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, BankMsg, Decimal, Response, SubMsg,
+};
+use sei_cosmwasm::SeiMsg;
+
+use crate::{
+	contract::{execute, query},
+	error::PoolPairContractError,
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQueryReservesResponse, PoolPairSwapReferral},
+	tests::{
+		deps, init,
+		pool::{PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult},
+		AddressFactory, TestDeps, LEFT_TOKEN_AMT, PAIR_DENOMS, RIGHT_TOKEN_AMT,
+	},
+};
+
+fn set_max_referral_fee_bps(deps: &mut TestDeps, max_referral_fee_bps: u16) {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: None,
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps: Some(max_referral_fee_bps),
+		},
+	)
+	.unwrap();
+}
+
+fn swap_with_referral(
+	deps: &mut TestDeps,
+	sender: &str,
+	referrer: &str,
+	fee_bps: u16,
+) -> Result<Response<SeiMsg>, PoolPairContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[coin(10000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: Some(Decimal::bps(1000)),
+			receiver: None,
+			receiver_payload: None,
+			referral: Some(PoolPairSwapReferral {
+				address: Addr::unchecked(referrer),
+				fee_bps,
+			}),
+		},
+	)
+}
+
+#[test]
+fn referral_fee_is_capped() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let sender = AddressFactory::random_address();
+	let referrer = AddressFactory::random_address();
+
+	// Nothing is allowed until the factory says otherwise
+	assert_eq!(
+		swap_with_referral(&mut deps, &sender, &referrer, 1),
+		Err(PoolPairContractError::ReferralFeeTooHigh(0))
+	);
+
+	set_max_referral_fee_bps(&mut deps, 100);
+	assert_eq!(
+		swap_with_referral(&mut deps, &sender, &referrer, 101),
+		Err(PoolPairContractError::ReferralFeeTooHigh(100))
+	);
+	assert_eq!(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: Some(101),
+			},
+		),
+		Err(PoolPairContractError::ReferralFeeTooHigh(100))
+	);
+	swap_with_referral(&mut deps, &sender, &referrer, 100).unwrap();
+}
+
+#[test]
+fn referral_fee_is_taken_from_the_result() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	set_max_referral_fee_bps(&mut deps, 100);
+
+	let simulate_swap: PoolPairCalcSwapResult = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: Some(30),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	// 0.3% of the 4900cba left after the swap fee
+	assert_eq!(simulate_swap.result_amount.u128(), 4886);
+	assert_eq!(simulate_swap.referral_fee_amount.u128(), 14);
+	assert_eq!(simulate_swap.total_fee_amount.u128(), 50);
+
+	let sender = AddressFactory::random_address();
+	let referrer = AddressFactory::random_address();
+	let res = swap_with_referral(&mut deps, &sender, &referrer, 30).unwrap();
+	assert_eq!(
+		res.messages,
+		vec![
+			SubMsg::new(BankMsg::Send {
+				to_address: AddressFactory::FEE_RECEIVER.into(),
+				amount: vec![coin(24, PAIR_DENOMS[1])]
+			}),
+			SubMsg::new(BankMsg::Send {
+				to_address: referrer.clone(),
+				amount: vec![coin(14, PAIR_DENOMS[1])]
+			}),
+			SubMsg::new(BankMsg::Send {
+				to_address: sender.clone(),
+				amount: vec![coin(4886, PAIR_DENOMS[1])]
+			})
+		]
+	);
+	assert_eq!(
+		res.attributes,
+		vec![
+			attr("action", "swap"),
+			attr("sender", &sender),
+			attr("receiver", &sender),
+			attr("in_coin", coin(10000, PAIR_DENOMS[0]).to_string()),
+			attr("out_coin", coin(4886, PAIR_DENOMS[1]).to_string()),
+			attr("spread_amount", "50"),
+			attr("total_fee_amount", "50"),
+			attr("maker_fee_amount", "24"),
+			attr("fee_denom", PAIR_DENOMS[1]),
+			attr("referrer", &referrer),
+			attr("referral_fee_amount", "14"),
+		]
+	);
+
+	// The liquidity providers end up with the same as they would without a referral
+	let reserves: PoolPairQueryReservesResponse =
+		from_json(query(deps.as_ref(), mock_env(), PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+	assert_eq!(
+		reserves.tracked,
+		[
+			coin(LEFT_TOKEN_AMT + 10000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT - 4900 - 24, PAIR_DENOMS[1])
+		]
+	);
+}
+
+#[test]
+fn zero_referral_fee_sends_nothing_to_the_referrer() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let sender = AddressFactory::random_address();
+	let referrer = AddressFactory::random_address();
+	let res = swap_with_referral(&mut deps, &sender, &referrer, 0).unwrap();
+	// Just the maker fee and the result
+	assert_eq!(res.messages.len(), 2);
+	assert!(res.attributes.contains(&attr("referrer", &referrer)));
+	assert!(res.attributes.contains(&attr("referral_fee_amount", "0")));
+}
+
+#[test]
+fn naive_swap_includes_referral_fee() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	set_max_referral_fee_bps(&mut deps, 100);
+
+	let simulate_naive_swap: PoolPairCalcNaiveSwapResult = from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateNaiveSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: Some(30),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(simulate_naive_swap.result_amount.u128(), 4936);
+	assert_eq!(simulate_naive_swap.referral_fee_amount.u128(), 14);
+	assert_eq!(simulate_naive_swap.total_fee_amount.u128(), 50);
+}
@@ -0,0 +1,129 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+};
+use crownfi_cw_common::storage::item::StoredItem;
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::execute,
+	error::PoolPairContractError,
+	msg::PoolPairExecuteMsg,
+	tests::{deps, init, AddressFactory, PoolPairConfig, PoolPairConfigFlags, LP_TOKEN, PAIR_DENOMS},
+};
+
+#[test]
+fn sender_must_be_admin() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let info = mock_info(&AddressFactory::random_address(), &[]);
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		info,
+		PoolPairExecuteMsg::SetPaused {
+			swaps: Some(true),
+			deposits: Some(true),
+		},
+	);
+	assert!(matches!(
+		res,
+		Err(PoolPairContractError::SwapsCommonError(
+			CrownfiSwapsCommonError::Unauthorized(_)
+		))
+	));
+}
+
+#[test]
+fn paused_actions_are_rejected() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::SetPaused {
+			swaps: Some(true),
+			deposits: Some(true),
+		},
+	)
+	.unwrap();
+
+	let sender = AddressFactory::random_address();
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(5000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::SwapsPaused));
+
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[coin(50, PAIR_DENOMS[0]), coin(25, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			auto_stake: false,
+		},
+	);
+	assert_eq!(res, Err(PoolPairContractError::DepositsPaused));
+
+	// Withdrawals are always allowed
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(AddressFactory::ADMIN, &[coin(1000, LP_TOKEN)]),
+		PoolPairExecuteMsg::WithdrawLiquidity {
+			receiver: None,
+			receiver_payload: None,
+		},
+	);
+	assert!(res.is_ok());
+}
+
+#[test]
+fn unset_values_are_left_unchanged() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let env = mock_env();
+
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::SetPaused {
+			swaps: Some(true),
+			deposits: None,
+		},
+	)
+	.unwrap();
+	let config = PoolPairConfig::load().unwrap().unwrap();
+	assert!(config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED));
+	assert!(!config.flags.contains(PoolPairConfigFlags::DEPOSITS_PAUSED));
+
+	execute(
+		deps.as_mut(),
+		env,
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::SetPaused {
+			swaps: Some(false),
+			deposits: Some(true),
+		},
+	)
+	.unwrap();
+	let config = PoolPairConfig::load().unwrap().unwrap();
+	assert!(!config.flags.contains(PoolPairConfigFlags::SWAPS_PAUSED));
+	assert!(config.flags.contains(PoolPairConfigFlags::DEPOSITS_PAUSED));
+}
@@ -0,0 +1,147 @@
+use cosmwasm_std::{
+	coin,
+	testing::{mock_env, mock_info},
+	Addr, Uint128,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{
+		execute, instantiate,
+		pool::{balances_into_share_value, MINIMUM_INITIAL_SHARES},
+		shares::tracked_share_supply,
+	},
+	error::PoolPairContractError,
+	msg::{PoolPairExecuteMsg, PoolPairInstantiateMsg},
+	tests::{deps, init, AddressFactory, PoolPairConfigJsonable, PoolPairReserves, TestDeps, LP_TOKEN, PAIR_DENOMS},
+};
+
+fn init_with(deps: &mut TestDeps, shares_receiver: &str, amounts: [u128; 2]) -> Result<(), PoolPairContractError> {
+	let msg = PoolPairInstantiateMsg {
+		shares_receiver: Addr::unchecked(shares_receiver),
+		config: PoolPairConfigJsonable {
+			admin: Addr::unchecked(AddressFactory::ADMIN),
+			inverse: false,
+			endorsed: true,
+			swaps_paused: false,
+			deposits_paused: false,
+			long_term_orders: false,
+			position_tracking: false,
+			fee_on_input: false,
+			max_referral_fee_bps: 0,
+			fee_receiver: Addr::unchecked(AddressFactory::FEE_RECEIVER),
+			total_fee_bps: 100,
+			maker_fee_bps: 50,
+		},
+		lp_staking: None,
+	};
+	let env = mock_env();
+	let funds = [coin(amounts[0], PAIR_DENOMS[0]), coin(amounts[1], PAIR_DENOMS[1])];
+	instantiate(deps.as_mut(), env.clone(), mock_info(shares_receiver, &funds), msg)?;
+	deps.querier.update_balance(env.contract.address, funds.into());
+	Ok(())
+}
+
+/// Sends tokens to the pool and has them counted towards its reserves
+fn donate_and_sync(deps: &mut TestDeps, amounts: [u128; 2]) {
+	let reserves = PoolPairReserves::load_non_empty().unwrap().amounts;
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![
+			coin(reserves[0] + amounts[0], PAIR_DENOMS[0]),
+			coin(reserves[1] + amounts[1], PAIR_DENOMS[1]),
+		],
+	);
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::Sync {},
+	)
+	.unwrap();
+}
+
+fn provide(deps: &mut TestDeps, amounts: [u128; 2]) -> Result<(), PoolPairContractError> {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(
+			&AddressFactory::random_address(),
+			&[coin(amounts[0], PAIR_DENOMS[0]), coin(amounts[1], PAIR_DENOMS[1])],
+		),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			auto_stake: false,
+		},
+	)?;
+	Ok(())
+}
+
+fn share_value(shares: u128) -> [u128; 2] {
+	let reserves = PoolPairReserves::load_non_empty().unwrap().amounts;
+	balances_into_share_value(
+		Uint128::new(shares),
+		tracked_share_supply(LP_TOKEN),
+		[coin(reserves[0], PAIR_DENOMS[0]), coin(reserves[1], PAIR_DENOMS[1])],
+	)
+	.map(|coin| coin.amount.u128())
+}
+
+#[test]
+fn initial_shares_must_exceed_the_locked_amount() {
+	let mut deps = deps(&[]);
+	// sqrt(1000 * 1000) shares would all be locked
+	let res = init_with(&mut deps, AddressFactory::ADMIN, [1000, 1000]);
+	assert_eq!(res, Err(PoolPairContractError::MinimumSharesAmountError));
+
+	init_with(&mut deps, AddressFactory::ADMIN, [1001, 1001]).unwrap();
+	assert_eq!(tracked_share_supply(LP_TOKEN).u128(), 1001);
+}
+
+#[test]
+fn pool_cannot_be_fully_drained() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let admin_shares = tracked_share_supply(LP_TOKEN) - MINIMUM_INITIAL_SHARES;
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[coin(admin_shares.u128(), LP_TOKEN)]),
+		PoolPairExecuteMsg::WithdrawLiquidity {
+			receiver: None,
+			receiver_payload: None,
+		},
+	)
+	.unwrap();
+	assert_eq!(tracked_share_supply(LP_TOKEN), MINIMUM_INITIAL_SHARES);
+	// 1000 / 707106 of the pool is left behind
+	assert_eq!(PoolPairReserves::load_non_empty().unwrap().amounts, [1415, 708]);
+
+	// Donations to a drained pool only benefit the locked shares, so a deposit which would be rounded down to nothing
+	// is rejected rather than being given to them.
+	donate_and_sync(&mut deps, [1_000_000_000, 500_000_000]);
+	assert_eq!(share_value(1000), [1_000_001_415, 500_000_708]);
+	let res = provide(&mut deps, [1000, 500]);
+	assert_eq!(res, Err(CrownfiSwapsCommonError::PayoutIsZero.into()));
+}
+
+#[test]
+fn donation_inflation_is_unprofitable() {
+	let mut deps = deps(&[]);
+	let attacker = AddressFactory::random_address();
+	// The attacker creates the pool with the smallest possible deposit, receiving only 1 share.
+	init_with(&mut deps, &attacker, [1001, 1001]).unwrap();
+	assert_eq!(share_value(1), [1, 1]);
+
+	// The attacker inflates the value of a share...
+	donate_and_sync(&mut deps, [1_000_000, 1_000_000]);
+	// ...which rounds down the shares the victim receives, 10000 * 1001 / 1001001 = 9.999
+	provide(&mut deps, [10000, 10000]).unwrap();
+	assert_eq!(tracked_share_supply(LP_TOKEN).u128(), 1001 + 9);
+
+	// But since most of the donation went to the locked shares, the attacker can't even get their deposit back.
+	assert_eq!(share_value(1), [1000, 1000]);
+}
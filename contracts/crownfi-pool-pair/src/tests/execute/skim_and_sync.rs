@@ -0,0 +1,106 @@
+use cosmwasm_std::{
+	coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, BankMsg, SubMsg,
+};
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
+
+use crate::{
+	contract::{execute, query, shares::tracked_share_supply},
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
+	tests::{
+		deps, init, inner_share_in_assets, pool::PoolPairCalcSwapResult, share_in_assets, AddressFactory, TestDeps,
+		LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS, RIGHT_TOKEN_AMT,
+	},
+};
+
+/// Sends tokens to the pool without using any of its messages
+fn donate(deps: &mut TestDeps, amounts: [u128; 2]) {
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![
+			coin(LEFT_TOKEN_AMT + amounts[0], PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT + amounts[1], PAIR_DENOMS[1]),
+		],
+	);
+}
+
+fn simulate_swap(deps: &TestDeps) -> PoolPairCalcSwapResult {
+	from_json(
+		query(
+			deps.as_ref(),
+			mock_env(),
+			PoolPairQueryMsg::SimulateSwap {
+				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: None,
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap()
+}
+
+#[test]
+fn donations_are_ignored_until_synced() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let swap_result = simulate_swap(&deps);
+	let share_value = share_in_assets(deps.as_ref(), 1000);
+	donate(&mut deps, [100000, 0]);
+	assert_eq!(simulate_swap(&deps), swap_result);
+	assert_eq!(share_in_assets(deps.as_ref(), 1000), share_value);
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::Sync {},
+	)
+	.unwrap();
+	assert_ne!(simulate_swap(&deps), swap_result);
+	assert_eq!(
+		share_in_assets(deps.as_ref(), 1000),
+		inner_share_in_assets(
+			[LEFT_TOKEN_AMT + 100000, RIGHT_TOKEN_AMT],
+			1000,
+			tracked_share_supply(LP_TOKEN).u128()
+		)
+	);
+}
+
+#[test]
+fn surplus_can_be_skimmed() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let sender = AddressFactory::random_address();
+	let receiver = AddressFactory::random_address();
+	donate(&mut deps, [0, 1234]);
+	let res = execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::Skim {
+			receiver: Some(Addr::unchecked(&receiver)),
+		},
+	)
+	.unwrap();
+	assert_eq!(
+		res.messages,
+		vec![SubMsg::new(BankMsg::Send {
+			to_address: receiver,
+			amount: vec![coin(1234, PAIR_DENOMS[1])]
+		})]
+	);
+
+	donate(&mut deps, [0, 0]);
+	let res = execute(
+		deps.as_mut(),
+		env,
+		mock_info(&sender, &[]),
+		PoolPairExecuteMsg::Skim { receiver: None },
+	);
+	assert_eq!(res, Err(CrownfiSwapsCommonError::PayoutIsZero.into()));
+}
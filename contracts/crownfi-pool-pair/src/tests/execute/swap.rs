@@ -3,17 +3,18 @@ use cosmwasm_std::{
 	testing::{mock_env, mock_info},
 	Addr, BankMsg, CosmosMsg, Decimal, SubMsg, Uint128,
 };
+use crownfi_swaps_common::error::CrownfiSwapsCommonError;
 use cw_utils::PaymentError;
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
-	contract::{execute, query},
+	contract::{execute, pool::calc_swap, query, shares::tracked_share_supply},
 	error::PoolPairContractError,
-	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, VolumeQueryResponse},
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQueryReservesResponse, VolumeQueryResponse},
 	tests::{
 		deps, init, inner_share_in_assets, pool_balance, share_in_assets, AddressFactory, PoolPairConfig,
 		LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS, RIGHT_TOKEN_AMT,
 	},
-	workarounds::total_supply_workaround,
 };
 
 #[test]
@@ -27,6 +28,7 @@ fn must_pay_only_one_of_the_pair() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(5000, LP_TOKEN)]);
@@ -58,6 +60,7 @@ fn slippage_tolerance_must_not_exceed_limit() {
 		slippage_tolerance: Some(Decimal::bps(5001)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]);
@@ -69,6 +72,7 @@ fn slippage_tolerance_must_not_exceed_limit() {
 		slippage_tolerance: Some(Decimal::bps(5000)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let res = execute(deps.as_mut(), env, info, msg);
 	assert!(res.is_ok());
@@ -85,6 +89,7 @@ fn slippage_tolerance_must_be_respected() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let sender = AddressFactory::random_address();
@@ -98,6 +103,7 @@ fn slippage_tolerance_must_be_respected() {
 		slippage_tolerance: Some(Decimal::bps(1000)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let res = execute(deps.as_mut(), env, info, msg);
@@ -115,6 +121,7 @@ fn swap_calculation_is_correct() {
 		slippage_tolerance: Some(Decimal::bps(1000)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(50000, PAIR_DENOMS[1])]);
@@ -126,18 +133,18 @@ fn swap_calculation_is_correct() {
 		vec![
 			SubMsg::new(BankMsg::Send {
 				to_address: AddressFactory::FEE_RECEIVER.into(),
-				amount: vec![coin(500, PAIR_DENOMS[0])]
+				amount: vec![coin(454, PAIR_DENOMS[0])]
 			}),
 			SubMsg::new(BankMsg::Send {
 				to_address: sender.to_string(),
-				amount: vec![coin(99000, PAIR_DENOMS[0])]
+				amount: vec![coin(89999, PAIR_DENOMS[0])]
 			})
 		]
 	);
 	deps.querier.update_balance(
 		env.contract.address,
 		vec![
-			coin(LEFT_TOKEN_AMT - (99000 + 500), PAIR_DENOMS[0]),
+			coin(LEFT_TOKEN_AMT - (89999 + 454), PAIR_DENOMS[0]),
 			coin(RIGHT_TOKEN_AMT + 50000, PAIR_DENOMS[1]),
 		],
 	);
@@ -157,6 +164,7 @@ fn naive_result_is_used_when_expected_result_is_unspecified() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let msg_without_expected_result = PoolPairExecuteMsg::Swap {
@@ -164,6 +172,7 @@ fn naive_result_is_used_when_expected_result_is_unspecified() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let sender = AddressFactory::random_address();
@@ -186,6 +195,7 @@ fn maker_fee_sent_to_correct_address() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let sender = AddressFactory::random_address();
@@ -197,7 +207,7 @@ fn maker_fee_sent_to_correct_address() {
 		res.messages[0],
 		SubMsg::new(BankMsg::Send {
 			to_address: pool_conf.fee_receiver.to_string(),
-			amount: vec![coin(5, PAIR_DENOMS[0])]
+			amount: vec![coin(4, PAIR_DENOMS[0])]
 		})
 	)
 }
@@ -213,6 +223,7 @@ fn pool_fees_stay_within_pool() {
 		slippage_tolerance: None,
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 
 	let sender = AddressFactory::random_address();
@@ -263,6 +274,7 @@ fn events_emitted_with_correct_values() {
 		slippage_tolerance: None,
 		receiver: Some(Addr::unchecked(&receiver)),
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(500, PAIR_DENOMS[1])]);
@@ -275,10 +287,11 @@ fn events_emitted_with_correct_values() {
 			attr("sender", &sender),
 			attr("receiver", &receiver),
 			attr("in_coin", coin(500, PAIR_DENOMS[1]).to_string()),
-			attr("out_coin", coin(990, PAIR_DENOMS[0]).to_string()),
+			attr("out_coin", coin(989, PAIR_DENOMS[0]).to_string()),
 			attr("spread_amount", "1"),
 			attr("total_fee_amount", "10"),
-			attr("maker_fee_amount", "5"),
+			attr("maker_fee_amount", "4"),
+			attr("fee_denom", PAIR_DENOMS[0]),
 		]
 	);
 }
@@ -294,11 +307,12 @@ fn share_values_correctly_updated() {
 		slippage_tolerance: Some(Decimal::bps(1000)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let info = mock_info(&sender, &[coin(10000, PAIR_DENOMS[1])]);
 
-	let total_supply = total_supply_workaround(LP_TOKEN);
+	let total_supply = tracked_share_supply(LP_TOKEN);
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
 	let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
 
@@ -337,22 +351,168 @@ fn volume_tracking_updated() {
 		slippage_tolerance: Some(Decimal::bps(1000)),
 		receiver: None,
 		receiver_payload: None,
+		referral: None,
 	};
 	let sender = AddressFactory::random_address();
 	let amt = 10000;
 	let info = mock_info(&sender, &[coin(10000, PAIR_DENOMS[1])]);
 	let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
-	let maker_fee_amount = res
-		.attributes
+	// The output volume includes the maker fee
+	let total_sent = res
+		.messages
 		.into_iter()
-		.find(|x| x.key == "maker_fee_amount")
-		.and_then(|x| x.value.parse::<u128>().ok())
-		.unwrap();
+		.filter_map(|x| match x.msg {
+			CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount[0].amount),
+			_ => None,
+		})
+		.sum::<Uint128>();
 
 	let volume_sum: VolumeQueryResponse =
 		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::TotalVolumeSum).unwrap()).unwrap();
 	assert_eq!(
 		volume_sum.volume.each_ref().map(Uint128::u128),
-		[amt * 2 - maker_fee_amount, amt]
+		[total_sent.u128(), amt]
 	);
 }
+
+#[test]
+fn tiny_swaps_still_pay_fees() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let res = execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&AddressFactory::random_address(), &[coin(10, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: Some(Decimal::bps(1000)),
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	)
+	.unwrap();
+	// 1% of 19 is rounded up to 1, the maker's share of it is rounded down to 0.
+	assert!(res
+		.attributes
+		.contains(&attr("out_coin", coin(18, PAIR_DENOMS[0]).to_string())));
+	assert!(res.attributes.contains(&attr("total_fee_amount", "1")));
+	assert!(res.attributes.contains(&attr("maker_fee_amount", "0")));
+}
+
+/// A randomly seeded RNG and its seed, which is included in failure messages. Failures can be reproduced by setting
+/// `SWAP_TEST_SEED` to it.
+fn seeded_rng() -> (StdRng, u64) {
+	let seed = std::env::var("SWAP_TEST_SEED")
+		.map(|seed| seed.parse().expect("SWAP_TEST_SEED should be a u64"))
+		.unwrap_or_else(|_| rand::random());
+	(StdRng::seed_from_u64(seed), seed)
+}
+
+#[test]
+fn constant_product_never_decreases() {
+	let (mut rng, seed) = seeded_rng();
+	for fee_on_input in [false, true] {
+		for _ in 0..1000 {
+			let balances = [
+				Uint128::new(rng.gen_range(1..=1_000_000_000_000)),
+				Uint128::new(rng.gen_range(1..=1_000_000_000_000)),
+			];
+			let inverse = rng.gen_bool(0.5);
+			let offer_amount = Uint128::new(rng.gen_range(1..=balances[inverse as usize].u128() * 2));
+			let total_fee_bps = rng.gen_range(0..=10000);
+			let maker_fee_bps = rng.gen_range(0..=total_fee_bps);
+
+			let result = calc_swap(
+				&balances,
+				offer_amount,
+				total_fee_bps,
+				maker_fee_bps,
+				0,
+				fee_on_input,
+				inverse,
+				None,
+				Decimal::MAX,
+			)
+			.unwrap();
+			// Same as `process_swap`, the maker fee leaves the pool from whichever side it was taken from.
+			let mut balances_after = balances;
+			if fee_on_input {
+				balances_after[inverse as usize] += offer_amount - result.maker_fee_amount;
+				balances_after[(!inverse) as usize] -= result.result_amount;
+			} else {
+				balances_after[inverse as usize] += offer_amount;
+				balances_after[(!inverse) as usize] -= result.result_amount + result.maker_fee_amount;
+			}
+			assert!(
+				balances_after[0].full_mul(balances_after[1]) >= balances[0].full_mul(balances[1]),
+				"k decreased swapping {offer_amount} with {balances:?} ({total_fee_bps}/{maker_fee_bps} bps, seed {seed})"
+			);
+		}
+	}
+}
+
+#[test]
+fn constant_product_never_decreases_between_swaps() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let (mut rng, seed) = seeded_rng();
+	let mut reserves = [Uint128::new(LEFT_TOKEN_AMT), Uint128::new(RIGHT_TOKEN_AMT)];
+	for _ in 0..200 {
+		execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(AddressFactory::ADMIN, &[]),
+			PoolPairExecuteMsg::UpdateConfig {
+				fee_receiver: None,
+				total_fee_bps: None,
+				maker_fee_bps: None,
+				endorsed: None,
+				lp_staking: None,
+				long_term_orders: None,
+				position_tracking: None,
+				fee_on_input: Some(rng.gen_bool(0.5)),
+				max_referral_fee_bps: None,
+			},
+		)
+		.unwrap();
+		let index = rng.gen_range(0..2);
+		// Mostly tiny swaps, as that's where rounding matters the most
+		let offer_amount = if rng.gen_bool(0.5) {
+			rng.gen_range(1..=100)
+		} else {
+			rng.gen_range(1..=reserves[index].u128() / 10)
+		};
+		let res = execute(
+			deps.as_mut(),
+			mock_env(),
+			mock_info(
+				&AddressFactory::random_address(),
+				&[coin(offer_amount, PAIR_DENOMS[index])],
+			),
+			PoolPairExecuteMsg::Swap {
+				expected_result: None,
+				slippage_tolerance: Some(Decimal::bps(5000)),
+				receiver: None,
+				receiver_payload: None,
+				referral: None,
+			},
+		);
+		match res {
+			Ok(_) | Err(PoolPairContractError::SlippageTooHigh(_)) => {}
+			Err(PoolPairContractError::SwapsCommonError(CrownfiSwapsCommonError::PayoutIsZero)) => {}
+			Err(err) => panic!("unexpected error swapping {offer_amount} with {reserves:?}: {err} (seed {seed})"),
+		}
+
+		let reserves_after: PoolPairQueryReservesResponse =
+			from_json(query(deps.as_ref(), mock_env(), PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+		let reserves_after = reserves_after.tracked.map(|coin| coin.amount);
+		assert!(
+			reserves_after[0].full_mul(reserves_after[1]) >= reserves[0].full_mul(reserves[1]),
+			"k decreased swapping {offer_amount} with {reserves:?} (seed {seed})"
+		);
+		reserves = reserves_after;
+	}
+}
@@ -20,14 +20,17 @@ fn accepts_no_coins() {
 	init(&mut deps);
 	let env = mock_env();
 
-	let new_admin = AddressFactory::random_address();
 	let new_fee_receiver = AddressFactory::random_address();
 	let exec_msg = PoolPairExecuteMsg::UpdateConfig {
-		admin: Some(Addr::unchecked(&new_admin)),
 		fee_receiver: Some(Addr::unchecked(&new_fee_receiver)),
 		total_fee_bps: Some(69),
 		maker_fee_bps: None,
 		endorsed: Some(true),
+		lp_staking: None,
+		long_term_orders: None,
+		position_tracking: None,
+		fee_on_input: None,
+		max_referral_fee_bps: None,
 	};
 
 	let info = mock_info(AddressFactory::ADMIN, &[coin(1, PAIR_DENOMS[0])]);
@@ -45,11 +48,15 @@ fn sender_must_be_admin() {
 
 	let fake_admin = AddressFactory::random_address();
 	let exec_msg = PoolPairExecuteMsg::UpdateConfig {
-		admin: Some(Addr::unchecked(&fake_admin)),
 		fee_receiver: Some(Addr::unchecked(&fake_admin)),
 		total_fee_bps: Some(69),
 		maker_fee_bps: None,
 		endorsed: Some(true),
+		lp_staking: None,
+		long_term_orders: None,
+		position_tracking: None,
+		fee_on_input: None,
+		max_referral_fee_bps: None,
 	};
 
 	let env = mock_env();
@@ -69,21 +76,24 @@ fn items_are_set_as_specified() {
 	init(&mut deps);
 	let env = mock_env();
 
-	let new_admin = AddressFactory::random_address();
 	let new_fee_receiver = AddressFactory::random_address();
 	let exec_msg = PoolPairExecuteMsg::UpdateConfig {
-		admin: Some(Addr::unchecked(&new_admin)),
 		fee_receiver: Some(Addr::unchecked(&new_fee_receiver)),
 		total_fee_bps: Some(69),
 		maker_fee_bps: None,
 		endorsed: Some(true),
+		lp_staking: None,
+		long_term_orders: None,
+		position_tracking: None,
+		fee_on_input: None,
+		max_referral_fee_bps: None,
 	};
 
 	let info = mock_info(AddressFactory::ADMIN, &[]);
 	execute(deps.as_mut(), env.clone().clone(), info, exec_msg).unwrap();
 
 	let config = PoolPairConfig::load().unwrap().unwrap();
-	assert_eq!(config.admin, Addr::unchecked(new_admin).try_into().unwrap());
+	assert_eq!(config.admin, Addr::unchecked(AddressFactory::ADMIN).try_into().unwrap());
 	assert_eq!(
 		config.fee_receiver,
 		Addr::unchecked(new_fee_receiver).try_into().unwrap()
@@ -91,3 +101,51 @@ fn items_are_set_as_specified() {
 	assert_eq!(config.total_fee_bps, 69);
 	assert_eq!(config.maker_fee_bps, 50);
 }
+
+#[test]
+fn fee_bps_must_be_valid() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let update_fees = |total_fee_bps: Option<u16>, maker_fee_bps: Option<u16>, max_referral_fee_bps: Option<u16>| {
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps,
+			maker_fee_bps,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: None,
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps,
+		}
+	};
+	for exec_msg in [
+		update_fees(Some(10_001), None, None),
+		update_fees(None, Some(101), None),
+		update_fees(Some(49), None, None),
+		update_fees(None, None, Some(10_001)),
+	] {
+		assert_eq!(
+			execute(
+				deps.as_mut(),
+				mock_env(),
+				mock_info(AddressFactory::ADMIN, &[]),
+				exec_msg
+			),
+			Err(PoolPairContractError::InvalidFeeBps)
+		);
+	}
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		update_fees(Some(10_000), Some(10_000), Some(10_000)),
+	)
+	.unwrap();
+	let config = PoolPairConfig::load().unwrap().unwrap();
+	assert_eq!(config.total_fee_bps, 10_000);
+	assert_eq!(config.maker_fee_bps, 10_000);
+	assert_eq!(config.max_referral_fee_bps, 10_000);
+}
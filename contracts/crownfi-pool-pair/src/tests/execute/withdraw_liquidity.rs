@@ -7,14 +7,13 @@ use crownfi_swaps_common::error::CrownfiSwapsCommonError;
 use cw_utils::PaymentError;
 
 use crate::{
-	contract::execute,
+	contract::{execute, shares::tracked_share_supply, UNLOCK_REPLY_ID},
 	error::PoolPairContractError,
 	msg::PoolPairExecuteMsg,
 	tests::{
 		calc_shares, deps, init, pool_balance, share_in_assets, AddressFactory, DUST, LEFT_TOKEN_AMT, LP_TOKEN,
 		PAIR_DENOMS, RIGHT_TOKEN_AMT,
 	},
-	workarounds::total_supply_workaround,
 };
 
 #[test]
@@ -108,7 +107,7 @@ fn value_of_share_doesnt_change() {
 	init(&mut deps);
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_share = total_supply_workaround(LP_TOKEN);
+	let total_share = tracked_share_supply(LP_TOKEN);
 	let assets = share_in_assets(deps.as_ref(), 500);
 	let share_values = calc_shares(assets.clone().map(|x| x.amount.u128()), pb);
 
@@ -131,7 +130,7 @@ fn value_of_share_doesnt_change() {
 		],
 	);
 
-	let total_share_after = total_supply_workaround(LP_TOKEN);
+	let total_share_after = tracked_share_supply(LP_TOKEN);
 	assert_eq!(total_share - Uint128::new(500), total_share_after);
 	let new_share_values = calc_shares(assets.clone().map(|x| x.amount.u128()), pb);
 	assert_eq!(share_values - DUST, new_share_values);
@@ -207,11 +206,14 @@ fn returns_wasm_message_when_payload_provided() {
 			SubMsg::new(BankMsg::Burn {
 				amount: vec![coin(500, LP_TOKEN)]
 			}),
-			SubMsg::new(WasmMsg::Execute {
-				contract_addr: receiver,
-				msg: Binary(b"anana".into()),
-				funds: assets.into()
-			})
+			SubMsg::reply_on_success(
+				WasmMsg::Execute {
+					contract_addr: receiver,
+					msg: Binary(b"anana".into()),
+					funds: assets.into()
+				},
+				UNLOCK_REPLY_ID
+			)
 		]
 	);
 }
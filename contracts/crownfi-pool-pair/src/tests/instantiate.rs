@@ -9,10 +9,9 @@ use cw2::get_contract_version;
 use sei_cosmwasm::SeiMsg;
 
 use crate::{
-	contract::instantiate,
+	contract::{instantiate, shares::tracked_share_supply},
 	error::PoolPairContractError,
 	tests::{deps, init, shares::LP_SUBDENOM, AddressFactory, PoolPairConfig, LP_TOKEN, PAIR_DENOMS},
-	workarounds::total_supply_workaround,
 };
 
 use super::{PoolPairConfigJsonable, PoolPairInstantiateMsg, ONE_BILLION};
@@ -29,10 +28,17 @@ fn must_be_paid_with_2_coins() {
 			admin: admin_addr.clone(),
 			inverse: false,
 			endorsed: true,
+			swaps_paused: false,
+			deposits_paused: false,
+			long_term_orders: false,
+			position_tracking: false,
+			fee_on_input: false,
+			max_referral_fee_bps: 0,
 			fee_receiver: admin_addr,
 			total_fee_bps: 100,
 			maker_fee_bps: 50,
 		},
+		lp_staking: None,
 	};
 
 	let env = mock_env();
@@ -99,7 +105,7 @@ fn subdenom_is_created_and_shares_are_minted() {
 			}),
 			SubMsg::new(BankMsg::Send {
 				to_address: AddressFactory::ADMIN.into(),
-				amount: vec![coin(707106, LP_TOKEN)]
+				amount: vec![coin(707106 - 1000, LP_TOKEN)]
 			})
 		]
 	);
@@ -114,6 +120,6 @@ fn contract_is_aware_of_newly_minted_tokens() {
 	assert_eq!(id.left, PAIR_DENOMS[0]);
 	assert_eq!(id.right, PAIR_DENOMS[1]);
 
-	let total_supply = total_supply_workaround(LP_TOKEN);
+	let total_supply = tracked_share_supply(LP_TOKEN);
 	assert_eq!(total_supply.u128(), 707106);
 }
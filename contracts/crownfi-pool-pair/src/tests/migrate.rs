@@ -0,0 +1,65 @@
+use cosmwasm_std::{coin, testing::mock_env};
+use crownfi_cw_common::storage::item::StoredItem;
+use cw2::{get_contract_version, set_contract_version};
+
+use crate::{
+	contract::migrate,
+	tests::{deps, init, PoolPairConfig, PoolPairReserves, LEFT_TOKEN_AMT, PAIR_DENOMS, RIGHT_TOKEN_AMT},
+};
+
+use super::PoolPairMigrateMsg;
+
+#[test]
+fn updates_contract_version() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	set_contract_version(&mut deps.storage, "crownfi-pool-pair-contract", "0.0.0").unwrap();
+
+	migrate(deps.as_mut(), mock_env(), PoolPairMigrateMsg {}).unwrap();
+	let c_version = get_contract_version(&deps.storage).unwrap();
+	assert_eq!(c_version.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn preserves_config() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	let config_before = *PoolPairConfig::load_non_empty().unwrap();
+
+	migrate(deps.as_mut(), mock_env(), PoolPairMigrateMsg {}).unwrap();
+	assert_eq!(*PoolPairConfig::load_non_empty().unwrap(), config_before);
+}
+
+#[test]
+fn tracks_reserves_if_not_already() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	PoolPairReserves::remove();
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![
+			coin(LEFT_TOKEN_AMT + 5000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT, PAIR_DENOMS[1]),
+		],
+	);
+
+	migrate(deps.as_mut(), mock_env(), PoolPairMigrateMsg {}).unwrap();
+	assert_eq!(
+		PoolPairReserves::load_non_empty().unwrap().amounts,
+		[LEFT_TOKEN_AMT + 5000, RIGHT_TOKEN_AMT]
+	);
+
+	// Already tracked reserves aren't touched
+	deps.querier.update_balance(
+		mock_env().contract.address,
+		vec![
+			coin(LEFT_TOKEN_AMT + 10000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT, PAIR_DENOMS[1]),
+		],
+	);
+	migrate(deps.as_mut(), mock_env(), PoolPairMigrateMsg {}).unwrap();
+	assert_eq!(
+		PoolPairReserves::load_non_empty().unwrap().amounts,
+		[LEFT_TOKEN_AMT + 5000, RIGHT_TOKEN_AMT]
+	);
+}
@@ -3,13 +3,14 @@ use cosmwasm_std::{OwnedDeps, Uint128};
 use crownfi_cw_common::data_types::canonical_addr::SeiCanonicalAddr;
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
 
+use crate::contract::shares::tracked_share_supply;
 use crate::contract::*;
 use crate::msg::*;
 use crate::state::*;
-use crate::workarounds::total_supply_workaround;
 
 mod execute;
 mod instantiate;
+mod migrate;
 mod query;
 
 const DUST: u128 = 1;
@@ -57,10 +58,17 @@ fn init(deps: &mut TestDeps) -> Response<SeiMsg> {
 			admin: Addr::unchecked(AddressFactory::ADMIN),
 			inverse: false,
 			endorsed: true,
+			swaps_paused: false,
+			deposits_paused: false,
+			long_term_orders: false,
+			position_tracking: false,
+			fee_on_input: false,
+			max_referral_fee_bps: 0,
 			fee_receiver: Addr::unchecked(AddressFactory::FEE_RECEIVER),
 			total_fee_bps: 100,
 			maker_fee_bps: 50,
 		},
+		lp_staking: None,
 	};
 
 	let env = mock_env();
@@ -86,7 +94,7 @@ fn init(deps: &mut TestDeps) -> Response<SeiMsg> {
 }
 
 fn calc_shares<T: Into<Uint128> + Copy>(deposits: [T; 2], pool: [T; 2]) -> u128 {
-	let total_supply = total_supply_workaround(LP_TOKEN);
+	let total_supply = tracked_share_supply(LP_TOKEN);
 	std::cmp::min(
 		deposits[0].into().multiply_ratio(total_supply, pool[0].into()),
 		deposits[1].into().multiply_ratio(total_supply, pool[1].into()),
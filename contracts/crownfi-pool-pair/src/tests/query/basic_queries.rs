@@ -21,10 +21,17 @@ fn basic_queries() {
 			admin: Addr::unchecked(AddressFactory::ADMIN),
 			inverse: true,
 			endorsed: true,
+			swaps_paused: false,
+			deposits_paused: false,
+			long_term_orders: false,
+			position_tracking: false,
+			fee_on_input: false,
+			max_referral_fee_bps: 0,
 			fee_receiver: Addr::unchecked(AddressFactory::FEE_RECEIVER),
 			total_fee_bps: 100,
 			maker_fee_bps: 50,
 		},
+		lp_staking: None,
 	};
 
 	let env = mock_env();
@@ -71,7 +78,13 @@ fn basic_queries() {
 			total_fee_bps: 100,
 			maker_fee_bps: 50,
 			inverse: true,
-			endorsed: true
+			endorsed: true,
+			swaps_paused: false,
+			deposits_paused: false,
+			long_term_orders: false,
+			position_tracking: false,
+			fee_on_input: false,
+			max_referral_fee_bps: 0,
 		}
 	);
 }
@@ -29,6 +29,7 @@ fn hourly_exchange_rate() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
@@ -44,6 +45,7 @@ fn hourly_exchange_rate() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
@@ -82,8 +84,8 @@ fn hourly_exchange_rate() {
 	)
 	.unwrap();
 
-	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.49749"));
-	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.50251"));
+	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.45225"));
+	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.46119"));
 	assert!(approximated_equality(
 		exchange_rate.exchange_rate_avg.to_string().parse().unwrap(),
 		(exchange_rate.exchange_rate_low.to_string().parse::<f64>().unwrap()
@@ -103,8 +105,8 @@ fn hourly_exchange_rate() {
 	)
 	.unwrap();
 
-	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.49749"));
-	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.50251"));
+	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.45225"));
+	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.46119"));
 	assert!(approximated_equality(
 		exchange_rate.exchange_rate_avg.to_string().parse().unwrap(),
 		(exchange_rate.exchange_rate_low.to_string().parse::<f64>().unwrap()
@@ -113,7 +115,7 @@ fn hourly_exchange_rate() {
 		3
 	));
 
-	// A new hour's been started, but there are no trades yet. So the default is to return the balance ratio
+	// A new hour's been started, but there are no trades yet. So the default is to return the reserve ratio
 	let exchange_rate2: ExchangeRateQueryResponse = from_json(
 		query(
 			deps.as_ref(),
@@ -123,9 +125,10 @@ fn hourly_exchange_rate() {
 		.unwrap(),
 	)
 	.unwrap();
-	assert_eq!(exchange_rate2.exchange_rate_avg.to_string(), "0.5");
-	assert_eq!(exchange_rate2.exchange_rate_low.to_string(), "0.5");
-	assert_eq!(exchange_rate2.exchange_rate_high.to_string(), "0.5");
+	// (500000 - 45226 + 50000) / (1000000 + 100000 - 108413)
+	assert_eq!(exchange_rate2.exchange_rate_avg.to_string(), "0.509056693966338808");
+	assert_eq!(exchange_rate2.exchange_rate_low.to_string(), "0.509056693966338808");
+	assert_eq!(exchange_rate2.exchange_rate_high.to_string(), "0.509056693966338808");
 
 	env.block.time = Timestamp::from_seconds(1725414000);
 	let exchange_rate3: ExchangeRateQueryResponse = from_json(
@@ -161,6 +164,7 @@ fn daily_exchange_rate() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
@@ -176,6 +180,7 @@ fn daily_exchange_rate() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
@@ -213,8 +218,8 @@ fn daily_exchange_rate() {
 		.unwrap(),
 	)
 	.unwrap();
-	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.49749"));
-	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.50251"));
+	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.45225"));
+	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.46119"));
 	assert!(approximated_equality(
 		exchange_rate.exchange_rate_avg.to_string().parse().unwrap(),
 		(exchange_rate.exchange_rate_low.to_string().parse::<f64>().unwrap()
@@ -234,8 +239,8 @@ fn daily_exchange_rate() {
 		.unwrap(),
 	)
 	.unwrap();
-	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.49749"));
-	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.50251"));
+	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.45225"));
+	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.46119"));
 	assert!(approximated_equality(
 		exchange_rate.exchange_rate_avg.to_string().parse().unwrap(),
 		(exchange_rate.exchange_rate_low.to_string().parse::<f64>().unwrap()
@@ -244,7 +249,7 @@ fn daily_exchange_rate() {
 		3
 	));
 
-	// A new day's been started, but there are no trades yet. So the default is to return the balance ratio
+	// A new day's been started, but there are no trades yet. So the default is to return the reserve ratio
 	let exchange_rate2: ExchangeRateQueryResponse = from_json(
 		query(
 			deps.as_ref(),
@@ -254,9 +259,10 @@ fn daily_exchange_rate() {
 		.unwrap(),
 	)
 	.unwrap();
-	assert_eq!(exchange_rate2.exchange_rate_avg.to_string(), "0.5");
-	assert_eq!(exchange_rate2.exchange_rate_low.to_string(), "0.5");
-	assert_eq!(exchange_rate2.exchange_rate_high.to_string(), "0.5");
+	// (500000 - 45226 + 50000) / (1000000 + 100000 - 108413)
+	assert_eq!(exchange_rate2.exchange_rate_avg.to_string(), "0.509056693966338808");
+	assert_eq!(exchange_rate2.exchange_rate_low.to_string(), "0.509056693966338808");
+	assert_eq!(exchange_rate2.exchange_rate_high.to_string(), "0.509056693966338808");
 
 	env.block.time = Timestamp::from_seconds(1725494401);
 	let exchange_rate3: ExchangeRateQueryResponse = from_json(
@@ -293,6 +299,7 @@ fn exchange_rate_all_time() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
@@ -301,8 +308,8 @@ fn exchange_rate_all_time() {
 	let exchange_rate: ExchangeRateQueryResponse =
 		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::ExchangeRateAllTime).unwrap()).unwrap();
 
-	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.49749"));
-	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.49749"));
+	assert!(exchange_rate.exchange_rate_high.to_string().starts_with("0.45225"));
+	assert!(exchange_rate.exchange_rate_low.to_string().starts_with("0.45225"));
 	assert!(approximated_equality(
 		exchange_rate.exchange_rate_avg.to_string().parse().unwrap(),
 		(exchange_rate.exchange_rate_low.to_string().parse::<f64>().unwrap()
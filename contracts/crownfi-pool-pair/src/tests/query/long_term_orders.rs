@@ -0,0 +1,131 @@
+use cosmwasm_std::{
+	coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, Decimal, Timestamp,
+};
+
+use crate::{
+	contract::{execute, query},
+	msg::{
+		ExchangeRateQueryResponse, PoolPairExecuteMsg, PoolPairQueryLongTermOrderResponse,
+		PoolPairQueryLongTermOrdersResponse, PoolPairQueryMsg, PoolPairQueryReservesResponse,
+	},
+	tests::{deps, init, AddressFactory, LEFT_TOKEN_AMT, PAIR_DENOMS},
+};
+
+#[test]
+fn progress_is_settled_virtually() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let start = 1_571_799_600;
+	let mut env = mock_env();
+	env.block.time = Timestamp::from_seconds(start);
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: Some(true),
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+	let owner = AddressFactory::random_address();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&owner, &[coin(72_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: 36_000 },
+	)
+	.unwrap();
+
+	// Nothing has executed since the order was placed, but a quarter of it is reported as sold.
+	env.block.time = Timestamp::from_seconds(start + 9_000);
+	let orders: Vec<PoolPairQueryLongTermOrderResponse> = from_json(
+		query(
+			deps.as_ref(),
+			env.clone(),
+			PoolPairQueryMsg::LongTermOrdersByOwner {
+				owner: Addr::unchecked(&owner),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap();
+	assert_eq!(orders.len(), 1);
+	assert_eq!(orders[0].order_id, 1);
+	assert_eq!(orders[0].sell_denom, PAIR_DENOMS[0]);
+	assert_eq!(orders[0].buy_denom, PAIR_DENOMS[1]);
+	assert_eq!(orders[0].sold.u128(), 18_000);
+	assert_eq!(orders[0].remaining.u128(), 54_000);
+	assert!(!orders[0].claimable_proceeds.is_zero());
+
+	let state: PoolPairQueryLongTermOrdersResponse =
+		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::LongTermOrders).unwrap()).unwrap();
+	assert_eq!(state.last_settlement, start + 9_000);
+	assert_eq!(state.sell_rates, [coin(2, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+	assert_eq!(
+		state.escrow,
+		[
+			coin(54_000, PAIR_DENOMS[0]),
+			coin(orders[0].claimable_proceeds.u128(), PAIR_DENOMS[1])
+		]
+	);
+
+	let reserves: PoolPairQueryReservesResponse =
+		from_json(query(deps.as_ref(), env, PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+	assert_eq!(reserves.tracked[0], coin(LEFT_TOKEN_AMT + 18_000, PAIR_DENOMS[0]));
+}
+
+#[test]
+fn exchange_rate_without_trades_uses_settled_reserves() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let start = 1_571_799_600;
+	let mut env = mock_env();
+	env.block.time = Timestamp::from_seconds(start);
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: Some(true),
+			position_tracking: None,
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[coin(72_000_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: 36_000 },
+	)
+	.unwrap();
+
+	env.block.time = Timestamp::from_seconds(start + 18_000);
+	let reserves: PoolPairQueryReservesResponse =
+		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+	assert_eq!(reserves.tracked[0], coin(LEFT_TOKEN_AMT + 36_000_000, PAIR_DENOMS[0]));
+	let exchange_rate: ExchangeRateQueryResponse =
+		from_json(query(deps.as_ref(), env, PoolPairQueryMsg::ExchangeRateAllTime).unwrap()).unwrap();
+	assert_eq!(
+		exchange_rate.exchange_rate_avg,
+		Decimal::from_ratio(reserves.tracked[1].amount, reserves.tracked[0].amount)
+	);
+}
@@ -1,5 +1,9 @@
 mod basic_queries;
 mod exchange_rate;
+mod long_term_orders;
+mod position_report;
+mod reserves;
+mod share_supply;
 mod share_value;
 mod simulate_naive_swap;
 mod simulate_provide_liquidity;
@@ -0,0 +1,238 @@
+use cosmwasm_std::{
+	coin, from_json,
+	testing::{mock_env, mock_info},
+	Addr, Binary, Decimal, Env, Reply, SubMsgResponse, SubMsgResult, Timestamp, Uint128,
+};
+
+use crate::{
+	contract::{execute, query, reply, FLASH_SWAP_REPLY_ID},
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQueryPositionReportResponse},
+	tests::{deps, init, AddressFactory, TestDeps, LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS, RIGHT_TOKEN_AMT},
+};
+
+fn provide_liquidity(deps: &mut TestDeps, sender: &str) {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(sender, &[coin(100_000, PAIR_DENOMS[0]), coin(50_000, PAIR_DENOMS[1])]),
+		PoolPairExecuteMsg::ProvideLiquidity {
+			slippage_tolerance: None,
+			receiver: None,
+			receiver_payload: None,
+			auto_stake: false,
+		},
+	)
+	.unwrap();
+}
+
+fn position_report(deps: &TestDeps, address: &str) -> Option<PoolPairQueryPositionReportResponse> {
+	position_report_at(deps, address, mock_env())
+}
+
+fn position_report_at(deps: &TestDeps, address: &str, env: Env) -> Option<PoolPairQueryPositionReportResponse> {
+	from_json(
+		query(
+			deps.as_ref(),
+			env,
+			PoolPairQueryMsg::PositionReport {
+				address: Addr::unchecked(address),
+			},
+		)
+		.unwrap(),
+	)
+	.unwrap()
+}
+
+fn enable_position_tracking(deps: &mut TestDeps, long_term_orders: bool) {
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: Some(long_term_orders),
+			position_tracking: Some(true),
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+}
+
+#[test]
+fn nothing_is_recorded_unless_enabled() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let sender = AddressFactory::random_address();
+	provide_liquidity(&mut deps, &sender);
+	assert_eq!(position_report(&deps, &sender), None);
+}
+
+#[test]
+fn reports_cost_basis_and_fees() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(AddressFactory::ADMIN, &[]),
+		PoolPairExecuteMsg::UpdateConfig {
+			fee_receiver: None,
+			total_fee_bps: None,
+			maker_fee_bps: None,
+			endorsed: None,
+			lp_staking: None,
+			long_term_orders: None,
+			position_tracking: Some(true),
+			fee_on_input: None,
+			max_referral_fee_bps: None,
+		},
+	)
+	.unwrap();
+
+	let sender = AddressFactory::random_address();
+	provide_liquidity(&mut deps, &sender);
+	let report = position_report(&deps, &sender).unwrap();
+	assert_eq!(report.shares.u128(), 70_710);
+	assert_eq!(
+		report.cost_basis,
+		[coin(100_000, PAIR_DENOMS[0]), coin(50_000, PAIR_DENOMS[1])]
+	);
+	assert_eq!(report.fees_earned, [coin(0, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+
+	// 230 of the 459 fee goes to LPs, which this position owns 70710 / 777816 of.
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&AddressFactory::random_address(), &[coin(100_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: Some(Decimal::bps(1000)),
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	)
+	.unwrap();
+	let report = position_report(&deps, &sender).unwrap();
+	assert_eq!(report.fees_earned, [coin(0, PAIR_DENOMS[0]), coin(20, PAIR_DENOMS[1])]);
+	// The price moved, so holding would've been better.
+	assert!(report.share_value_total[0].amount < report.hodl_value_total[0].amount);
+	assert!(report.share_value_total[1].amount < report.hodl_value_total[1].amount);
+	assert!(!report.impermanent_loss.is_zero());
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&sender, &[coin(35_355, LP_TOKEN)]),
+		PoolPairExecuteMsg::WithdrawLiquidity {
+			receiver: None,
+			receiver_payload: None,
+		},
+	)
+	.unwrap();
+	let report = position_report(&deps, &sender).unwrap();
+	assert_eq!(report.shares.u128(), 35_355);
+	assert_eq!(
+		report.cost_basis,
+		[coin(50_000, PAIR_DENOMS[0]), coin(25_000, PAIR_DENOMS[1])]
+	);
+	assert_eq!(report.fees_earned, [coin(0, PAIR_DENOMS[0]), coin(10, PAIR_DENOMS[1])]);
+
+	execute(
+		deps.as_mut(),
+		mock_env(),
+		mock_info(&sender, &[coin(35_355, LP_TOKEN)]),
+		PoolPairExecuteMsg::WithdrawLiquidity {
+			receiver: None,
+			receiver_payload: None,
+		},
+	)
+	.unwrap();
+	assert_eq!(position_report(&deps, &sender), None);
+}
+
+#[test]
+fn reports_flash_swap_fees() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_position_tracking(&mut deps, false);
+
+	let sender = AddressFactory::random_address();
+	provide_liquidity(&mut deps, &sender);
+
+	let env = mock_env();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::FlashSwap {
+			amounts: [Uint128::new(10_000), Uint128::zero()],
+			receiver: None,
+			payload: Binary::default(),
+		},
+	)
+	.unwrap();
+	// 10200 is paid back, 51 of the 102 fee goes to LPs, which this position owns 70710 / 777816 of.
+	deps.querier.update_balance(
+		env.contract.address.clone(),
+		vec![
+			coin(LEFT_TOKEN_AMT + 100_000 + 200, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT + 50_000, PAIR_DENOMS[1]),
+		],
+	);
+	reply(
+		deps.as_mut(),
+		env,
+		Reply {
+			id: FLASH_SWAP_REPLY_ID,
+			result: SubMsgResult::Ok(SubMsgResponse {
+				events: vec![],
+				data: None,
+			}),
+		},
+	)
+	.unwrap();
+	let report = position_report(&deps, &sender).unwrap();
+	assert_eq!(report.fees_earned, [coin(4, PAIR_DENOMS[0]), coin(0, PAIR_DENOMS[1])]);
+}
+
+#[test]
+fn reports_long_term_order_fees() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+	enable_position_tracking(&mut deps, true);
+
+	let sender = AddressFactory::random_address();
+	provide_liquidity(&mut deps, &sender);
+
+	let start = 1_571_799_600;
+	let mut env = mock_env();
+	env.block.time = Timestamp::from_seconds(start);
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[coin(72_000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::PlaceLongTermOrder { duration: 36_000 },
+	)
+	.unwrap();
+
+	// The order receives 33788, 338 of which goes to LPs, which this position owns 70710 / 777816 of. The fees are
+	// reported before the orders are settled as well.
+	env.block.time = Timestamp::from_seconds(start + 36_000);
+	let report = position_report_at(&deps, &sender, env.clone()).unwrap();
+	assert_eq!(report.fees_earned, [coin(0, PAIR_DENOMS[0]), coin(30, PAIR_DENOMS[1])]);
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[]),
+		PoolPairExecuteMsg::ExecuteVirtualOrders {},
+	)
+	.unwrap();
+	assert_eq!(position_report_at(&deps, &sender, env), Some(report));
+}
@@ -0,0 +1,57 @@
+use cosmwasm_std::{
+	coin, from_json,
+	testing::{mock_env, mock_info},
+	Decimal,
+};
+
+use crate::{
+	contract::{execute, query},
+	msg::{PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQueryReservesResponse},
+	tests::{deps, init, AddressFactory, LEFT_TOKEN_AMT, PAIR_DENOMS, RIGHT_TOKEN_AMT},
+};
+
+#[test]
+fn tracks_swaps_but_not_donations() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	execute(
+		deps.as_mut(),
+		env.clone(),
+		mock_info(&AddressFactory::random_address(), &[coin(100000, PAIR_DENOMS[0])]),
+		PoolPairExecuteMsg::Swap {
+			expected_result: None,
+			slippage_tolerance: Some(Decimal::bps(1000)),
+			receiver: None,
+			receiver_payload: None,
+			referral: None,
+		},
+	)
+	.unwrap();
+	// 44999cba to the sender and 227cba to the fee receiver, along with someone sending 5000abc directly
+	deps.querier.update_balance(
+		env.contract.address.clone(),
+		vec![
+			coin(LEFT_TOKEN_AMT + 100000 + 5000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT - 45226, PAIR_DENOMS[1]),
+		],
+	);
+
+	let reserves: PoolPairQueryReservesResponse =
+		from_json(query(deps.as_ref(), env, PoolPairQueryMsg::Reserves).unwrap()).unwrap();
+	assert_eq!(
+		reserves.tracked,
+		[
+			coin(LEFT_TOKEN_AMT + 100000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT - 45226, PAIR_DENOMS[1])
+		]
+	);
+	assert_eq!(
+		reserves.actual,
+		[
+			coin(LEFT_TOKEN_AMT + 100000 + 5000, PAIR_DENOMS[0]),
+			coin(RIGHT_TOKEN_AMT - 45226, PAIR_DENOMS[1])
+		]
+	);
+}
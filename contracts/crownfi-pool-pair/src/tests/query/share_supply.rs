@@ -0,0 +1,37 @@
+use cosmwasm_std::{coin, from_json, testing::mock_env, Uint128};
+
+use crate::{
+	contract::query,
+	msg::{PoolPairQueryMsg, PoolPairQueryShareSupplyResponse},
+	tests::{deps, init, AddressFactory, LP_TOKEN},
+};
+
+#[test]
+fn reports_tracked_and_bank_supply() {
+	let mut deps = deps(&[]);
+	init(&mut deps);
+
+	let env = mock_env();
+	let share_supply: PoolPairQueryShareSupplyResponse =
+		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::ShareSupply).unwrap()).unwrap();
+	// sqrt(1000000 * 500000)
+	assert_eq!(
+		share_supply,
+		PoolPairQueryShareSupplyResponse {
+			tracked: Uint128::new(707106),
+			bank: None
+		}
+	);
+
+	deps.querier
+		.update_balance(AddressFactory::ADMIN, vec![coin(700000, LP_TOKEN)]);
+	let share_supply: PoolPairQueryShareSupplyResponse =
+		from_json(query(deps.as_ref(), env, PoolPairQueryMsg::ShareSupply).unwrap()).unwrap();
+	assert_eq!(
+		share_supply,
+		PoolPairQueryShareSupplyResponse {
+			tracked: Uint128::new(707106),
+			bank: Some(Uint128::new(700000))
+		}
+	);
+}
@@ -1,17 +1,16 @@
 use cosmwasm_std::{
 	coin, from_json,
 	testing::{mock_env, mock_info},
-	Coin, Decimal, Uint128,
+	BankMsg, Coin, CosmosMsg, Decimal, Uint128,
 };
 
 use crate::{
-	contract::{execute, query},
+	contract::{execute, query, shares::tracked_share_supply},
 	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
 	tests::{
 		deps, init, inner_share_in_assets, pool_balance, AddressFactory, LEFT_TOKEN_AMT, LP_TOKEN, PAIR_DENOMS,
 		RIGHT_TOKEN_AMT,
 	},
-	workarounds::total_supply_workaround,
 };
 
 #[test]
@@ -22,7 +21,7 @@ fn does_what_it_says() {
 	let env = mock_env();
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_shares = total_supply_workaround(LP_TOKEN);
+	let total_shares = tracked_share_supply(LP_TOKEN);
 	let share_value = inner_share_in_assets(pb, 1000, total_shares.u128());
 
 	let share_value_query_result: [Coin; 2] = from_json(
@@ -47,7 +46,7 @@ fn does_not_change_with_decreased_liquidity() {
 	let env = mock_env();
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_shares = total_supply_workaround(LP_TOKEN);
+	let total_shares = tracked_share_supply(LP_TOKEN);
 	let actual_share_value = inner_share_in_assets(pb, 1000, total_shares.u128());
 
 	let sender = AddressFactory::random_address();
@@ -103,12 +102,13 @@ fn proportionally_changes_if_imbalanced_liquidity_provided() {
 			slippage_tolerance: None,
 			receiver: None,
 			receiver_payload: None,
+			auto_stake: false,
 		},
 	)
 	.unwrap();
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_shares = total_supply_workaround(LP_TOKEN);
+	let total_shares = tracked_share_supply(LP_TOKEN);
 	let expected_share_value = inner_share_in_assets([pb[0] + 50000, pb[1] + 25100], 1000, total_shares.u128());
 
 	deps.querier.update_balance(
@@ -145,10 +145,9 @@ fn proportionally_changes_on_swap() {
 	let info = mock_info(&sender, &[coin(100000, PAIR_DENOMS[0])]);
 
 	let pb = pool_balance(PAIR_DENOMS, &deps.querier);
-	let total_shares = total_supply_workaround(LP_TOKEN);
-	let expected_share_value = inner_share_in_assets([pb[0] + 100000, pb[1] - 100000 / 2], 1000, total_shares.u128());
+	let total_shares = tracked_share_supply(LP_TOKEN);
 
-	execute(
+	let res = execute(
 		deps.as_mut(),
 		env.clone(),
 		info,
@@ -157,15 +156,26 @@ fn proportionally_changes_on_swap() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
+	// The swap result and the maker fee
+	let total_sent = res
+		.messages
+		.into_iter()
+		.filter_map(|x| match x.msg {
+			CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount[0].amount.u128()),
+			_ => None,
+		})
+		.sum::<u128>();
+	let expected_share_value = inner_share_in_assets([pb[0] + 100000, pb[1] - total_sent], 1000, total_shares.u128());
 
 	deps.querier.update_balance(
 		env.contract.address.clone(),
 		vec![
 			coin(LEFT_TOKEN_AMT + 100000, PAIR_DENOMS[0]),
-			coin(RIGHT_TOKEN_AMT - 100000 / 2, PAIR_DENOMS[1]),
+			coin(RIGHT_TOKEN_AMT - total_sent, PAIR_DENOMS[1]),
 		],
 	);
 
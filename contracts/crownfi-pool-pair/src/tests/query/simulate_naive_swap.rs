@@ -18,6 +18,7 @@ fn simulate_swap_assuming_infinity_liquidity() {
 			env.clone(),
 			PoolPairQueryMsg::SimulateNaiveSwap {
 				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: None,
 			},
 		)
 		.unwrap(),
@@ -18,14 +18,16 @@ fn matches_actual_swap_function() {
 			env.clone(),
 			PoolPairQueryMsg::SimulateSwap {
 				offer: coin(10000, PAIR_DENOMS[0]),
+				referral_fee_bps: None,
 			},
 		)
 		.unwrap(),
 	)
 	.unwrap();
 
-	assert_eq!(4902, simulate_swap.result_amount.u128());
-	assert_eq!(49, simulate_swap.spread_amount.u128());
+	assert_eq!(4900, simulate_swap.result_amount.u128());
+	assert_eq!(50, simulate_swap.spread_amount.u128());
 	assert_eq!(24, simulate_swap.maker_fee_amount.u128());
-	assert_eq!(49, simulate_swap.total_fee_amount.u128());
+	assert_eq!(50, simulate_swap.total_fee_amount.u128());
+	assert!(!simulate_swap.fee_on_input);
 }
@@ -5,10 +5,9 @@ use cosmwasm_std::{
 };
 
 use crate::{
-	contract::{execute, query},
+	contract::{execute, query, shares::tracked_share_supply},
 	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
 	tests::{deps, init, AddressFactory, LP_TOKEN, PAIR_DENOMS},
-	workarounds::total_supply_workaround,
 };
 
 #[test]
@@ -18,7 +17,7 @@ fn must_equal_total_supply() {
 
 	let env = mock_env();
 
-	let total_shares = total_supply_workaround(LP_TOKEN);
+	let total_shares = tracked_share_supply(LP_TOKEN);
 	let total_shares_query_result: Uint128 =
 		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::TotalShares).unwrap()).unwrap();
 	assert_eq!(total_shares, total_shares_query_result);
@@ -45,6 +44,7 @@ fn increases_when_liquidity_provided() {
 			slippage_tolerance: None,
 			receiver: None,
 			receiver_payload: None,
+			auto_stake: false,
 		},
 	)
 	.unwrap();
@@ -1,8 +1,9 @@
 use cosmwasm_std::{
 	coin, from_json,
 	testing::{mock_env, mock_info},
-	Attribute, Decimal, Timestamp, Uint128,
+	BankMsg, CosmosMsg, Decimal, SubMsg, Timestamp, Uint128,
 };
+use sei_cosmwasm::SeiMsg;
 
 use crate::{
 	contract::{execute, query},
@@ -30,10 +31,11 @@ fn hourly_volume_sum() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
-	let maker_fee_amt1 = maker_fee_from_attrs(res.attributes);
+	let output_amt1 = total_output(res.messages);
 
 	env.block.time = Timestamp::from_seconds(1725410702);
 	let amt2 = 50000;
@@ -47,10 +49,11 @@ fn hourly_volume_sum() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
-	let maker_fee_amt2 = maker_fee_from_attrs(res.attributes);
+	let output_amt2 = total_output(res.messages);
 
 	env.block.time = Timestamp::from_seconds(1725411002);
 	let hourly_volume_sum: VolumeQueryResponse = from_json(
@@ -78,7 +81,7 @@ fn hourly_volume_sum() {
 
 	assert_eq!(
 		hourly_volume_sum.volume.each_ref().map(Uint128::u128),
-		[amt1 + (amt2 * 2 - maker_fee_amt2), (amt1 / 2 - maker_fee_amt1) + amt2]
+		[amt1 + output_amt2, output_amt1 + amt2]
 	);
 
 	let hourly_volume_sum2: VolumeQueryResponse = from_json(
@@ -125,10 +128,11 @@ fn daily_volume_sum() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
-	let maker_fee_amt1 = maker_fee_from_attrs(res.attributes);
+	let output_amt1 = total_output(res.messages);
 
 	env.block.time = Timestamp::from_seconds(1725490800);
 	let amt2 = 50000;
@@ -142,10 +146,11 @@ fn daily_volume_sum() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
-	let maker_fee_amt2 = maker_fee_from_attrs(res.attributes);
+	let output_amt2 = total_output(res.messages);
 
 	env.block.time = Timestamp::from_seconds(1725493500);
 
@@ -173,7 +178,7 @@ fn daily_volume_sum() {
 
 	assert_eq!(
 		daily_volume_sum.volume.each_ref().map(Uint128::u128),
-		[amt1 + (amt2 * 2 - maker_fee_amt2), (amt1 / 2 - maker_fee_amt1) + amt2]
+		[amt1 + output_amt2, output_amt1 + amt2]
 	);
 
 	let daily_volume_sum2: VolumeQueryResponse = from_json(
@@ -220,27 +225,25 @@ fn total_volume_sum() {
 			slippage_tolerance: Some(Decimal::bps(1000)),
 			receiver: None,
 			receiver_payload: None,
+			referral: None,
 		},
 	)
 	.unwrap();
-	let maker_fee = maker_fee_from_attrs(res.attributes);
+	let output_amt = total_output(res.messages);
 
 	env.block.time = Timestamp::from_seconds(1725409802);
 	let total_volume_sum: VolumeQueryResponse =
 		from_json(query(deps.as_ref(), env.clone(), PoolPairQueryMsg::TotalVolumeSum).unwrap()).unwrap();
 
-	assert_eq!(
-		total_volume_sum.volume.each_ref().map(Uint128::u128),
-		[amt, amt / 2 - maker_fee]
-	);
+	assert_eq!(total_volume_sum.volume.each_ref().map(Uint128::u128), [amt, output_amt]);
 }
 
-fn maker_fee_from_attrs(attrs: Vec<Attribute>) -> u128 {
-	for attr in attrs {
-		if attr.key == "maker_fee_amount" {
-			return attr.value.parse().unwrap();
-		}
-	}
-
-	panic!("wrong attributes");
+/// The swap result plus the maker fee
+fn total_output(msgs: Vec<SubMsg<SeiMsg>>) -> u128 {
+	msgs.into_iter()
+		.filter_map(|x| match x.msg {
+			CosmosMsg::Bank(BankMsg::Send { amount, .. }) => Some(amount[0].amount.u128()),
+			_ => None,
+		})
+		.sum()
 }
@@ -2,12 +2,11 @@ use bytemuck::Zeroable;
 use cosmwasm_std::{
 	coin, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, Fraction, MessageInfo, Reply, ReplyOn, Response, SubMsg, SubMsgResult, WasmMsg
 };
-use crownfi_cw_common::storage::{item::StoredItem, SerializableItem};
+use crownfi_cw_common::storage::item::StoredItem;
 use crownfi_pool_pair_contract::{
-	contract::pool::{PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult},
-	msg::{PoolPairExecuteMsg, PoolPairQueryMsg},
+	msg::{PoolPairExecuteMsg, PoolPairSwapReferral},
+	raw_query::PoolPairRawState,
 };
-use crownfi_swaps_common::data_types::pair_id::CanonicalPoolPairIdentifier;
 use cw2::set_contract_version;
 use cw_utils::{nonpayable, one_coin, ParseReplyError};
 use sei_cosmwasm::{SeiMsg, SeiQueryWrapper};
@@ -56,6 +55,7 @@ pub fn execute(
 			expectation,
 			// unwrapper,
 			receiver,
+			referral,
 		} => process_execute_swaps(
 			deps,
 			msg_info,
@@ -64,6 +64,7 @@ pub fn execute(
 			expectation,
 			// unwrapper,
 			receiver,
+			referral,
 		),
 		SwapRouterExecuteMsg::NextStep {} => process_execute_next_step(deps, msg_info),
 	}
@@ -77,6 +78,7 @@ fn process_execute_swaps(
 	expectation: Option<SwapRouterExpectation>,
 	// unwrapper: Option<Addr>,
 	receiver: SwapReceiver,
+	referral: Option<PoolPairSwapReferral>,
 ) -> Result<Response<SeiMsg>, SwapRouterContractError> {
 	if SwapRouterState::load()?.is_some() {
 		return Err(SwapRouterContractError::AlreadyRoutingSwaps);
@@ -95,10 +97,15 @@ fn process_execute_swaps(
 		}
 		SwapReceiver::WasmUnwrap { contract, receiver } => (receiver.try_into()?, contract.try_into()?, 2),
 	};
+	let (referrer, referral_fee_bps) = match referral.as_ref() {
+		Some(referral) => ((&referral.address).try_into()?, referral.fee_bps as u128),
+		None => (Zeroable::zeroed(), 0),
+	};
 
 	let new_state = SwapRouterState {
 		receiver,
 		unwrapper,
+		referrer,
 		unwrapper_kind,
 		intermediate_slippage_tolerance: intermediate_slippage_tolerance
 			.map(|num| num.numerator().u128())
@@ -111,6 +118,7 @@ fn process_execute_swaps(
 			.as_ref()
 			.map(|expectation| expectation.slippage_tolerance.numerator().u128())
 			.unwrap_or(u128::MAX),
+		referral_fee_bps,
 	};
 	let mut stored_swappers = get_swapper_addresses();
 	for swapper in swappers.iter().skip(1).rev() {
@@ -130,6 +138,8 @@ fn process_execute_swaps(
 				slippage_tolerance: intermediate_slippage_tolerance,
 				receiver: None, // self
 				receiver_payload: Some(b"\"next_step\"".into()),
+				// Only the last swap pays the referral fee
+				referral: if swappers.len() == 1 { referral } else { None },
 			})?,
 			funds: msg_info.funds,
 		}),
@@ -151,6 +161,15 @@ fn process_execute_next_step(
 		} else {
 			Some(Decimal::new(router_state.intermediate_slippage_tolerance.into()))
 		};
+		// Only the last swap pays the referral fee
+		let referral = if stored_swappers.len() == 0 && router_state.referrer != Zeroable::zeroed() {
+			Some(PoolPairSwapReferral {
+				address: Addr::try_from(router_state.referrer)?,
+				fee_bps: router_state.referral_fee_bps as u16,
+			})
+		} else {
+			None
+		};
 		Ok(Response::new().add_message(WasmMsg::Execute {
 			contract_addr: Addr::try_from(swapper.as_ref())?.into_string(),
 			msg: to_json_binary(&PoolPairExecuteMsg::Swap {
@@ -158,6 +177,7 @@ fn process_execute_next_step(
 				slippage_tolerance,
 				receiver: None, // self
 				receiver_payload: Some(b"\"next_step\"".into()),
+				referral,
 			})?,
 			funds: msg_info.funds,
 		}))
@@ -228,38 +248,37 @@ pub fn query(
 	msg: SwapRouterQueryMsg,
 ) -> Result<Binary, SwapRouterContractError> {
 	Ok(match msg {
-		SwapRouterQueryMsg::SimulateSwaps { offer, swappers } => {
+		SwapRouterQueryMsg::SimulateSwaps {
+			offer,
+			swappers,
+			referral_fee_bps,
+		} => {
 			let mut current_denom = offer.denom;
 			let mut current_naive_amount = offer.amount;
 			let mut current_actual_amount = offer.amount;
-			for swapper_addr in swappers.into_iter() {
-				let pool_pair = CanonicalPoolPairIdentifier::deserialize_to_owned(
-					&deps
-						.querier
-						.query_wasm_raw(swapper_addr.clone(), CanonicalPoolPairIdentifier::namespace())?
-						.unwrap_or_default(),
-				)?;
-				// Smart queries are very expensive, crownfi_pool_pair_contract should probably provide helper
-				// functions built on top of raw queries to facilitate this. Though this makes us
-				// forward-compatible with contracts which have the same functionality.
-				let swap_result = deps.querier.query_wasm_smart::<PoolPairCalcSwapResult>(
-					swapper_addr.clone(),
-					&PoolPairQueryMsg::SimulateSwap {
-						offer: coin(current_actual_amount.into(), current_denom.clone()),
-					},
-				)?;
-				current_actual_amount = swap_result.result_amount;
-				let naive_swap_result = deps.querier.query_wasm_smart::<PoolPairCalcNaiveSwapResult>(
-					swapper_addr.clone(),
-					&PoolPairQueryMsg::SimulateNaiveSwap {
-						offer: coin(current_actual_amount.into(), current_denom.clone()),
-					},
-				)?;
-				current_naive_amount = naive_swap_result.result_amount;
-				current_denom = pool_pair
+			let last_index = swappers.len().saturating_sub(1);
+			for (index, swapper_addr) in swappers.into_iter().enumerate() {
+				let referral_fee_bps = if index == last_index {
+					referral_fee_bps.unwrap_or_default()
+				} else {
+					0
+				};
+				let pool = PoolPairRawState::query(&deps.querier, swapper_addr)?;
+				let next_denom = pool
+					.pair_id
 					.other_denom(&current_denom)
 					.ok_or(SwapRouterContractError::FundsIncompatibleWithSwapRoute)?
 					.to_owned();
+				current_actual_amount = pool
+					.simulate_swap(
+						&coin(current_actual_amount.into(), current_denom.clone()),
+						referral_fee_bps,
+					)?
+					.result_amount;
+				current_naive_amount = pool
+					.simulate_naive_swap(&coin(current_naive_amount.into(), current_denom), referral_fee_bps)?
+					.result_amount;
+				current_denom = next_denom;
 			}
 			to_json_binary(&SwapRouterSimulateSwapsResponse {
 				result_denom: current_denom,
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use crownfi_pool_pair_contract::error::PoolPairContractError;
 use crownfi_swaps_common::{error::CrownfiSwapsCommonError, impl_from_cosmwasm_std_error_common};
 use cw_utils::{ParseReplyError, PaymentError};
 use thiserror::Error;
@@ -11,6 +12,8 @@ pub enum SwapRouterContractError {
 	SwapsCommon(#[from] CrownfiSwapsCommonError),
 	#[error("Payment error: {0}")]
 	PaymentError(#[from] PaymentError),
+	#[error("{0}")]
+	PoolPair(#[from] PoolPairContractError),
 	#[error("Failed reply: {0}")]
 	FailedReply(#[from] ParseReplyError),
 	#[error("Cannot execute a swap route while the previous one is incomplete")]
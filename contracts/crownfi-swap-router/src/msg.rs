@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use crownfi_pool_pair_contract::msg::PoolPairSwapReferral;
 
 #[cw_serde]
 pub struct SwapRouterInstantiateMsg {
@@ -31,6 +32,9 @@ pub enum SwapRouterExecuteMsg {
 		// unwrapper: Option<Addr>,
 		/// The account receiving the resulting asset, defaults to the sender.
 		receiver: SwapReceiver,
+		/// Pays part of the final swap's result to whoever referred the swap, this is taken before `expectation` is
+		/// checked.
+		referral: Option<PoolPairSwapReferral>,
 	},
 	NextStep,
 }
@@ -46,7 +50,12 @@ pub enum SwapReceiver {
 #[derive(QueryResponses)]
 pub enum SwapRouterQueryMsg {
 	#[returns(SwapRouterSimulateSwapsResponse)]
-	SimulateSwaps { offer: Coin, swappers: Vec<Addr> },
+	SimulateSwaps {
+		offer: Coin,
+		swappers: Vec<Addr>,
+		/// The referral fee (in bps) paid on the final swap, if any.
+		referral_fee_bps: Option<u16>,
+	},
 }
 #[cw_serde]
 pub struct SwapRouterSimulateSwapsResponse {
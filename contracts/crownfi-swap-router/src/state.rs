@@ -16,6 +16,8 @@ pub struct SwapRouterState {
 	pub receiver: SeiCanonicalAddr,
 	/// If == Zeroable::zeroed(), None
 	pub unwrapper: SeiCanonicalAddr,
+	/// If == Zeroable::zeroed(), None
+	pub referrer: SeiCanonicalAddr,
 	/// 0 == None, 1 == ERC20, 2 = CW20, u128 enums are unstable and anything lower does not align with the struct >:(
 	pub unwrapper_kind: u128,
 	/// If == u128::MAX, ignore. Else, Convert verbatim to Decimal
@@ -24,6 +26,8 @@ pub struct SwapRouterState {
 	pub expected_amount: u128,
 	/// If == u128::MAX, ignore. Else, Convert verbatim to Decimal
 	pub slippage_tolerance: u128,
+	/// Paid to `referrer` on the last swap, u128 for the same alignment reasons as above
+	pub referral_fee_bps: u128,
 }
 
 impl_serializable_as_ref!(SwapRouterState);
@@ -1,21 +1,16 @@
 use std::path::PathBuf;
 
-use cosmwasm_std::Empty;
 use crownfi_sei_sdk_autogen::CrownfiSdkMaker;
 
 use crownfi_cw20_wrapper::msg::*;
+use crownfi_dca_contract::msg::*;
 use crownfi_erc20_wrapper::msg::*;
+use crownfi_limit_orders_contract::msg::*;
+use crownfi_lp_incentives_contract::msg::*;
 use crownfi_pool_factory_contract::msg::*;
 use crownfi_pool_pair_contract::msg::*;
 use crownfi_swap_router_contract::msg::*;
 
-#[cosmwasm_schema::cw_serde]
-#[derive(cosmwasm_schema::QueryResponses)]
-enum EmptyQuery {
-	#[returns(Empty)]
-	Empty {},
-}
-
 type Void = ();
 
 fn main() -> color_eyre::Result<()> {
@@ -48,11 +43,18 @@ fn main() -> color_eyre::Result<()> {
 		.add_contract::<SwapRouterInstantiateMsg, SwapRouterExecuteMsg, SwapRouterQueryMsg, Void, Void, Void>(
 			"swap_router",
 		)?
+		.add_contract::<LpIncentivesInstantiateMsg, LpIncentivesExecuteMsg, LpIncentivesQueryMsg, Void, Void, Void>(
+			"lp_incentives",
+		)?
+		.add_contract::<LimitOrdersInstantiateMsg, LimitOrdersExecuteMsg, LimitOrdersQueryMsg, Void, Void, Void>(
+			"limit_orders",
+		)?
+		.add_contract::<DcaInstantiateMsg, DcaExecuteMsg, DcaQueryMsg, Void, Void, Void>("dca")?
 		.generate_code(swaps_dist)?;
 
 	CrownfiSdkMaker::new()
 		.add_contract::<Void, CW20WrapperExecMsg, CW20WrapperQueryMsg, Void, Void, Void>("cw_20_wrapper")?
-		.add_contract::<Void, ERC20WrapperExecMsg, EmptyQuery, Void, Void, Void>("erc_20_wrapper")?
+		.add_contract::<Void, ERC20WrapperExecMsg, ERC20WrapperQueryMsg, Void, Void, Void>("erc_20_wrapper")?
 		.generate_code(wrapper_dist)?;
 
 	Ok(())
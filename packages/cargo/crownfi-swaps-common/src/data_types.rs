@@ -1 +1,2 @@
 pub mod pair_id;
+pub mod pending_admin;
@@ -0,0 +1,73 @@
+use bytemuck::{Pod, Zeroable};
+use cosmwasm_std::{Addr, StdError};
+use crownfi_cw_common::{
+	data_types::canonical_addr::SeiCanonicalAddr,
+	impl_serializable_as_ref,
+	storage::{item::StoredItem, OZeroCopy, SerializableItem},
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::CrownfiSwapsCommonError;
+
+const PENDING_ADMIN_NAMESPACE: &str = "pend_adm";
+/// An admin transfer which has been proposed by the current admin, but not yet accepted by the new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
+#[repr(C)]
+pub struct PendingAdminTransfer {
+	/// The proposed admin, only they can accept the transfer
+	pub new_admin: SeiCanonicalAddr,
+	/// Unix timestamp (in seconds) after which the transfer may be accepted
+	pub accept_after: u64,
+}
+impl_serializable_as_ref!(PendingAdminTransfer);
+impl StoredItem for PendingAdminTransfer {
+	fn namespace() -> &'static [u8] {
+		PENDING_ADMIN_NAMESPACE.as_bytes()
+	}
+}
+impl PendingAdminTransfer {
+	pub fn load_non_empty<E>() -> Result<OZeroCopy<Self>, E>
+	where
+		Self: Sized,
+		E: From<StdError> + From<CrownfiSwapsCommonError>,
+	{
+		match Self::load()? {
+			Some(result) => Ok(result),
+			None => Err(CrownfiSwapsCommonError::NoPendingAdmin.into()),
+		}
+	}
+	/// Loads the pending transfer and ensures that it can be accepted by the specified address at the specified time.
+	pub fn load_acceptable<E>(sender: &SeiCanonicalAddr, timestamp: u64) -> Result<OZeroCopy<Self>, E>
+	where
+		E: From<StdError> + From<CrownfiSwapsCommonError>,
+	{
+		let pending = Self::load_non_empty::<E>()?;
+		if pending.new_admin != *sender {
+			return Err(
+				CrownfiSwapsCommonError::Unauthorized("Sender is not the currently proposed admin".into()).into(),
+			);
+		}
+		if timestamp < pending.accept_after {
+			return Err(CrownfiSwapsCommonError::Timelocked(pending.accept_after).into());
+		}
+		Ok(pending)
+	}
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, JsonSchema)]
+pub struct PendingAdminTransferJsonable {
+	/// The proposed admin, only they can accept the transfer
+	pub new_admin: Addr,
+	/// Unix timestamp (in seconds) after which the transfer may be accepted
+	pub accept_after: u64,
+}
+impl TryFrom<&PendingAdminTransfer> for PendingAdminTransferJsonable {
+	type Error = StdError;
+	fn try_from(value: &PendingAdminTransfer) -> Result<Self, Self::Error> {
+		Ok(PendingAdminTransferJsonable {
+			new_admin: value.new_admin.try_into()?,
+			accept_after: value.accept_after,
+		})
+	}
+}
@@ -15,4 +15,8 @@ pub enum CrownfiSwapsCommonError {
 	NeedsTwoCoins,
 	#[error("This action would result in a 0 payout")]
 	PayoutIsZero,
+	#[error("There is no pending admin transfer")]
+	NoPendingAdmin,
+	#[error("This action is timelocked until {0}")]
+	Timelocked(u64),
 }
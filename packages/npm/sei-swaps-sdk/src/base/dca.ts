@@ -0,0 +1,87 @@
+/* eslint-disable */
+/**
+ * This file was automatically generated by crownfi-sei-sdk-autogen.
+ * DO NOT MODIFY IT BY HAND.
+ * The Rust definition of the associated structs is the source of truth!!
+ */
+import {Addr, ArrayOf_DcaPlanInfo, DcaConfigJsonable, DcaExecuteMsg, DcaQueryMsg, Decimal, Nullable_DcaPlanInfo, Uint128} from "./types.js";
+import {Coin} from "@cosmjs/amino";
+import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
+import {QueryClient} from "@cosmjs/stargate";
+import {ContractBase} from "@crownfi/sei-utils";
+export class DcaContract<Q extends QueryClient & WasmExtension> extends ContractBase<Q> {
+	/** Returns the contract's config. */
+	queryConfig(): Promise<DcaConfigJsonable> {
+		const msg = "config" satisfies DcaQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the specified plan, if it hasn't been closed. */
+	queryPlan(args: {
+		"plan_id": number
+	}): Promise<Nullable_DcaPlanInfo> {
+		const msg = {"plan": args} satisfies DcaQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all plans which still have something left to swap, in the order they were created. */
+	queryActivePlans(args: {
+		"after"?: number | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_DcaPlanInfo> {
+		const msg = {"active_plans": args} satisfies DcaQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the specified account's plans which haven't been closed, in the order they were created. */
+	queryPlansByOwner(args: {
+		"owner": Addr
+	}): Promise<ArrayOf_DcaPlanInfo> {
+		const msg = {"plans_by_owner": args} satisfies DcaQueryMsg;
+		return this.query(msg);
+	}
+	/** Updates the contract's config */
+	buildUpdateConfigIx(args: {
+		/** The head honcho */
+		"admin"?: Addr | null,
+		/** The swap router used to execute each plan's route */
+		"router"?: Addr | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"update_config": args} satisfies DcaExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Creates a plan which swaps the coin sent along with this message in installments. The coin is held by this contract until it has all been swapped or the plan is withdrawn. */
+	buildCreatePlanIx(args: {
+		/** The amount swapped each time the plan is executed. The last execution swaps whatever is left. */
+		"amount_per_execution": Uint128,
+		/** The minimum amount of time (in seconds) between executions */
+		"interval_seconds": number,
+		/** The minimum amount of the output to receive per unit of the deposit swapped. Executions which would receive less than this fail, so the plan waits until the price recovers. Must be greater than 0. */
+		"min_price": Decimal,
+		/** The swappers the router will use, in order */
+		"route": Addr[],
+		/** A value between 0 and 1 determining how much worse the result of each step of the route can be compared to its result assuming infinite liquidity. e.g. 0.01 means a 1% slippage tolerance. */
+		"slippage_tolerance": Decimal
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"create_plan": args} satisfies DcaExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Swaps the next installment of a plan if its interval has passed. Anyone can do this. */
+	buildExecutePlanIx(args: {
+		"plan_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"execute_plan": args} satisfies DcaExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sends the output received so far to the plan's owner, the plan keeps running. Only the plan's owner may do this. */
+	buildWithdrawIx(args: {
+		"plan_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"withdraw": args} satisfies DcaExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Closes a plan, sending the deposit which has yet to be swapped along with all the output received to the plan's owner. Only the plan's owner may do this. */
+	buildClosePlanIx(args: {
+		"plan_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"close_plan": args} satisfies DcaExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+}
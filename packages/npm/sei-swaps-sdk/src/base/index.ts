@@ -8,3 +8,6 @@ export * from "./types.js";
 export * from "./pool_factory.js";
 export * from "./pool_pair.js";
 export * from "./swap_router.js";
+export * from "./lp_incentives.js";
+export * from "./limit_orders.js";
+export * from "./dca.js";
@@ -0,0 +1,88 @@
+/* eslint-disable */
+/**
+ * This file was automatically generated by crownfi-sei-sdk-autogen.
+ * DO NOT MODIFY IT BY HAND.
+ * The Rust definition of the associated structs is the source of truth!!
+ */
+import {Addr, ArrayOf_LimitOrderInfo, Decimal, LimitOrderSimulateExecutionResponse, LimitOrdersConfigJsonable, LimitOrdersExecuteMsg, LimitOrdersQueryMsg, Nullable_LimitOrderInfo} from "./types.js";
+import {Coin} from "@cosmjs/amino";
+import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
+import {QueryClient} from "@cosmjs/stargate";
+import {ContractBase} from "@crownfi/sei-utils";
+export class LimitOrdersContract<Q extends QueryClient & WasmExtension> extends ContractBase<Q> {
+	/** Returns the contract's config. */
+	queryConfig(): Promise<LimitOrdersConfigJsonable> {
+		const msg = "config" satisfies LimitOrdersQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the specified order, if it's still open. */
+	queryOrder(args: {
+		"order_id": number
+	}): Promise<Nullable_LimitOrderInfo> {
+		const msg = {"order": args} satisfies LimitOrdersQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all open orders, in the order they were placed. */
+	queryOrders(args: {
+		"after"?: number | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_LimitOrderInfo> {
+		const msg = {"orders": args} satisfies LimitOrdersQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the specified account's open orders, in the order they were placed. */
+	queryOrdersByOwner(args: {
+		"owner": Addr
+	}): Promise<ArrayOf_LimitOrderInfo> {
+		const msg = {"orders_by_owner": args} satisfies LimitOrdersQueryMsg;
+		return this.query(msg);
+	}
+	/** Tells you whether or not the specified order can currently be executed, and what executing it would result in. */
+	querySimulateExecution(args: {
+		"order_id": number
+	}): Promise<LimitOrderSimulateExecutionResponse> {
+		const msg = {"simulate_execution": args} satisfies LimitOrdersQueryMsg;
+		return this.query(msg);
+	}
+	/** Updates the contract's config */
+	buildUpdateConfigIx(args: {
+		/** The head honcho */
+		"admin"?: Addr | null,
+		/** The pool factory, orders can only be placed on pools it has created */
+		"factory"?: Addr | null,
+		/** The portion (in bps) of an order's result which is given to the keeper who executed it. */
+		"keeper_bounty_bps"?: number | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"update_config": args} satisfies LimitOrdersExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Places an order which swaps the coin sent along with this message once the pool's price reaches the limit price. The coin is held by this contract until the order is executed or cancelled. */
+	buildPlaceOrderIx(args: {
+		/** The minimum amount of the other asset to receive per unit of the coin sent, after the keeper's bounty is taken. */
+		"limit_price": Decimal,
+		/** The pool to swap with, which must have been created by the factory and not be deprecated */
+		"pool": Addr
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"place_order": args} satisfies LimitOrdersExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Cancels an order and refunds the escrowed coin. Only the order's owner may do this. */
+	buildCancelOrderIx(args: {
+		"order_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_order": args} satisfies LimitOrdersExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Executes an order if `SimulateSwap` on its pool meets its limit price. Anyone can do this, and they will receive the keeper bounty. */
+	buildExecuteOrderIx(args: {
+		"order_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"execute_order": args} satisfies LimitOrdersExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Used internally to receive the result of an order's swap. */
+	buildCompleteOrderIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"complete_order": {}} satisfies LimitOrdersExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+}
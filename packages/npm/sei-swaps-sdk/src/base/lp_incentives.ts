@@ -0,0 +1,110 @@
+/* eslint-disable */
+/**
+ * This file was automatically generated by crownfi-sei-sdk-autogen.
+ * DO NOT MODIFY IT BY HAND.
+ * The Rust definition of the associated structs is the source of truth!!
+ */
+import {Addr, ArrayOf_Coin, ArrayOf_LpIncentivesPoolInfo, ArrayOf_LpIncentivesPosition, LpIncentivesConfigJsonable, LpIncentivesExecuteMsg, LpIncentivesQueryMsg, Nullable_LpIncentivesPoolInfo, Uint128} from "./types.js";
+import {Coin} from "@cosmjs/amino";
+import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
+import {QueryClient} from "@cosmjs/stargate";
+import {ContractBase} from "@crownfi/sei-utils";
+export class LpIncentivesContract<Q extends QueryClient & WasmExtension> extends ContractBase<Q> {
+	/** Returns the contract's config. */
+	queryConfig(): Promise<LpIncentivesConfigJsonable> {
+		const msg = "config" satisfies LpIncentivesQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the staking state and reward programs of the specified pool. */
+	queryPool(args: {
+		"lp_denom": string
+	}): Promise<Nullable_LpIncentivesPoolInfo> {
+		const msg = {"pool": args} satisfies LpIncentivesQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the staking state and reward programs of all pools, ordered by LP denom. */
+	queryPools(args: {
+		"after"?: string | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_LpIncentivesPoolInfo> {
+		const msg = {"pools": args} satisfies LpIncentivesQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns everything the specified account has staked, along with their unclaimed rewards. */
+	queryPositions(args: {
+		"address": Addr
+	}): Promise<ArrayOf_LpIncentivesPosition> {
+		const msg = {"positions": args} satisfies LpIncentivesQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the total unclaimed rewards for the specified account. Optionally only for a single pool. */
+	queryPendingRewards(args: {
+		"address": Addr,
+		"lp_denom"?: string | null
+	}): Promise<ArrayOf_Coin> {
+		const msg = {"pending_rewards": args} satisfies LpIncentivesQueryMsg;
+		return this.query(msg);
+	}
+	/** Updates the contract's config */
+	buildUpdateConfigIx(args: {
+		/** The head honcho */
+		"admin"?: Addr | null,
+		/** The pool factory, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
+		"factory"?: Addr | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"update_config": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Adds a reward program to the pool associated with the specified LP denom. The rewards must be sent along with this message. Only the admin or the factory may do this, and only for pools the factory created. */
+	buildAddRewardProgramIx(args: {
+		/** Unix timestamp (in seconds) of when all rewards will have been released. */
+		"end_time": number,
+		/** The pool's LP token denom, i.e. `factory/<pair>/lp` */
+		"lp_denom": string,
+		/** Unix timestamp (in seconds) of when rewards start being released. Defaults to now. */
+		"start_time"?: number | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"add_reward_program": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Stakes the LP tokens sent along with this message. The pool must have had a reward program added to it. */
+	buildStakeIx(args: {
+		/** The account which will own the staked tokens, defaults to the sender. */
+		"receiver"?: Addr | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"stake": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Unstakes LP tokens. Unclaimed rewards remain claimable. */
+	buildUnstakeIx(args: {
+		/** The amount to unstake, defaults to everything staked. */
+		"amount"?: Uint128 | null,
+		/** The pool's LP token denom */
+		"lp_denom": string,
+		/** The account receiving the LP tokens, defaults to the sender. */
+		"receiver"?: Addr | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"unstake": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Unstakes everything from the specified pool without bringing its rewards up to date, in case that fails. All unclaimed rewards from the pool are forfeited. */
+	buildEmergencyUnstakeIx(args: {
+		/** The pool's LP token denom */
+		"lp_denom": string,
+		/** The account receiving the LP tokens, defaults to the sender. */
+		"receiver"?: Addr | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"emergency_unstake": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Claims all pending rewards */
+	buildClaimIx(args: {
+		/** If specified, only claim the rewards earned from this pool. */
+		"lp_denom"?: string | null,
+		/** The account receiving the rewards, defaults to the sender. */
+		"receiver"?: Addr | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"claim": args} satisfies LpIncentivesExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+}
@@ -4,7 +4,7 @@
  * DO NOT MODIFY IT BY HAND.
  * The Rust definition of the associated structs is the source of truth!!
  */
-import {Addr, ArrayOf_PoolFactoryCreatedPair, Binary, Nullable_Addr, PoolFactoryConfigJsonable, PoolFactoryExecuteMsg, PoolFactoryQueryMsg} from "./types.js";
+import {Addr, ArrayOf_PoolFactoryBatchSimulateResult, ArrayOf_PoolFactoryCreatedPair, ArrayOf_PoolFactoryDenomTokenMetadata, ArrayOf_PoolFactoryDeprecatedPair, ArrayOf_PoolFactoryPoolCodeVersion, ArrayOf_PoolFactoryPoolSnapshot, ArrayOf_PoolFactoryRole, ArrayOf_PoolFactoryRoleHolder, ArrayOf_String, Binary, Nullable_PendingAdminTransferJsonable, Nullable_PoolFactoryCreatedPair, Nullable_PoolFactoryPendingConfig, Nullable_PoolFactoryPendingPoolFees, Nullable_PoolFactoryTokenMetadata, PoolFactoryBatchSimulateRequest, PoolFactoryConfigJsonable, PoolFactoryDenomFilter, PoolFactoryExecuteMsg, PoolFactoryPairInfo, PoolFactoryPoolCreationRulesJsonable, PoolFactoryQueryMsg, PoolFactoryRole, PoolFactoryTokenMetadata, PoolFactoryUnderlyingAsset, PoolFactoryUserPositions, PoolFactoryWrapperConfigJsonable, Uint128} from "./types.js";
 import {Coin} from "@cosmjs/amino";
 import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
 import {QueryClient} from "@cosmjs/stargate";
@@ -15,13 +15,160 @@ export class PoolFactoryContract<Q extends QueryClient & WasmExtension> extends
 		const msg = "config" satisfies PoolFactoryQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the proposed admin transfer, if any. */
+	queryPendingAdmin(): Promise<Nullable_PendingAdminTransferJsonable> {
+		const msg = "pending_admin" satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the config changes waiting for the timelock to elapse, if any. */
+	queryPendingConfig(): Promise<Nullable_PoolFactoryPendingConfig> {
+		const msg = "pending_config" satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the fee changes for the specified pool waiting for the timelock to elapse, if any. */
+	queryPendingFeesForPool(args: {
+		"pair": [string, string]
+	}): Promise<Nullable_PoolFactoryPendingPoolFees> {
+		const msg = {"pending_fees_for_pool": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the anti-spam measures for permissionless pool creation. */
+	queryPoolCreationRules(): Promise<PoolFactoryPoolCreationRulesJsonable> {
+		const msg = "pool_creation_rules" satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the denoms used by the pool creation rules' `denom_filter`, in lexicographical order. */
+	queryDenomList(args: {
+		"after"?: string | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_String> {
+		const msg = {"denom_list": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the token wrapper contracts used to resolve the underlying asset of wrapped denoms. */
+	queryWrappers(): Promise<PoolFactoryWrapperConfigJsonable> {
+		const msg = "wrappers" satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the asset the specified denom ultimately represents. */
+	queryUnderlyingAsset(args: {
+		"denom": string
+	}): Promise<PoolFactoryUnderlyingAsset> {
+		const msg = {"underlying_asset": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the verified metadata for the specified denom, if any. */
+	queryTokenMetadata(args: {
+		"denom": string
+	}): Promise<Nullable_PoolFactoryTokenMetadata> {
+		const msg = {"token_metadata": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the verified metadata for the specified denoms. Denoms without verified metadata are omitted. */
+	queryTokenMetadataBatch(args: {
+		"denoms": string[]
+	}): Promise<ArrayOf_PoolFactoryDenomTokenMetadata> {
+		const msg = {"token_metadata_batch": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all verified token metadata, ordered by denom. */
+	queryAllTokenMetadata(args: {
+		"after"?: string | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_PoolFactoryDenomTokenMetadata> {
+		const msg = {"all_token_metadata": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the roles explicitly granted to the specified address. */
+	queryRoles(args: {
+		"address": Addr
+	}): Promise<ArrayOf_PoolFactoryRole> {
+		const msg = {"roles": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all addresses which have been granted roles, ordered by their canonical address. */
+	queryRoleHolders(args: {
+		"after"?: Addr | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_PoolFactoryRoleHolder> {
+		const msg = {"role_holders": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the code each pool is running and whether or not it has been pinned. */
+	queryPoolCodeVersions(args: {
+		"after"?: [string, string],
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_PoolFactoryPoolCodeVersion> {
+		const msg = {"pool_code_versions": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
 	/** Gets the contract address for a pair. The result may include the inverse pair if it exists. */
 	queryPairAddr(args: {
 		"pair": [string, string]
-	}): Promise<Nullable_Addr> {
+	}): Promise<Nullable_PoolFactoryCreatedPair> {
 		const msg = {"pair_addr": args} satisfies PoolFactoryQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the pair the specified address is the pool contract for, if it was created by this factory. */
+	queryPairByAddr(args: {
+		"address": Addr
+	}): Promise<Nullable_PoolFactoryCreatedPair> {
+		const msg = {"pair_by_addr": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all pairs which contain the specified denom, ordered by the other denom in the pair. */
+	queryPairsContainingDenom(args: {
+		/** The other denom of the last pair returned by a previous query */
+		"after"?: string | null,
+		"denom": string,
+		"limit"?: number | null
+	}): Promise<ArrayOf_PoolFactoryCreatedPair> {
+		const msg = {"pairs_containing_denom": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the pool's address, config, reserves, and total shares. */
+	queryPairInfo(args: {
+		"pair": [string, string]
+	}): Promise<PoolFactoryPairInfo> {
+		const msg = {"pair_info": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns all pools which have been deprecated, including those which have been replaced, ordered by their canonical address. */
+	queryDeprecatedPairs(args: {
+		"after"?: Addr | null,
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_PoolFactoryDeprecatedPair> {
+		const msg = {"deprecated_pairs": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Simulates swaps for multiple pools at once. This reads the pools' storage directly rather than querying each of them. At most 100 requests can be made at once. */
+	queryBatchSimulate(args: {
+		"requests": PoolFactoryBatchSimulateRequest[]
+	}): Promise<ArrayOf_PoolFactoryBatchSimulateResult> {
+		const msg = {"batch_simulate": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the reserves, total shares, fees, and spot price of pools. This reads the pools' storage directly rather than querying each of them.
+
+Up to `limit` pools (default 30, maximum 100) are returned at a time. */
+	queryPoolsSnapshot(args: {
+		"after"?: [string, string],
+		"limit"?: number | null
+	} = {}): Promise<ArrayOf_PoolFactoryPoolSnapshot> {
+		const msg = {"pools_snapshot": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the pools the specified address holds LP shares in, along with the value of those shares.
+
+Pools are scanned in lexicographical order. As this queries each pool, only up to `limit` pools (default 30, maximum 100) are scanned at a time. If there are more pools to scan, `next` can be used as `after` in the next query. */
+	queryUserPositions(args: {
+		"address": Addr,
+		"after"?: [string, string],
+		"limit"?: number | null
+	}): Promise<PoolFactoryUserPositions> {
+		const msg = {"user_positions": args} satisfies PoolFactoryQueryMsg;
+		return this.query(msg);
+	}
 	/** Pairs returns an array of pairs and their information according to the specified parameters in `start_after` and `limit` variables. */
 	queryPairs(args: {
 		"after"?: [string, string],
@@ -30,25 +177,104 @@ export class PoolFactoryContract<Q extends QueryClient & WasmExtension> extends
 		const msg = {"pairs": args} satisfies PoolFactoryQueryMsg;
 		return this.query(msg);
 	}
-	/** UpdateConfig updates relevant code IDs */
+	/** UpdateConfig updates relevant code IDs
+
+If a timelock is configured, changes to `pair_code_id`, fee increases, and timelock decreases are queued and must be applied with `ApplyPendingConfig` once the timelock has elapsed. Newly queued changes are added to those already queued and restart the timelock. Changes which take effect immediately cancel any queued change to the same setting. */
 	buildUpdateConfigIx(args: {
-		/** The head honcho */
-		"admin"?: Addr | null,
+		/** The CW20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
+		"cw20_wrapper"?: Addr | null,
 		/** The amount of fees (in bps) collected by the Maker contract from this pair type */
 		"default_maker_fee_bps"?: number | null,
 		/** The total fees (in bps) charged by a pair of this type */
 		"default_total_fee_bps"?: number | null,
-		/** Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
+		/** The ERC20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
+		"erc20_wrapper"?: Addr | null,
+		/** Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. Cannot be disabled while there's a pool creation fee. */
 		"fee_receiver"?: Addr | null,
+		/** The LP incentives contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. New pools will use this for `auto_stake`, existing pools can be updated with `UpdateGlobalConfigForPool`. */
+		"lp_incentives"?: Addr | null,
+		/** The highest referral fee (in bps) swaps on the pairs can pay. Existing pools can be updated with `UpdateGlobalConfigForPool`. */
+		"max_referral_fee_bps"?: number | null,
 		/** Code to use when instantiating new pool pairs */
 		"pair_code_id"?: number | null,
 		/** If true, everyone will be able to create new trading pairs */
-		"permissionless_pool_cration"?: boolean | null
+		"permissionless_pool_cration"?: boolean | null,
+		/** If true, pools can't be created for pairs which share the same underlying assets as an existing pool */
+		"reject_duplicate_underlying_pairs"?: boolean | null,
+		/** How long (in seconds) sensitive changes must be queued for before they can be applied */
+		"timelock_seconds"?: number | null
 	} = {}, funds?: Coin[]): ExecuteInstruction {
 		const msg = {"update_config": args} satisfies PoolFactoryExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
-	/** CreatePool instantiates a new pair pool contract. The pair is determined by the initial liquidity funds sent to this contract */
+	/** Applies the config changes queued by `UpdateConfig` once the timelock has elapsed. */
+	buildApplyPendingConfigIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"apply_pending_config": {}} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Discards the config changes queued by `UpdateConfig`. */
+	buildCancelPendingConfigIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_pending_config": {}} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Proposes a new admin, the transfer only takes effect once they accept it with `AcceptAdmin`. This replaces any previously proposed admin. If a timelock is configured, the transfer can only be accepted after it elapses. */
+	buildProposeAdminIx(args: {
+		/** The proposed head honcho */
+		"admin": Addr
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"propose_admin": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Accepts a previously proposed admin transfer. Must be sent by the proposed admin. */
+	buildAcceptAdminIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"accept_admin": {}} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Cancels a previously proposed admin transfer. */
+	buildCancelAdminTransferIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_admin_transfer": {}} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Proposes a new admin for the specified pool, who must then accept it by sending `AcceptAdmin` to the pool. Once accepted, the pool can no longer be managed through this contract. */
+	buildProposePoolAdminIx(args: {
+		/** The proposed head honcho of the pool */
+		"admin": Addr,
+		/** The trading pair to hand over. The associated pool contract must have already been created. */
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"propose_pool_admin": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Cancels an admin transfer proposed with `ProposePoolAdmin`. */
+	buildCancelPoolAdminTransferIx(args: {
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_pool_admin_transfer": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Updates the anti-spam measures for permissionless pool creation. These don't apply to the admin or those with the `pool_creator` role. */
+	buildUpdatePoolCreationRulesIx(args: {
+		/** Sent to the `fee_receiver` when creating a pool, set the amount to 0 to disable. Requires a `fee_receiver`. */
+		"creation_fee"?: Coin | null,
+		/** Whether the denom list is used as an allowlist or a blocklist */
+		"denom_filter"?: PoolFactoryDenomFilter | null,
+		/** The minimum amount of each denom which must be provided when creating a pool */
+		"min_initial_liquidity"?: Uint128 | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"update_pool_creation_rules": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Adds and removes denoms from the list used by the pool creation rules' `denom_filter`. */
+	buildUpdateDenomListIx(args: {
+		"add": string[],
+		"remove": string[]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"update_denom_list": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** CreatePool instantiates a new pair pool contract. The pair is determined by the initial liquidity funds sent to this contract. If permissionless pool creation is disabled, this requires the `pool_creator` role.
+
+Unless the sender has the `pool_creator` role, the pool creation rules apply. If there's a creation fee, it must be sent along with the initial liquidity. */
 	buildCreatePoolIx(args: {
 		"initial_shares_receiver"?: Addr | null,
 		/** As funds must be given in alphabetical order, this is used to determine whether or not the pair should be inversed when presented to the user */
@@ -57,7 +283,26 @@ export class PoolFactoryContract<Q extends QueryClient & WasmExtension> extends
 		const msg = {"create_pool": args} satisfies PoolFactoryExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
-	/** Sets the specified trading pair to have the specified fees. */
+	/** Marks the specified pool as deprecated and pauses deposits for it. It remains the pool for its pair until it's replaced with `ReplacePool`. */
+	buildDeprecatePoolIx(args: {
+		/** The trading pair to deprecate. The associated pool contract must have already been created. */
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"deprecate_pool": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Creates a new pool which takes over the pair from the existing one, which gets paused and moved to the deprecated pools. The pair is determined by the initial liquidity funds sent to this contract, same as `CreatePool`. */
+	buildReplacePoolIx(args: {
+		"initial_shares_receiver"?: Addr | null,
+		/** As funds must be given in alphabetical order, this is used to determine whether or not the pair should be inversed when presented to the user */
+		"left_denom": string
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"replace_pool": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sets the specified trading pair to have the specified fees. Requires the `fee_manager` role.
+
+If a timelock is configured, fee increases are queued and must be applied with `ApplyPendingFeesForPool` once the timelock has elapsed. */
 	buildUpdateFeesForPoolIx(args: {
 		/** The maker fee, where 10000 is 100%. Must be less than `total_fee_bps`. */
 		"maker_fee_bps"?: number | null,
@@ -69,9 +314,23 @@ export class PoolFactoryContract<Q extends QueryClient & WasmExtension> extends
 		const msg = {"update_fees_for_pool": args} satisfies PoolFactoryExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Applies the fee changes queued by `UpdateFeesForPool` and `SetPoolFeeOnInput` once the timelock has elapsed. */
+	buildApplyPendingFeesForPoolIx(args: {
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"apply_pending_fees_for_pool": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Discards the fee changes queued by `UpdateFeesForPool` and `SetPoolFeeOnInput`. */
+	buildCancelPendingFeesForPoolIx(args: {
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_pending_fees_for_pool": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 	/** Syncs the non-fee-amount configuration options for all pools.
 
-Currently this only syncs the maker fee receiver. */
+Currently this syncs the maker fee receiver and the LP staking contract used for `auto_stake`. */
 	buildUpdateGlobalConfigForPoolIx(args: {
 		/** Pool pair config is updated in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off. */
 		"after"?: [string, string],
@@ -81,12 +340,156 @@ Currently this only syncs the maker fee receiver. */
 		const msg = {"update_global_config_for_pool": args} satisfies PoolFactoryExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
-	/** Upgrades the specified pool pair to the `pair_code_id` as specified in this contract's config. */
+	/** Upgrades the specified pool pair to the `pair_code_id` as specified in this contract's config. Requires the `upgrader` role. Fails if the pool has been pinned. */
 	buildUpdatePoolCodeIx(args: {
 		"pair": [string, string],
+		/** The migrate message sent to the pool, defaults to `{}`. */
 		"payload"?: Binary | null
 	}, funds?: Coin[]): ExecuteInstruction {
 		const msg = {"update_pool_code": args} satisfies PoolFactoryExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Upgrades all pools to the `pair_code_id` as specified in this contract's config. Requires the `upgrader` role.
+
+Pinned pools and pools already running the configured code are skipped. The last pool considered is emitted as the `last_pair` attribute. */
+	buildMigratePoolsIx(args: {
+		/** Pools are considered in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off. */
+		"after"?: [string, string],
+		/** The limit amount of pools to consider, by default, all pools will be considered. */
+		"limit"?: number | null,
+		/** The migrate message sent to each pool, defaults to `{}`. */
+		"payload"?: Binary | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"migrate_pools": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Pins or unpins the specified pool's code. Pinned pools aren't upgraded, which allows rolling out new code to a subset of pools. Requires the `upgrader` role. */
+	buildPinPoolCodeIx(args: {
+		/** The trading pair to change. The associated pool contract must have already been created. */
+		"pair": [string, string],
+		"pinned": boolean
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"pin_pool_code": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Pauses or unpauses swaps and deposits for the specified pool. Withdrawals are always allowed.
+
+The admin can do both, while those with the `guardian` role can only pause. */
+	buildSetPoolPausedIx(args: {
+		/** If specified, enables or disables providing liquidity */
+		"deposits"?: boolean | null,
+		/** The trading pair to change. The associated pool contract must have already been created. */
+		"pair": [string, string],
+		/** If specified, enables or disables swaps */
+		"swaps"?: boolean | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_pool_paused": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Same as `SetPoolPaused`, but for all pools. */
+	buildSetAllPoolsPausedIx(args: {
+		/** Pools are updated in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off. */
+		"after"?: [string, string],
+		/** If specified, enables or disables providing liquidity */
+		"deposits"?: boolean | null,
+		/** The limit amount of pools to update, by default, all pools will be updated. */
+		"limit"?: number | null,
+		/** If specified, enables or disables swaps */
+		"swaps"?: boolean | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_all_pools_paused": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Enables or disables placing long-term orders on the specified pool. Existing orders are always settled. Requires the `fee_manager` role. */
+	buildSetPoolLongTermOrdersIx(args: {
+		"enabled": boolean,
+		/** The trading pair to change. The associated pool contract must have already been created. */
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_pool_long_term_orders": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Enables or disables recording the cost basis of each address's deposits on the specified pool, which is used by the pool's `PositionReport` query. Existing positions are still reduced on withdrawals. Requires the `fee_manager` role. */
+	buildSetPoolPositionTrackingIx(args: {
+		"enabled": boolean,
+		/** The trading pair to change. The associated pool contract must have already been created. */
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_pool_position_tracking": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sets whether the specified pool takes swap fees from the offered amount rather than the result. Requires the `fee_manager` role.
+
+If a timelock is configured, changes are queued along with any other pending fee changes for the pool, and must be applied with `ApplyPendingFeesForPool` once the timelock has elapsed. */
+	buildSetPoolFeeOnInputIx(args: {
+		"enabled": boolean,
+		/** The trading pair to change. The associated pool contract must have already been created. */
+		"pair": [string, string]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_pool_fee_on_input": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Adds pools to the reverse and per-denom indexes used by `PairByAddr` and `PairsContainingDenom`. Pools created after these indexes were introduced are indexed automatically, this is only needed for older pools. */
+	buildReindexPoolsIx(args: {
+		/** Pools are indexed in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off. */
+		"after"?: [string, string],
+		/** The limit amount of pools to index, by default, all pools will be indexed. */
+		"limit"?: number | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"reindex_pools": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Records the underlying assets of pools, which `reject_duplicate_underlying_pairs` relies on. Pools created after these were introduced are recorded automatically, this is only needed for older pools. */
+	buildRecordUnderlyingPairsIx(args: {
+		/** Pools are recorded in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off. */
+		"after"?: [string, string],
+		/** The limit amount of pools to record, by default, all pools will be recorded. */
+		"limit"?: number | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"record_underlying_pairs": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sets the verified metadata for the specified denom, replacing any which was previously set. */
+	buildSetTokenMetadataIx(args: {
+		"denom": string,
+		"metadata": PoolFactoryTokenMetadata
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_token_metadata": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Removes the verified metadata for the specified denom. */
+	buildRemoveTokenMetadataIx(args: {
+		"denom": string
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"remove_token_metadata": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Grants the specified roles to the specified address. The admin implicitly has every role. */
+	buildGrantRolesIx(args: {
+		"address": Addr,
+		"roles": PoolFactoryRole[]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"grant_roles": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Revokes the specified roles from the specified address. */
+	buildRevokeRolesIx(args: {
+		"address": Addr,
+		"roles": PoolFactoryRole[]
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"revoke_roles": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Registers a reward program for the specified pool with the configured LP incentives contract. The rewards must be sent along with this message. */
+	buildRegisterIncentiveProgramIx(args: {
+		/** Unix timestamp (in seconds) of when all rewards will have been released. */
+		"end_time": number,
+		/** The trading pair to incentivise. The associated pool contract must have already been created. */
+		"pair": [string, string],
+		/** Unix timestamp (in seconds) of when rewards start being released. Defaults to now. */
+		"start_time"?: number | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"register_incentive_program": args} satisfies PoolFactoryExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 }
@@ -4,7 +4,7 @@
  * DO NOT MODIFY IT BY HAND.
  * The Rust definition of the associated structs is the source of truth!!
  */
-import {Addr, ArraySize_2Of_Coin, ArraySize_2Of_String, Binary, Decimal, ExchangeRateQueryResponse, PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult, PoolPairConfigJsonable, PoolPairExecuteMsg, PoolPairQueryMsg, PoolPairQuerySimulateDepositResponse, String, Uint128, VolumeQueryResponse} from "./types.js";
+import {Addr, ArrayOf_PoolPairQueryLongTermOrderResponse, ArraySize_2Of_Coin, ArraySize_2Of_String, Binary, Decimal, ExchangeRateQueryResponse, Nullable_Addr, Nullable_PendingAdminTransferJsonable, Nullable_PoolPairQueryLongTermOrderResponse, Nullable_PoolPairQueryPositionReportResponse, PoolPairCalcNaiveSwapResult, PoolPairCalcSwapResult, PoolPairConfigJsonable, PoolPairExecuteMsg, PoolPairQueryLongTermOrdersResponse, PoolPairQueryMsg, PoolPairQueryReservesResponse, PoolPairQueryShareSupplyResponse, PoolPairQuerySimulateDepositResponse, PoolPairSwapReferral, String, Uint128, VolumeQueryResponse} from "./types.js";
 import {Coin} from "@cosmjs/amino";
 import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
 import {QueryClient} from "@cosmjs/stargate";
@@ -35,11 +35,26 @@ export class PoolPairContract<Q extends QueryClient & WasmExtension> extends Con
 		const msg = "config" satisfies PoolPairQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the proposed admin transfer, if any. */
+	queryPendingAdmin(): Promise<Nullable_PendingAdminTransferJsonable> {
+		const msg = "pending_admin" satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the contract LP tokens are staked with when `auto_stake` is specified, if any. */
+	queryLpStaking(): Promise<Nullable_Addr> {
+		const msg = "lp_staking" satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
 	/** Returns the total amount of shares known to the contract */
 	queryTotalShares(): Promise<Uint128> {
 		const msg = "total_shares" satisfies PoolPairQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the LP token supply tracked by this contract alongside what the bank module reports, if anything. */
+	queryShareSupply(): Promise<PoolPairQueryShareSupplyResponse> {
+		const msg = "share_supply" satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
 	/** Returns the current value of shares */
 	queryShareValue(args: {
 		"amount": Uint128
@@ -47,6 +62,11 @@ export class PoolPairContract<Q extends QueryClient & WasmExtension> extends Con
 		const msg = {"share_value": args} satisfies PoolPairQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the reserves the pool is tracking, with long-term orders settled up until now, alongside the balances it actually holds. */
+	queryReserves(): Promise<PoolPairQueryReservesResponse> {
+		const msg = "reserves" satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
 	/** Simulates a deposit and tells you how many pool shares you'd recieve, along with their value. */
 	querySimulateProvideLiquidity(args: {
 		"offer": [Coin, Coin]
@@ -56,14 +76,18 @@ export class PoolPairContract<Q extends QueryClient & WasmExtension> extends Con
 	}
 	/** Simulates a swap and tells you how much you'd get in return, the spread, and the fees involved. */
 	querySimulateSwap(args: {
-		"offer": Coin
+		"offer": Coin,
+		/** The referral fee (in bps) taken from the result, if any */
+		"referral_fee_bps"?: number | null
 	}): Promise<PoolPairCalcSwapResult> {
 		const msg = {"simulate_swap": args} satisfies PoolPairQueryMsg;
 		return this.query(msg);
 	}
 	/** Simulates a swap assuming infinite liquidity, i.e. having no effect on the exchange rate. */
 	querySimulateNaiveSwap(args: {
-		"offer": Coin
+		"offer": Coin,
+		/** The referral fee (in bps) taken from the result, if any */
+		"referral_fee_bps"?: number | null
 	}): Promise<PoolPairCalcNaiveSwapResult> {
 		const msg = {"simulate_naive_swap": args} satisfies PoolPairQueryMsg;
 		return this.query(msg);
@@ -133,24 +157,90 @@ Data older than 30 days is not guaranteed. */
 		const msg = {"estimate_apy": args} satisfies PoolPairQueryMsg;
 		return this.query(msg);
 	}
+	/** Returns the combined state of all long-term orders, settled up until now. */
+	queryLongTermOrders(): Promise<PoolPairQueryLongTermOrdersResponse> {
+		const msg = "long_term_orders" satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the progress of the specified long-term order, if it exists. */
+	queryLongTermOrder(args: {
+		"order_id": number
+	}): Promise<Nullable_PoolPairQueryLongTermOrderResponse> {
+		const msg = {"long_term_order": args} satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns the progress of all long-term orders owned by the specified account. */
+	queryLongTermOrdersByOwner(args: {
+		"owner": Addr
+	}): Promise<ArrayOf_PoolPairQueryLongTermOrderResponse> {
+		const msg = {"long_term_orders_by_owner": args} satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
+	/** Returns what the position recorded for the specified address is worth compared to what was deposited, if position tracking was enabled when the address provided liquidity.
+
+Only deposits made with `ProvideLiquidity` and withdrawals sent by the address itself are recorded, so shares transferred elsewhere are still counted as part of the position. */
+	queryPositionReport(args: {
+		"address": Addr
+	}): Promise<Nullable_PoolPairQueryPositionReportResponse> {
+		const msg = {"position_report": args} satisfies PoolPairQueryMsg;
+		return this.query(msg);
+	}
 	/** Update the pair configuration */
 	buildUpdateConfigIx(args: {
-		/** The head honcho, this is usually the factory contract */
-		"admin"?: Addr | null,
 		/** If true, this has been endorsed by the admin. */
 		"endorsed"?: boolean | null,
+		/** If true, swap fees are taken from the offered amount rather than the result. */
+		"fee_on_input"?: boolean | null,
 		/** Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
 		"fee_receiver"?: Addr | null,
+		/** If true, long-term orders can be placed. Existing orders are always settled. */
+		"long_term_orders"?: boolean | null,
+		/** The contract LP tokens are staked with, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. */
+		"lp_staking"?: Addr | null,
 		/** The amount of fees (in bps) collected by the Maker contract from this pair type */
 		"maker_fee_bps"?: number | null,
+		/** The highest referral fee (in bps) swaps can pay */
+		"max_referral_fee_bps"?: number | null,
+		/** If true, the cost basis of each address's deposits and the fees earned by LPs are recorded, see `PoolPairQueryMsg::PositionReport`. */
+		"position_tracking"?: boolean | null,
 		/** The total fees (in bps) charged by a pair of this type */
 		"total_fee_bps"?: number | null
 	} = {}, funds?: Coin[]): ExecuteInstruction {
 		const msg = {"update_config": args} satisfies PoolPairExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Proposes a new admin, the transfer only takes effect once they accept it with `AcceptAdmin`. This replaces any previously proposed admin. */
+	buildProposeAdminIx(args: {
+		/** The proposed head honcho */
+		"admin": Addr
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"propose_admin": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Accepts a previously proposed admin transfer. Must be sent by the proposed admin. */
+	buildAcceptAdminIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"accept_admin": {}} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Cancels a previously proposed admin transfer. */
+	buildCancelAdminTransferIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_admin_transfer": {}} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Pauses or unpauses swaps and deposits. Withdrawals are always allowed. */
+	buildSetPausedIx(args: {
+		/** If specified, enables or disables providing liquidity */
+		"deposits"?: boolean | null,
+		/** If specified, enables or disables swaps */
+		"swaps"?: boolean | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"set_paused": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 	/** ProvideLiquidity allows someone to provide liquidity in the pool */
 	buildProvideLiquidityIx(args: {
+		/** If true, the pool shares are staked on behalf of the receiver with the LP staking contract configured on this pool. Cannot be used with `receiver_payload`. If the staking contract has no reward programs for this pool, the shares are sent to the receiver instead. */
+		"auto_stake"?: boolean,
 		/** The receiver of pool share */
 		"receiver"?: Addr | null,
 		/** If the receiver is a contract, you can execute it by passing the encoded message here verbatim. */
@@ -193,10 +283,72 @@ Data older than 30 days is not guaranteed. */
 		"receiver"?: Addr | null,
 		/** If the receiver is a contract, you can execute it by passing the encoded message here verbatim. */
 		"receiver_payload"?: Binary | null,
+		/** Pays part of the result to whoever referred the swap, e.g. the frontend it was made from. */
+		"referral"?: PoolPairSwapReferral | null,
 		/** A value between 0 and 1 determining the difference tolerance between `expected_result` and the actual result of the swap before fees. e.g. 0.1 means a 10% slippage tolerance. By default this will be to 0.5%. */
 		"slippage_tolerance"?: Decimal | null
 	} = {}, funds?: Coin[]): ExecuteInstruction {
 		const msg = {"swap": args} satisfies PoolPairExecuteMsg;
 		return this.executeIx(msg, funds);
 	}
+	/** Lends out the specified amounts of the pool's reserves and executes the receiver with the specified payload. By the time that execution ends, the receiver must have sent enough funds back to this contract so that the pool's constant product doesn't decrease, after the swap fee is taken from what was sent back.
+
+The amounts correspond to the denoms in canonical, that is, lexicographical order. */
+	buildFlashSwapIx(args: {
+		"amounts": [Uint128, Uint128],
+		/** The message the receiver is executed with */
+		"payload": Binary,
+		/** The contract receiving the loan, defaults to the sender */
+		"receiver"?: Addr | null
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"flash_swap": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sends anything this contract holds beyond its tracked reserves, e.g. tokens sent to it directly, to the receiver. Anyone can do this. */
+	buildSkimIx(args: {
+		/** The account receiving the surplus, defaults to the sender */
+		"receiver"?: Addr | null
+	} = {}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"skim": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sets the tracked reserves to this contract's actual balances, adding any surplus to the pool's liquidity. Anyone can do this. */
+	buildSyncIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"sync": {}} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sets the LP token supply tracked by this contract to what the bank module reports, correcting for any LP tokens burned by their holders. Anyone can do this, though it fails if the bank module isn't reporting a supply. */
+	buildReconcileSupplyIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"reconcile_supply": {}} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Sells the coin sent over the specified duration (in seconds), at a constant rate, by splitting it into infinitely many virtual swaps. The end time is rounded up to the next hour, and whatever can't be sold at a constant rate until then is refunded.
+
+Long-term orders selling opposite sides of the pair are matched with each other before the pool is used, and they're settled whenever anyone interacts with the pool. The total fee is taken from the proceeds. */
+	buildPlaceLongTermOrderIx(args: {
+		/** How long the order should run for */
+		"duration": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"place_long_term_order": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Cancels a long-term order, refunding what hasn't been sold yet along with the proceeds which haven't been withdrawn. Must be sent by the order's owner. */
+	buildCancelLongTermOrderIx(args: {
+		"order_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"cancel_long_term_order": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Withdraws the proceeds of a long-term order so far. The order is removed once it has ended. Must be sent by the order's owner. */
+	buildWithdrawLongTermOrderIx(args: {
+		"order_id": number
+	}, funds?: Coin[]): ExecuteInstruction {
+		const msg = {"withdraw_long_term_order": args} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
+	/** Settles the long-term orders up until now. Anyone can do this, though this happens anyway whenever anyone interacts with the pool. */
+	buildExecuteVirtualOrdersIx(funds?: Coin[]): ExecuteInstruction {
+		const msg = {"execute_virtual_orders": {}} satisfies PoolPairExecuteMsg;
+		return this.executeIx(msg, funds);
+	}
 }
@@ -4,7 +4,7 @@
  * DO NOT MODIFY IT BY HAND.
  * The Rust definition of the associated structs is the source of truth!!
  */
-import {Addr, Decimal, SwapReceiver, SwapRouterExecuteMsg, SwapRouterExpectation, SwapRouterQueryMsg, SwapRouterSimulateSwapsResponse} from "./types.js";
+import {Addr, Decimal, PoolPairSwapReferral, SwapReceiver, SwapRouterExecuteMsg, SwapRouterExpectation, SwapRouterQueryMsg, SwapRouterSimulateSwapsResponse} from "./types.js";
 import {Coin} from "@cosmjs/amino";
 import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
 import {QueryClient} from "@cosmjs/stargate";
@@ -12,6 +12,8 @@ import {ContractBase} from "@crownfi/sei-utils";
 export class SwapRouterContract<Q extends QueryClient & WasmExtension> extends ContractBase<Q> {
 	querySimulateSwaps(args: {
 		"offer": Coin,
+		/** The referral fee (in bps) paid on the final swap, if any. */
+		"referral_fee_bps"?: number | null,
 		"swappers": Addr[]
 	}): Promise<SwapRouterSimulateSwapsResponse> {
 		const msg = {"simulate_swaps": args} satisfies SwapRouterQueryMsg;
@@ -28,6 +30,8 @@ export class SwapRouterContract<Q extends QueryClient & WasmExtension> extends C
 		"intermediate_slippage_tolerance"?: Decimal | null,
 		/** If the resulting denom wraps another asset, use this contract to unwrap it The account receiving the resulting asset, defaults to the sender. */
 		"receiver": SwapReceiver,
+		/** Pays part of the final swap's result to whoever referred the swap, this is taken before `expectation` is checked. */
+		"referral"?: PoolPairSwapReferral | null,
 		/** The contract(s) to use to execute the swaps */
 		"swappers": Addr[]
 	}, funds?: Coin[]): ExecuteInstruction {
@@ -18,19 +18,6 @@
  * via the `definition` "Addr".
  */
 export type Addr = string;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Array_of_PoolFactoryCreatedPair".
- */
-export type ArrayOf_PoolFactoryCreatedPair = PoolFactoryCreatedPair[];
-/**
- * @minItems 2
- * @maxItems 2
- *
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Array_size_2_of_Coin".
- */
-export type ArraySize_2Of_Coin = [Coin, Coin];
 /**
  * A thin wrapper around u128 that is using strings for JSON encoding/decoding, such that the full u128 range can be used for clients that convert JSON numbers to floats, like JavaScript and jq.
  *
@@ -48,6 +35,103 @@ export type ArraySize_2Of_Coin = [Coin, Coin];
  * via the `definition` "Uint128".
  */
 export type Uint128 = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_Coin".
+ */
+export type ArrayOf_Coin = Coin[];
+/**
+ * A fixed-point decimal value with 18 fractional digits, i.e. Decimal(1_000_000_000_000_000_000) == 1.0
+ *
+ * The greatest possible value that can be represented is 340282366920938463463.374607431768211455 (which is (2^128 - 1) / 10^18)
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Decimal".
+ */
+export type Decimal = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_DcaPlanInfo".
+ */
+export type ArrayOf_DcaPlanInfo = DcaPlanInfo[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_LimitOrderInfo".
+ */
+export type ArrayOf_LimitOrderInfo = LimitOrderInfo[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_LpIncentivesPoolInfo".
+ */
+export type ArrayOf_LpIncentivesPoolInfo = LpIncentivesPoolInfo[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_LpIncentivesPosition".
+ */
+export type ArrayOf_LpIncentivesPosition = LpIncentivesPosition[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryBatchSimulateResult".
+ */
+export type ArrayOf_PoolFactoryBatchSimulateResult = PoolFactoryBatchSimulateResult[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryCreatedPair".
+ */
+export type ArrayOf_PoolFactoryCreatedPair = PoolFactoryCreatedPair[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryDenomTokenMetadata".
+ */
+export type ArrayOf_PoolFactoryDenomTokenMetadata = PoolFactoryDenomTokenMetadata[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryDeprecatedPair".
+ */
+export type ArrayOf_PoolFactoryDeprecatedPair = PoolFactoryDeprecatedPair[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryPoolCodeVersion".
+ */
+export type ArrayOf_PoolFactoryPoolCodeVersion = PoolFactoryPoolCodeVersion[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryPoolSnapshot".
+ */
+export type ArrayOf_PoolFactoryPoolSnapshot = PoolFactoryPoolSnapshot[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryRole".
+ */
+export type PoolFactoryRole = "fee_manager" | "pool_creator" | "upgrader" | "guardian";
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryRole".
+ */
+export type ArrayOf_PoolFactoryRole = PoolFactoryRole[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolFactoryRoleHolder".
+ */
+export type ArrayOf_PoolFactoryRoleHolder = PoolFactoryRoleHolder[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_PoolPairQueryLongTermOrderResponse".
+ */
+export type ArrayOf_PoolPairQueryLongTermOrderResponse = PoolPairQueryLongTermOrderResponse[];
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_of_String".
+ */
+export type ArrayOf_String = string[];
+/**
+ * @minItems 2
+ * @maxItems 2
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Array_size_2_of_Coin".
+ */
+export type ArraySize_2Of_Coin = [Coin, Coin];
 /**
  * @minItems 2
  * @maxItems 2
@@ -65,27 +149,13 @@ export type ArraySize_2Of_String = [string, string];
  * via the `definition` "Binary".
  */
 export type Binary = string;
-/**
- * A fixed-point decimal value with 18 fractional digits, i.e. Decimal(1_000_000_000_000_000_000) == 1.0
- *
- * The greatest possible value that can be represented is 340282366920938463463.374607431768211455 (which is (2^128 - 1) / 10^18)
- *
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Decimal".
- */
-export type Decimal = string;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Nullable_Addr".
- */
-export type Nullable_Addr = Addr | null;
 /**
  * This structure describes the execute messages of the contract.
  *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolFactoryExecuteMsg".
+ * via the `definition` "DcaExecuteMsg".
  */
-export type PoolFactoryExecuteMsg =
+export type DcaExecuteMsg =
   | {
       update_config: {
         /**
@@ -93,361 +163,1770 @@ export type PoolFactoryExecuteMsg =
          */
         admin?: Addr | null;
         /**
-         * The amount of fees (in bps) collected by the Maker contract from this pair type
+         * The swap router used to execute each plan's route
          */
-        default_maker_fee_bps?: number | null;
+        router?: Addr | null;
+      };
+    }
+  | {
+      create_plan: {
         /**
-         * The total fees (in bps) charged by a pair of this type
+         * The amount swapped each time the plan is executed. The last execution swaps whatever is left.
          */
-        default_total_fee_bps?: number | null;
+        amount_per_execution: Uint128;
         /**
-         * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+         * The minimum amount of time (in seconds) between executions
          */
-        fee_receiver?: Addr | null;
+        interval_seconds: number;
         /**
-         * Code to use when instantiating new pool pairs
+         * The minimum amount of the output to receive per unit of the deposit swapped. Executions which would receive less than this fail, so the plan waits until the price recovers. Must be greater than 0.
          */
-        pair_code_id?: number | null;
+        min_price: Decimal;
         /**
-         * If true, everyone will be able to create new trading pairs
+         * The swappers the router will use, in order
          */
-        permissionless_pool_cration?: boolean | null;
+        route: Addr[];
+        /**
+         * A value between 0 and 1 determining how much worse the result of each step of the route can be compared to its result assuming infinite liquidity. e.g. 0.01 means a 1% slippage tolerance.
+         */
+        slippage_tolerance: Decimal;
       };
     }
   | {
-      create_pool: {
-        initial_shares_receiver?: Addr | null;
-        /**
-         * As funds must be given in alphabetical order, this is used to determine whether or not the pair should be inversed when presented to the user
-         */
-        left_denom: string;
+      execute_plan: {
+        plan_id: number;
       };
     }
   | {
-      update_fees_for_pool: {
+      withdraw: {
+        plan_id: number;
+      };
+    }
+  | {
+      close_plan: {
+        plan_id: number;
+      };
+    };
+/**
+ * This structure describes the available query messages for the DCA contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "DcaQueryMsg".
+ */
+export type DcaQueryMsg =
+  | "config"
+  | {
+      plan: {
+        plan_id: number;
+      };
+    }
+  | {
+      active_plans: {
+        after?: number | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      plans_by_owner: {
+        owner: Addr;
+      };
+    };
+/**
+ * This structure describes the execute messages of the contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LimitOrdersExecuteMsg".
+ */
+export type LimitOrdersExecuteMsg =
+  | {
+      update_config: {
         /**
-         * The maker fee, where 10000 is 100%. Must be less than `total_fee_bps`.
+         * The head honcho
          */
-        maker_fee_bps?: number | null;
+        admin?: Addr | null;
         /**
-         * The trading pair to change. The associated pool contract must have already been created.
-         *
-         * @minItems 2
-         * @maxItems 2
+         * The pool factory, orders can only be placed on pools it has created
          */
-        pair: [string, string];
+        factory?: Addr | null;
         /**
-         * The total fee, where 10000 is 100%. This value subtracted by `total_fee_bps` will be the pool fee.
+         * The portion (in bps) of an order's result which is given to the keeper who executed it.
          */
-        total_fee_bps?: number | null;
+        keeper_bounty_bps?: number | null;
       };
     }
   | {
-      update_global_config_for_pool: {
+      place_order: {
         /**
-         * Pool pair config is updated in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
-         *
-         * @minItems 2
-         * @maxItems 2
+         * The minimum amount of the other asset to receive per unit of the coin sent, after the keeper's bounty is taken.
          */
-        after?: [string, string] | null;
+        limit_price: Decimal;
         /**
-         * The limit amount of pools to update, by default, all pools will be updated.
+         * The pool to swap with, which must have been created by the factory and not be deprecated
          */
-        limit?: number | null;
+        pool: Addr;
       };
     }
   | {
-      update_pool_code: {
-        /**
-         * @minItems 2
-         * @maxItems 2
-         */
-        pair: [string, string];
-        payload?: Binary | null;
+      cancel_order: {
+        order_id: number;
+      };
+    }
+  | {
+      execute_order: {
+        order_id: number;
       };
+    }
+  | {
+      complete_order: {};
     };
 /**
- * This structure describes the available query messages for the factory contract.
+ * This structure describes the available query messages for the limit orders contract.
  *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolFactoryQueryMsg".
+ * via the `definition` "LimitOrdersQueryMsg".
  */
-export type PoolFactoryQueryMsg =
+export type LimitOrdersQueryMsg =
   | "config"
   | {
-      pair_addr: {
-        /**
-         * @minItems 2
-         * @maxItems 2
-         */
-        pair: [string, string];
+      order: {
+        order_id: number;
       };
     }
   | {
-      pairs: {
-        /**
-         * @minItems 2
-         * @maxItems 2
-         */
-        after?: [string, string] | null;
+      orders: {
+        after?: number | null;
         limit?: number | null;
       };
+    }
+  | {
+      orders_by_owner: {
+        owner: Addr;
+      };
+    }
+  | {
+      simulate_execution: {
+        order_id: number;
+      };
     };
 /**
- * This structure describes the execute messages available in the contract.
+ * This structure describes the execute messages of the contract.
  *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolPairExecuteMsg".
+ * via the `definition` "LpIncentivesExecuteMsg".
  */
-export type PoolPairExecuteMsg =
+export type LpIncentivesExecuteMsg =
   | {
       update_config: {
         /**
-         * The head honcho, this is usually the factory contract
+         * The head honcho
          */
         admin?: Addr | null;
         /**
-         * If true, this has been endorsed by the admin.
-         */
-        endorsed?: boolean | null;
-        /**
-         * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
-         */
-        fee_receiver?: Addr | null;
-        /**
-         * The amount of fees (in bps) collected by the Maker contract from this pair type
-         */
-        maker_fee_bps?: number | null;
-        /**
-         * The total fees (in bps) charged by a pair of this type
+         * The pool factory, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
          */
-        total_fee_bps?: number | null;
+        factory?: Addr | null;
       };
     }
   | {
-      provide_liquidity: {
+      add_reward_program: {
         /**
-         * The receiver of pool share
+         * Unix timestamp (in seconds) of when all rewards will have been released.
          */
-        receiver?: Addr | null;
+        end_time: number;
         /**
-         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         * The pool's LP token denom, i.e. `factory/<pair>/lp`
          */
-        receiver_payload?: Binary | null;
+        lp_denom: string;
         /**
-         * The slippage tolerance that allows liquidity provision only if the price in the pool doesn't move too much
+         * Unix timestamp (in seconds) of when rewards start being released. Defaults to now.
          */
-        slippage_tolerance?: Decimal | null;
+        start_time?: number | null;
       };
     }
   | {
-      withdraw_liquidity: {
+      stake: {
         /**
-         * The receiver of the share value
+         * The account which will own the staked tokens, defaults to the sender.
          */
         receiver?: Addr | null;
-        /**
-         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
-         */
-        receiver_payload?: Binary | null;
       };
     }
   | {
-      withdraw_and_split_liquidity: {
-        /**
-         * The receiver of the share value
-         */
-        left_coin_receiver?: Addr | null;
+      unstake: {
         /**
-         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         * The amount to unstake, defaults to everything staked.
          */
-        left_coin_receiver_payload?: Binary | null;
+        amount?: Uint128 | null;
         /**
-         * The receiver of the share value
+         * The pool's LP token denom
          */
-        right_coin_receiver?: Addr | null;
+        lp_denom: string;
         /**
-         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         * The account receiving the LP tokens, defaults to the sender.
          */
-        right_coin_receiver_payload?: Binary | null;
+        receiver?: Addr | null;
       };
     }
   | {
-      swap: {
+      emergency_unstake: {
         /**
-         * The expected amount after swap, before fees are taken. By default this will be `incoming_coin * exchange_rate`
+         * The pool's LP token denom
          */
-        expected_result?: Uint128 | null;
+        lp_denom: string;
         /**
-         * The account receiving the payout
+         * The account receiving the LP tokens, defaults to the sender.
          */
         receiver?: Addr | null;
+      };
+    }
+  | {
+      claim: {
         /**
-         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         * If specified, only claim the rewards earned from this pool.
          */
-        receiver_payload?: Binary | null;
+        lp_denom?: string | null;
         /**
-         * A value between 0 and 1 determining the difference tolerance between `expected_result` and the actual result of the swap before fees. e.g. 0.1 means a 10% slippage tolerance. By default this will be to 0.5%.
+         * The account receiving the rewards, defaults to the sender.
          */
-        slippage_tolerance?: Decimal | null;
+        receiver?: Addr | null;
       };
     };
 /**
+ * This structure describes the available query messages for the incentives contract.
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolPairQueryMsg".
+ * via the `definition` "LpIncentivesQueryMsg".
  */
-export type PoolPairQueryMsg =
-  | "pair_denoms"
-  | "canonical_pair_denoms"
-  | "pair_identifier"
-  | "canonical_pair_identifier"
+export type LpIncentivesQueryMsg =
   | "config"
-  | "total_shares"
   | {
-      share_value: {
-        amount: Uint128;
+      pool: {
+        lp_denom: string;
       };
     }
   | {
-      simulate_provide_liquidity: {
+      pools: {
+        after?: string | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      positions: {
+        address: Addr;
+      };
+    }
+  | {
+      pending_rewards: {
+        address: Addr;
+        lp_denom?: string | null;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_Addr".
+ */
+export type Nullable_Addr = Addr | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_DcaPlanInfo".
+ */
+export type Nullable_DcaPlanInfo = DcaPlanInfo | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_LimitOrderInfo".
+ */
+export type Nullable_LimitOrderInfo = LimitOrderInfo | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_LpIncentivesPoolInfo".
+ */
+export type Nullable_LpIncentivesPoolInfo = LpIncentivesPoolInfo | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PendingAdminTransferJsonable".
+ */
+export type Nullable_PendingAdminTransferJsonable = PendingAdminTransferJsonable | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolFactoryCreatedPair".
+ */
+export type Nullable_PoolFactoryCreatedPair = PoolFactoryCreatedPair | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolFactoryPendingConfig".
+ */
+export type Nullable_PoolFactoryPendingConfig = PoolFactoryPendingConfig | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolFactoryPendingPoolFees".
+ */
+export type Nullable_PoolFactoryPendingPoolFees = PoolFactoryPendingPoolFees | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolFactoryTokenMetadata".
+ */
+export type Nullable_PoolFactoryTokenMetadata = PoolFactoryTokenMetadata | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolPairQueryLongTermOrderResponse".
+ */
+export type Nullable_PoolPairQueryLongTermOrderResponse = PoolPairQueryLongTermOrderResponse | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Nullable_PoolPairQueryPositionReportResponse".
+ */
+export type Nullable_PoolPairQueryPositionReportResponse = PoolPairQueryPositionReportResponse | null;
+/**
+ * Which denoms pools can be created for by those who don't have the `pool_creator` role.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryDenomFilter".
+ */
+export type PoolFactoryDenomFilter = "none" | "allowlist" | "blocklist";
+/**
+ * This structure describes the execute messages of the contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryExecuteMsg".
+ */
+export type PoolFactoryExecuteMsg =
+  | {
+      update_config: {
+        /**
+         * The CW20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+         */
+        cw20_wrapper?: Addr | null;
+        /**
+         * The amount of fees (in bps) collected by the Maker contract from this pair type
+         */
+        default_maker_fee_bps?: number | null;
+        /**
+         * The total fees (in bps) charged by a pair of this type
+         */
+        default_total_fee_bps?: number | null;
+        /**
+         * The ERC20 wrapper contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+         */
+        erc20_wrapper?: Addr | null;
+        /**
+         * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. Cannot be disabled while there's a pool creation fee.
+         */
+        fee_receiver?: Addr | null;
+        /**
+         * The LP incentives contract, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable. New pools will use this for `auto_stake`, existing pools can be updated with `UpdateGlobalConfigForPool`.
+         */
+        lp_incentives?: Addr | null;
+        /**
+         * The highest referral fee (in bps) swaps on the pairs can pay. Existing pools can be updated with `UpdateGlobalConfigForPool`.
+         */
+        max_referral_fee_bps?: number | null;
+        /**
+         * Code to use when instantiating new pool pairs
+         */
+        pair_code_id?: number | null;
+        /**
+         * If true, everyone will be able to create new trading pairs
+         */
+        permissionless_pool_cration?: boolean | null;
+        /**
+         * If true, pools can't be created for pairs which share the same underlying assets as an existing pool
+         */
+        reject_duplicate_underlying_pairs?: boolean | null;
+        /**
+         * How long (in seconds) sensitive changes must be queued for before they can be applied
+         */
+        timelock_seconds?: number | null;
+      };
+    }
+  | {
+      apply_pending_config: {};
+    }
+  | {
+      cancel_pending_config: {};
+    }
+  | {
+      propose_admin: {
+        /**
+         * The proposed head honcho
+         */
+        admin: Addr;
+      };
+    }
+  | {
+      accept_admin: {};
+    }
+  | {
+      cancel_admin_transfer: {};
+    }
+  | {
+      propose_pool_admin: {
         /**
+         * The proposed head honcho of the pool
+         */
+        admin: Addr;
+        /**
+         * The trading pair to hand over. The associated pool contract must have already been created.
+         *
          * @minItems 2
          * @maxItems 2
          */
-        offer: [Coin, Coin];
+        pair: [string, string];
       };
     }
   | {
-      simulate_swap: {
-        offer: Coin;
+      cancel_pool_admin_transfer: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
       };
     }
   | {
-      simulate_naive_swap: {
-        offer: Coin;
+      update_pool_creation_rules: {
+        /**
+         * Sent to the `fee_receiver` when creating a pool, set the amount to 0 to disable. Requires a `fee_receiver`.
+         */
+        creation_fee?: Coin | null;
+        /**
+         * Whether the denom list is used as an allowlist or a blocklist
+         */
+        denom_filter?: PoolFactoryDenomFilter | null;
+        /**
+         * The minimum amount of each denom which must be provided when creating a pool
+         */
+        min_initial_liquidity?: Uint128 | null;
       };
     }
   | {
-      hourly_volume_sum: {
-        past_hours?: number | null;
+      update_denom_list: {
+        add: string[];
+        remove: string[];
       };
     }
   | {
-      daily_volume_sum: {
-        past_days?: number | null;
+      create_pool: {
+        initial_shares_receiver?: Addr | null;
+        /**
+         * As funds must be given in alphabetical order, this is used to determine whether or not the pair should be inversed when presented to the user
+         */
+        left_denom: string;
       };
     }
-  | "total_volume_sum"
   | {
-      exchange_rate_hourly: {
-        past_hours?: number | null;
+      deprecate_pool: {
+        /**
+         * The trading pair to deprecate. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
       };
     }
   | {
-      exchange_rate_daily: {
-        past_days?: number | null;
+      replace_pool: {
+        initial_shares_receiver?: Addr | null;
+        /**
+         * As funds must be given in alphabetical order, this is used to determine whether or not the pair should be inversed when presented to the user
+         */
+        left_denom: string;
       };
     }
-  | "exchange_rate_all_time"
   | {
-      estimate_apy: {
-        past_days: number;
+      update_fees_for_pool: {
+        /**
+         * The maker fee, where 10000 is 100%. Must be less than `total_fee_bps`.
+         */
+        maker_fee_bps?: number | null;
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+        /**
+         * The total fee, where 10000 is 100%. This value subtracted by `total_fee_bps` will be the pool fee.
+         */
+        total_fee_bps?: number | null;
       };
-    };
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "String".
- */
-export type String = string;
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "SwapReceiver".
- */
-export type SwapReceiver =
+    }
   | {
-      direct: Addr;
+      apply_pending_fees_for_pool: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
     }
   | {
-      wasm_unwrap: {
-        contract: Addr;
-        receiver: Addr;
+      cancel_pending_fees_for_pool: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
       };
     }
   | {
-      evm_unwrap: {
-        contract: Addr;
-        evm_receiver: string;
+      update_global_config_for_pool: {
+        /**
+         * Pool pair config is updated in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        /**
+         * The limit amount of pools to update, by default, all pools will be updated.
+         */
+        limit?: number | null;
       };
-    };
+    }
+  | {
+      update_pool_code: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+        /**
+         * The migrate message sent to the pool, defaults to `{}`.
+         */
+        payload?: Binary | null;
+      };
+    }
+  | {
+      migrate_pools: {
+        /**
+         * Pools are considered in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        /**
+         * The limit amount of pools to consider, by default, all pools will be considered.
+         */
+        limit?: number | null;
+        /**
+         * The migrate message sent to each pool, defaults to `{}`.
+         */
+        payload?: Binary | null;
+      };
+    }
+  | {
+      pin_pool_code: {
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+        pinned: boolean;
+      };
+    }
+  | {
+      set_pool_paused: {
+        /**
+         * If specified, enables or disables providing liquidity
+         */
+        deposits?: boolean | null;
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+        /**
+         * If specified, enables or disables swaps
+         */
+        swaps?: boolean | null;
+      };
+    }
+  | {
+      set_all_pools_paused: {
+        /**
+         * Pools are updated in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        /**
+         * If specified, enables or disables providing liquidity
+         */
+        deposits?: boolean | null;
+        /**
+         * The limit amount of pools to update, by default, all pools will be updated.
+         */
+        limit?: number | null;
+        /**
+         * If specified, enables or disables swaps
+         */
+        swaps?: boolean | null;
+      };
+    }
+  | {
+      set_pool_long_term_orders: {
+        enabled: boolean;
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | {
+      set_pool_position_tracking: {
+        enabled: boolean;
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | {
+      set_pool_fee_on_input: {
+        enabled: boolean;
+        /**
+         * The trading pair to change. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | {
+      reindex_pools: {
+        /**
+         * Pools are indexed in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        /**
+         * The limit amount of pools to index, by default, all pools will be indexed.
+         */
+        limit?: number | null;
+      };
+    }
+  | {
+      record_underlying_pairs: {
+        /**
+         * Pools are recorded in lexicographical order. If you need to execute this instruction accross multiple transactions, this is where you can specify to pick up where you left off.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        /**
+         * The limit amount of pools to record, by default, all pools will be recorded.
+         */
+        limit?: number | null;
+      };
+    }
+  | {
+      set_token_metadata: {
+        denom: string;
+        metadata: PoolFactoryTokenMetadata;
+      };
+    }
+  | {
+      remove_token_metadata: {
+        denom: string;
+      };
+    }
+  | {
+      grant_roles: {
+        address: Addr;
+        roles: PoolFactoryRole[];
+      };
+    }
+  | {
+      revoke_roles: {
+        address: Addr;
+        roles: PoolFactoryRole[];
+      };
+    }
+  | {
+      register_incentive_program: {
+        /**
+         * Unix timestamp (in seconds) of when all rewards will have been released.
+         */
+        end_time: number;
+        /**
+         * The trading pair to incentivise. The associated pool contract must have already been created.
+         *
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+        /**
+         * Unix timestamp (in seconds) of when rewards start being released. Defaults to now.
+         */
+        start_time?: number | null;
+      };
+    };
+/**
+ * This structure describes the available query messages for the factory contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryQueryMsg".
+ */
+export type PoolFactoryQueryMsg =
+  | "config"
+  | "pending_admin"
+  | "pending_config"
+  | {
+      pending_fees_for_pool: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | "pool_creation_rules"
+  | {
+      denom_list: {
+        after?: string | null;
+        limit?: number | null;
+      };
+    }
+  | "wrappers"
+  | {
+      underlying_asset: {
+        denom: string;
+      };
+    }
+  | {
+      token_metadata: {
+        denom: string;
+      };
+    }
+  | {
+      token_metadata_batch: {
+        denoms: string[];
+      };
+    }
+  | {
+      all_token_metadata: {
+        after?: string | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      roles: {
+        address: Addr;
+      };
+    }
+  | {
+      role_holders: {
+        after?: Addr | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      pool_code_versions: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      pair_addr: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | {
+      pair_by_addr: {
+        address: Addr;
+      };
+    }
+  | {
+      pairs_containing_denom: {
+        /**
+         * The other denom of the last pair returned by a previous query
+         */
+        after?: string | null;
+        denom: string;
+        limit?: number | null;
+      };
+    }
+  | {
+      pair_info: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        pair: [string, string];
+      };
+    }
+  | {
+      deprecated_pairs: {
+        after?: Addr | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      batch_simulate: {
+        requests: PoolFactoryBatchSimulateRequest[];
+      };
+    }
+  | {
+      pools_snapshot: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      user_positions: {
+        address: Addr;
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        limit?: number | null;
+      };
+    }
+  | {
+      pairs: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        after?: [string, string] | null;
+        limit?: number | null;
+      };
+    };
+/**
+ * The asset a denom ultimately represents, so that wrapped forms of the same token can be recognised.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryUnderlyingAsset".
+ */
+export type PoolFactoryUnderlyingAsset =
+  | {
+      native: {
+        denom: string;
+      };
+    }
+  | {
+      cw20: {
+        contract: string;
+      };
+    }
+  | {
+      erc20: {
+        contract: string;
+      };
+    };
+/**
+ * This structure describes the execute messages available in the contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairExecuteMsg".
+ */
+export type PoolPairExecuteMsg =
+  | {
+      update_config: {
+        /**
+         * If true, this has been endorsed by the admin.
+         */
+        endorsed?: boolean | null;
+        /**
+         * If true, swap fees are taken from the offered amount rather than the result.
+         */
+        fee_on_input?: boolean | null;
+        /**
+         * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+         */
+        fee_receiver?: Addr | null;
+        /**
+         * If true, long-term orders can be placed. Existing orders are always settled.
+         */
+        long_term_orders?: boolean | null;
+        /**
+         * The contract LP tokens are staked with, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
+         */
+        lp_staking?: Addr | null;
+        /**
+         * The amount of fees (in bps) collected by the Maker contract from this pair type
+         */
+        maker_fee_bps?: number | null;
+        /**
+         * The highest referral fee (in bps) swaps can pay
+         */
+        max_referral_fee_bps?: number | null;
+        /**
+         * If true, the cost basis of each address's deposits and the fees earned by LPs are recorded, see `PoolPairQueryMsg::PositionReport`.
+         */
+        position_tracking?: boolean | null;
+        /**
+         * The total fees (in bps) charged by a pair of this type
+         */
+        total_fee_bps?: number | null;
+      };
+    }
+  | {
+      propose_admin: {
+        /**
+         * The proposed head honcho
+         */
+        admin: Addr;
+      };
+    }
+  | {
+      accept_admin: {};
+    }
+  | {
+      cancel_admin_transfer: {};
+    }
+  | {
+      set_paused: {
+        /**
+         * If specified, enables or disables providing liquidity
+         */
+        deposits?: boolean | null;
+        /**
+         * If specified, enables or disables swaps
+         */
+        swaps?: boolean | null;
+      };
+    }
+  | {
+      provide_liquidity: {
+        /**
+         * If true, the pool shares are staked on behalf of the receiver with the LP staking contract configured on this pool. Cannot be used with `receiver_payload`. If the staking contract has no reward programs for this pool, the shares are sent to the receiver instead.
+         */
+        auto_stake?: boolean;
+        /**
+         * The receiver of pool share
+         */
+        receiver?: Addr | null;
+        /**
+         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         */
+        receiver_payload?: Binary | null;
+        /**
+         * The slippage tolerance that allows liquidity provision only if the price in the pool doesn't move too much
+         */
+        slippage_tolerance?: Decimal | null;
+      };
+    }
+  | {
+      withdraw_liquidity: {
+        /**
+         * The receiver of the share value
+         */
+        receiver?: Addr | null;
+        /**
+         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         */
+        receiver_payload?: Binary | null;
+      };
+    }
+  | {
+      withdraw_and_split_liquidity: {
+        /**
+         * The receiver of the share value
+         */
+        left_coin_receiver?: Addr | null;
+        /**
+         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         */
+        left_coin_receiver_payload?: Binary | null;
+        /**
+         * The receiver of the share value
+         */
+        right_coin_receiver?: Addr | null;
+        /**
+         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         */
+        right_coin_receiver_payload?: Binary | null;
+      };
+    }
+  | {
+      swap: {
+        /**
+         * The expected amount after swap, before fees are taken. By default this will be `incoming_coin * exchange_rate`
+         */
+        expected_result?: Uint128 | null;
+        /**
+         * The account receiving the payout
+         */
+        receiver?: Addr | null;
+        /**
+         * If the receiver is a contract, you can execute it by passing the encoded message here verbatim.
+         */
+        receiver_payload?: Binary | null;
+        /**
+         * Pays part of the result to whoever referred the swap, e.g. the frontend it was made from.
+         */
+        referral?: PoolPairSwapReferral | null;
+        /**
+         * A value between 0 and 1 determining the difference tolerance between `expected_result` and the actual result of the swap before fees. e.g. 0.1 means a 10% slippage tolerance. By default this will be to 0.5%.
+         */
+        slippage_tolerance?: Decimal | null;
+      };
+    }
+  | {
+      flash_swap: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        amounts: [Uint128, Uint128];
+        /**
+         * The message the receiver is executed with
+         */
+        payload: Binary;
+        /**
+         * The contract receiving the loan, defaults to the sender
+         */
+        receiver?: Addr | null;
+      };
+    }
+  | {
+      skim: {
+        /**
+         * The account receiving the surplus, defaults to the sender
+         */
+        receiver?: Addr | null;
+      };
+    }
+  | {
+      sync: {};
+    }
+  | {
+      reconcile_supply: {};
+    }
+  | {
+      place_long_term_order: {
+        /**
+         * How long the order should run for
+         */
+        duration: number;
+      };
+    }
+  | {
+      cancel_long_term_order: {
+        order_id: number;
+      };
+    }
+  | {
+      withdraw_long_term_order: {
+        order_id: number;
+      };
+    }
+  | {
+      execute_virtual_orders: {};
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairQueryMsg".
+ */
+export type PoolPairQueryMsg =
+  | "pair_denoms"
+  | "canonical_pair_denoms"
+  | "pair_identifier"
+  | "canonical_pair_identifier"
+  | "config"
+  | "pending_admin"
+  | "lp_staking"
+  | "total_shares"
+  | "share_supply"
+  | {
+      share_value: {
+        amount: Uint128;
+      };
+    }
+  | "reserves"
+  | {
+      simulate_provide_liquidity: {
+        /**
+         * @minItems 2
+         * @maxItems 2
+         */
+        offer: [Coin, Coin];
+      };
+    }
+  | {
+      simulate_swap: {
+        offer: Coin;
+        /**
+         * The referral fee (in bps) taken from the result, if any
+         */
+        referral_fee_bps?: number | null;
+      };
+    }
+  | {
+      simulate_naive_swap: {
+        offer: Coin;
+        /**
+         * The referral fee (in bps) taken from the result, if any
+         */
+        referral_fee_bps?: number | null;
+      };
+    }
+  | {
+      hourly_volume_sum: {
+        past_hours?: number | null;
+      };
+    }
+  | {
+      daily_volume_sum: {
+        past_days?: number | null;
+      };
+    }
+  | "total_volume_sum"
+  | {
+      exchange_rate_hourly: {
+        past_hours?: number | null;
+      };
+    }
+  | {
+      exchange_rate_daily: {
+        past_days?: number | null;
+      };
+    }
+  | "exchange_rate_all_time"
+  | {
+      estimate_apy: {
+        past_days: number;
+      };
+    }
+  | "long_term_orders"
+  | {
+      long_term_order: {
+        order_id: number;
+      };
+    }
+  | {
+      long_term_orders_by_owner: {
+        owner: Addr;
+      };
+    }
+  | {
+      position_report: {
+        address: Addr;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "String".
+ */
+export type String = string;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "SwapReceiver".
+ */
+export type SwapReceiver =
+  | {
+      direct: Addr;
+    }
+  | {
+      wasm_unwrap: {
+        contract: Addr;
+        receiver: Addr;
+      };
+    }
+  | {
+      evm_unwrap: {
+        contract: Addr;
+        evm_receiver: string;
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "SwapRouterExecuteMsg".
+ */
+export type SwapRouterExecuteMsg =
+  | "next_step"
+  | {
+      execute_swaps: {
+        /**
+         * If you want the swap to fail due to an excessive difference between what you're expecting and what you're getting, specify your terms here.
+         */
+        expectation?: SwapRouterExpectation | null;
+        /**
+         * The slippage tolerance for each step of the way, default value is at the each swapper's discretion, though the CrownFi swap contracts have a default of 0.5%.
+         */
+        intermediate_slippage_tolerance?: Decimal | null;
+        /**
+         * If the resulting denom wraps another asset, use this contract to unwrap it The account receiving the resulting asset, defaults to the sender.
+         */
+        receiver: SwapReceiver;
+        /**
+         * Pays part of the final swap's result to whoever referred the swap, this is taken before `expectation` is checked.
+         */
+        referral?: PoolPairSwapReferral | null;
+        /**
+         * The contract(s) to use to execute the swaps
+         */
+        swappers: Addr[];
+      };
+    };
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "SwapRouterQueryMsg".
+ */
+export type SwapRouterQueryMsg = {
+  simulate_swaps: {
+    offer: Coin;
+    /**
+     * The referral fee (in bps) paid on the final swap, if any.
+     */
+    referral_fee_bps?: number | null;
+    swappers: Addr[];
+  };
+};
+
+export interface CrownfiSdkMakerAutogen {}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "Coin".
+ */
+export interface Coin {
+  amount: Uint128;
+  denom: string;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "DcaPlanInfo".
+ */
+export interface DcaPlanInfo {
+  amount_per_execution: Uint128;
+  /**
+   * Unix timestamp (in seconds) of when the plan was created
+   */
+  created_at: number;
+  executions: number;
+  interval_seconds: number;
+  /**
+   * Unix timestamp (in seconds) of the last execution
+   */
+  last_execution?: number | null;
+  /**
+   * The minimum amount of the output received per unit of the deposit swapped
+   */
+  min_price: Decimal;
+  /**
+   * Unix timestamp (in seconds) of when the plan may next be executed, `None` if there's nothing left to swap.
+   */
+  next_execution?: number | null;
+  /**
+   * The output received which hasn't been withdrawn yet
+   */
+  output: Coin;
+  owner: Addr;
+  plan_id: number;
+  /**
+   * The deposit which has yet to be swapped
+   */
+  remaining: Coin;
+  route: Addr[];
+  slippage_tolerance: Decimal;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LimitOrderInfo".
+ */
+export interface LimitOrderInfo {
+  ask_denom: string;
+  /**
+   * Unix timestamp (in seconds) of when the order was placed
+   */
+  created_at: number;
+  keeper_bounty_bps: number;
+  /**
+   * The minimum amount the owner will receive, i.e. `offer.amount * limit_price`
+   */
+  min_result: Uint128;
+  /**
+   * The escrowed coin
+   */
+  offer: Coin;
+  order_id: number;
+  owner: Addr;
+  pool: Addr;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LpIncentivesPoolInfo".
+ */
+export interface LpIncentivesPoolInfo {
+  lp_denom: string;
+  programs: LpIncentivesRewardProgramInfo[];
+  total_staked: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LpIncentivesRewardProgramInfo".
+ */
+export interface LpIncentivesRewardProgramInfo {
+  denom: string;
+  end_time: number;
+  released_amount: Uint128;
+  start_time: number;
+  total_amount: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LpIncentivesPosition".
+ */
+export interface LpIncentivesPosition {
+  amount: Uint128;
+  lp_denom: string;
+  pending_rewards: Coin[];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryBatchSimulateResult".
+ */
+export interface PoolFactoryBatchSimulateResult {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  offer: Coin;
+  result: PoolPairCalcSwapResult;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairCalcSwapResult".
+ */
+export interface PoolPairCalcSwapResult {
+  /**
+   * If true, the fees are in the offered denom rather than the resulting denom.
+   */
+  fee_on_input: boolean;
+  /**
+   * How much CrownFi is skimming off the top
+   */
+  maker_fee_amount: Uint128;
+  /**
+   * How much of the result is paid to the referrer, this is always in the resulting denom.
+   */
+  referral_fee_amount: Uint128;
+  /**
+   * The amount of coin after the swap, minus the `referral_fee_amount` and the `total_fee_amount` if the fee is taken from the result.
+   */
+  result_amount: Uint128;
+  /**
+   * The discrepancy between what was received from the pool and `swapped_amount * exchange_rate`, where `swapped_amount` is the offered amount minus the `total_fee_amount` if the fee is taken from the input.
+   */
+  spread_amount: Uint128;
+  /**
+   * `maker_fee_amount + liquidity_provider_fee_amount`
+   */
+  total_fee_amount: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryCreatedPair".
+ */
+export interface PoolFactoryCreatedPair {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  /**
+   * True if the pool has been deprecated
+   */
+  deprecated: boolean;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryDenomTokenMetadata".
+ */
+export interface PoolFactoryDenomTokenMetadata {
+  denom: string;
+  metadata: PoolFactoryTokenMetadata;
+}
+/**
+ * Verified information about a token, curated by the admin.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryTokenMetadata".
+ */
+export interface PoolFactoryTokenMetadata {
+  /**
+   * The amount of decimal places used when displaying amounts
+   */
+  decimals: number;
+  /**
+   * The name to show to users
+   */
+  display_name: string;
+  /**
+   * The "0x"-prefixed address of the token's EVM pointer contract, if any
+   */
+  evm_pointer?: string | null;
+  logo_uri?: string | null;
+  /**
+   * The ticker symbol to show to users
+   */
+  symbol: string;
+  /**
+   * If this denom is minted by a wrapper contract, the address of the token being wrapped, either a CW20 contract or an "0x"-prefixed ERC20 contract.
+   */
+  wrapper_origin?: string | null;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryDeprecatedPair".
+ */
+export interface PoolFactoryDeprecatedPair {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  /**
+   * Unix timestamp (in seconds) of when the pool was deprecated
+   */
+  deprecated_at: number;
+  /**
+   * True if a new pool has taken over the pair
+   */
+  replaced: boolean;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryPoolCodeVersion".
+ */
+export interface PoolFactoryPoolCodeVersion {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  /**
+   * The code ID the pool was last instantiated or migrated with, unknown for pools created before this was tracked
+   */
+  code_id?: number | null;
+  /**
+   * If true, the pool will not be upgraded
+   */
+  pinned: boolean;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryPoolSnapshot".
+ */
+export interface PoolFactoryPoolSnapshot {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  maker_fee_bps: number;
+  /**
+   * The pool's balances, in the same order as `canonical_pair`
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  reserves: [Coin, Coin];
+  /**
+   * The amount of `canonical_pair[1]` one unit of `canonical_pair[0]` is worth, before fees. This is always in the canonical order, regardless of whether the pool is configured as inverse. None if either reserve is empty.
+   */
+  spot_price?: Decimal | null;
+  swaps_paused: boolean;
+  total_fee_bps: number;
+  total_shares: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryRoleHolder".
+ */
+export interface PoolFactoryRoleHolder {
+  address: Addr;
+  roles: PoolFactoryRole[];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairQueryLongTermOrderResponse".
+ */
+export interface PoolPairQueryLongTermOrderResponse {
+  /**
+   * The denom being bought
+   */
+  buy_denom: string;
+  /**
+   * The proceeds which can be withdrawn
+   */
+  claimable_proceeds: Uint128;
+  /**
+   * Unix timestamp (in seconds) of when the order ends
+   */
+  end_time: number;
+  order_id: number;
+  owner: Addr;
+  /**
+   * The amount which has yet to be sold
+   */
+  remaining: Uint128;
+  /**
+   * The denom being sold
+   */
+  sell_denom: string;
+  /**
+   * The amount sold per second
+   */
+  sell_rate: Uint128;
+  /**
+   * The amount which has been sold so far
+   */
+  sold: Uint128;
+  /**
+   * Unix timestamp (in seconds) of when the order was placed
+   */
+  start_time: number;
+  /**
+   * The proceeds which have been withdrawn so far
+   */
+  withdrawn_proceeds: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "DcaConfigJsonable".
+ */
+export interface DcaConfigJsonable {
+  /**
+   * The head honcho
+   */
+  admin: Addr;
+  /**
+   * The swap router used to execute each plan's route
+   */
+  router: Addr;
+}
+/**
+ * This structure stores the basic settings for creating a new DCA contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "DcaInstantiateMsg".
+ */
+export interface DcaInstantiateMsg {
+  config: DcaConfigJsonable;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "ExchangeRateQueryResponse".
+ */
+export interface ExchangeRateQueryResponse {
+  exchange_rate_avg: Decimal;
+  exchange_rate_high: Decimal;
+  exchange_rate_low: Decimal;
+  from_timestamp_ms: number;
+  to_timestamp_ms: number;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LimitOrderSimulateExecutionResponse".
+ */
+export interface LimitOrderSimulateExecutionResponse {
+  /**
+   * True if the limit price has been reached
+   */
+  executable: boolean;
+  /**
+   * The amount the keeper would receive
+   */
+  keeper_bounty: Uint128;
+  /**
+   * The amount the owner would receive
+   */
+  result_amount: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LimitOrdersConfigJsonable".
+ */
+export interface LimitOrdersConfigJsonable {
+  /**
+   * The head honcho
+   */
+  admin: Addr;
+  /**
+   * The pool factory, orders can only be placed on pools it has created
+   */
+  factory: Addr;
+  /**
+   * The portion (in bps) of an order's result which is given to the keeper who executed it. This only applies to orders placed after it was set.
+   */
+  keeper_bounty_bps: number;
+}
+/**
+ * This structure stores the basic settings for creating a new limit orders contract.
+ *
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LimitOrdersInstantiateMsg".
+ */
+export interface LimitOrdersInstantiateMsg {
+  config: LimitOrdersConfigJsonable;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "LpIncentivesConfigJsonable".
+ */
+export interface LpIncentivesConfigJsonable {
+  /**
+   * The head honcho
+   */
+  admin: Addr;
+  /**
+   * The pool factory, which is allowed to register reward programs.
+   */
+  factory?: Addr | null;
+}
 /**
+ * This structure stores the basic settings for creating a new incentives contract.
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "SwapRouterExecuteMsg".
+ * via the `definition` "LpIncentivesInstantiateMsg".
  */
-export type SwapRouterExecuteMsg =
-  | "next_step"
-  | {
-      execute_swaps: {
-        /**
-         * If you want the swap to fail due to an excessive difference between what you're expecting and what you're getting, specify your terms here.
-         */
-        expectation?: SwapRouterExpectation | null;
-        /**
-         * The slippage tolerance for each step of the way, default value is at the each swapper's discretion, though the CrownFi swap contracts have a default of 0.5%.
-         */
-        intermediate_slippage_tolerance?: Decimal | null;
-        /**
-         * If the resulting denom wraps another asset, use this contract to unwrap it The account receiving the resulting asset, defaults to the sender.
-         */
-        receiver: SwapReceiver;
-        /**
-         * The contract(s) to use to execute the swaps
-         */
-        swappers: Addr[];
-      };
-    };
+export interface LpIncentivesInstantiateMsg {
+  config: LpIncentivesConfigJsonable;
+}
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "SwapRouterQueryMsg".
+ * via the `definition` "PendingAdminTransferJsonable".
  */
-export type SwapRouterQueryMsg = {
-  simulate_swaps: {
-    offer: Coin;
-    swappers: Addr[];
-  };
-};
-
-export interface CrownfiSdkMakerAutogen {}
+export interface PendingAdminTransferJsonable {
+  /**
+   * Unix timestamp (in seconds) after which the transfer may be accepted
+   */
+  accept_after: number;
+  /**
+   * The proposed admin, only they can accept the transfer
+   */
+  new_admin: Addr;
+}
 /**
+ * Sensitive config changes which are waiting for the timelock to elapse.
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolFactoryCreatedPair".
+ * via the `definition` "PoolFactoryPendingConfig".
  */
-export interface PoolFactoryCreatedPair {
-  address: Addr;
+export interface PoolFactoryPendingConfig {
   /**
-   * @minItems 2
-   * @maxItems 2
+   * Unix timestamp (in seconds) after which these changes may be applied
    */
-  canonical_pair: [string, string];
+  apply_after: number;
+  default_maker_fee_bps?: number | null;
+  default_total_fee_bps?: number | null;
+  pair_code_id?: number | null;
+  timelock_seconds?: number | null;
 }
 /**
+ * Pool fee changes which are waiting for the timelock to elapse.
+ *
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Coin".
+ * via the `definition` "PoolFactoryPendingPoolFees".
  */
-export interface Coin {
-  amount: Uint128;
-  denom: string;
+export interface PoolFactoryPendingPoolFees {
+  /**
+   * Unix timestamp (in seconds) after which these changes may be applied
+   */
+  apply_after: number;
+  fee_on_input?: boolean | null;
+  maker_fee_bps?: number | null;
+  total_fee_bps?: number | null;
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "ExchangeRateQueryResponse".
+ * via the `definition` "PoolPairQueryPositionReportResponse".
  */
-export interface ExchangeRateQueryResponse {
-  exchange_rate_avg: Decimal;
-  exchange_rate_high: Decimal;
-  exchange_rate_low: Decimal;
-  from_timestamp_ms: number;
-  to_timestamp_ms: number;
+export interface PoolPairQueryPositionReportResponse {
+  /**
+   * What was deposited for the shares, reduced proportionally on withdrawals
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  cost_basis: [Coin, Coin];
+  /**
+   * An estimate of the LP fees from swaps which the shares have earned. These are already part of `share_value`.
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  fees_earned: [Coin, Coin];
+  /**
+   * `cost_basis` converted entirely into each denom at the current exchange rate, i.e. what the deposits would be worth had they been held instead
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  hodl_value_total: [Coin, Coin];
+  /**
+   * How much less `share_value_total` is worth compared to `hodl_value_total`, not counting `fees_earned`.
+   */
+  impermanent_loss: Decimal;
+  /**
+   * What the shares are currently worth
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  share_value: [Coin, Coin];
+  /**
+   * `share_value` converted entirely into each denom at the current exchange rate
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  share_value_total: [Coin, Coin];
+  /**
+   * The shares minted for the address's deposits, minus those it has withdrawn since
+   */
+  shares: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryBatchSimulateRequest".
+ */
+export interface PoolFactoryBatchSimulateRequest {
+  offer: Coin;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  pair: [string, string];
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
@@ -470,6 +1949,14 @@ export interface PoolFactoryConfigJsonable {
    * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
    */
   fee_receiver: Addr;
+  /**
+   * The LP incentives contract reward programs are registered with
+   */
+  lp_incentives?: Addr | null;
+  /**
+   * The highest referral fee (in bps) swaps on the pairs can pay
+   */
+  max_referral_fee_bps?: number;
   /**
    * Code to use when instantiating new pool pairs
    */
@@ -478,6 +1965,14 @@ export interface PoolFactoryConfigJsonable {
    * If true, everyone will be able to create new trading pairs
    */
   permissionless_pool_cration: boolean;
+  /**
+   * If true, pools can't be created for pairs which share the same underlying assets as an existing pool
+   */
+  reject_duplicate_underlying_pairs?: boolean;
+  /**
+   * How long (in seconds) sensitive changes must be queued for before they can be applied
+   */
+  timelock_seconds?: number;
 }
 /**
  * This structure stores the basic settings for creating a new factory contract.
@@ -487,46 +1982,33 @@ export interface PoolFactoryConfigJsonable {
  */
 export interface PoolFactoryInstantiateMsg {
   config: PoolFactoryConfigJsonable;
-}
-/**
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolPairCalcNaiveSwapResult".
- */
-export interface PoolPairCalcNaiveSwapResult {
-  /**
-   * How much CrownFi is skimming off the top
-   */
-  maker_fee_amount: Uint128;
-  /**
-   * The amount of coin after the swap, minus the `total_fee_amount`.
-   */
-  result_amount: Uint128;
   /**
-   * `maker_fee_amount + liquidity_provider_fee_amount`
+   * Granted the `guardian` role, which is able to pause pools in case of an emergency.
    */
-  total_fee_amount: Uint128;
+  guardian?: Addr | null;
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "PoolPairCalcSwapResult".
+ * via the `definition` "PoolFactoryPairInfo".
  */
-export interface PoolPairCalcSwapResult {
-  /**
-   * How much CrownFi is skimming off the top
-   */
-  maker_fee_amount: Uint128;
-  /**
-   * The amount of coin after the swap, minus the `total_fee_amount`.
-   */
-  result_amount: Uint128;
+export interface PoolFactoryPairInfo {
+  address: Addr;
   /**
-   * The discrepancy between `result_amount + total_fee_amount` and `incoming_amount * exchange_rate`
+   * @minItems 2
+   * @maxItems 2
    */
-  spread_amount: Uint128;
+  canonical_pair: [string, string];
+  config: PoolPairConfigJsonable;
+  deprecated: boolean;
+  endorsed: boolean;
   /**
-   * `maker_fee_amount + liquidity_provider_fee_amount`
+   * The pool's balances, in the same order as `canonical_pair`
+   *
+   * @minItems 2
+   * @maxItems 2
    */
-  total_fee_amount: Uint128;
+  reserves: [Coin, Coin];
+  total_shares: Uint128;
 }
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
@@ -537,10 +2019,18 @@ export interface PoolPairConfigJsonable {
    * The head honcho, this is usually the factory contract
    */
   admin: Addr;
+  /**
+   * If true, liquidity cannot be provided. Withdrawals are always allowed.
+   */
+  deposits_paused?: boolean;
   /**
    * If true, this has been endorsed by the market maker (probably CrownFi)
    */
   endorsed: boolean;
+  /**
+   * If true, swap fees are taken from the offered amount rather than the result, and are paid in the offered denom.
+   */
+  fee_on_input?: boolean;
   /**
    * Where to put the maker fees, set to "sei1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq703fpu" to disable.
    */
@@ -549,15 +2039,135 @@ export interface PoolPairConfigJsonable {
    * If true, this is marketed as the inverse pair
    */
   inverse: boolean;
+  /**
+   * If true, long-term orders can be placed. Existing orders are always settled.
+   */
+  long_term_orders?: boolean;
   /**
    * The amount of fees (in bps) collected by the Maker contract from this pair type
    */
   maker_fee_bps: number;
+  /**
+   * The highest referral fee (in bps) swaps can pay, this is configured by the factory
+   */
+  max_referral_fee_bps?: number;
+  /**
+   * If true, the cost basis of each address's deposits and the fees earned by LPs are recorded.
+   */
+  position_tracking?: boolean;
+  /**
+   * If true, swaps are disabled
+   */
+  swaps_paused?: boolean;
   /**
    * The total fees (in bps) charged by a pair of this type
    */
   total_fee_bps: number;
 }
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryPoolCreationRulesJsonable".
+ */
+export interface PoolFactoryPoolCreationRulesJsonable {
+  /**
+   * Sent to the `fee_receiver` when creating a pool
+   */
+  creation_fee?: Coin | null;
+  denom_filter: PoolFactoryDenomFilter;
+  /**
+   * The minimum amount of each denom which must be provided when creating a pool
+   */
+  min_initial_liquidity: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryUserPosition".
+ */
+export interface PoolFactoryUserPosition {
+  address: Addr;
+  /**
+   * @minItems 2
+   * @maxItems 2
+   */
+  canonical_pair: [string, string];
+  /**
+   * The portion of the pool's total shares held
+   */
+  pool_share: Decimal;
+  /**
+   * The current value of the LP shares held, in the same order as `canonical_pair`
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  share_value: [Coin, Coin];
+  /**
+   * The amount of LP shares held
+   */
+  shares: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryUserPositions".
+ */
+export interface PoolFactoryUserPositions {
+  /**
+   * If there are more pools to scan, the pair to specify as `after` in the next query
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  next?: [string, string] | null;
+  positions: PoolFactoryUserPosition[];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolFactoryWrapperConfigJsonable".
+ */
+export interface PoolFactoryWrapperConfigJsonable {
+  cw20_wrapper?: Addr | null;
+  erc20_wrapper?: Addr | null;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairCalcNaiveSwapResult".
+ */
+export interface PoolPairCalcNaiveSwapResult {
+  /**
+   * If true, the fees are in the offered denom rather than the resulting denom.
+   */
+  fee_on_input: boolean;
+  /**
+   * How much CrownFi is skimming off the top
+   */
+  maker_fee_amount: Uint128;
+  /**
+   * How much of the result is paid to the referrer, this is always in the resulting denom.
+   */
+  referral_fee_amount: Uint128;
+  /**
+   * The amount of coin after the swap, minus the `referral_fee_amount` and the `total_fee_amount` if the fee is taken from the result.
+   */
+  result_amount: Uint128;
+  /**
+   * `maker_fee_amount + liquidity_provider_fee_amount`
+   */
+  total_fee_amount: Uint128;
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairSwapReferral".
+ */
+export interface PoolPairSwapReferral {
+  /**
+   * The account receiving the referral fee
+   */
+  address: Addr;
+  /**
+   * The referral fee (in bps) taken from the result, this can't exceed the pool's `max_referral_fee_bps`.
+   */
+  fee_bps: number;
+}
 /**
  * This structure stores the basic settings for creating a new factory contract.
  *
@@ -566,8 +2176,70 @@ export interface PoolPairConfigJsonable {
  */
 export interface PoolPairInstantiateMsg {
   config: PoolPairConfigJsonable;
+  /**
+   * The contract LP tokens are staked with when `auto_stake` is specified.
+   */
+  lp_staking?: Addr | null;
   shares_receiver: Addr;
 }
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairQueryLongTermOrdersResponse".
+ */
+export interface PoolPairQueryLongTermOrdersResponse {
+  /**
+   * What this contract holds on behalf of long-term orders, this is excluded from the reserves
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  escrow: [Coin, Coin];
+  /**
+   * Unix timestamp (in seconds) of when the orders were last settled
+   */
+  last_settlement: number;
+  /**
+   * The amount of each denom being sold per second
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  sell_rates: [Coin, Coin];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairQueryReservesResponse".
+ */
+export interface PoolPairQueryReservesResponse {
+  /**
+   * Any amount above `tracked`, and what's held on behalf of long-term orders, can be taken with `Skim` or added to the reserves with `Sync`
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  actual: [Coin, Coin];
+  /**
+   * What all swaps and share values are calculated from
+   *
+   * @minItems 2
+   * @maxItems 2
+   */
+  tracked: [Coin, Coin];
+}
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "PoolPairQueryShareSupplyResponse".
+ */
+export interface PoolPairQueryShareSupplyResponse {
+  /**
+   * What the bank module reports, this is what `TotalShares` returns when available
+   */
+  bank?: Uint128 | null;
+  /**
+   * What this contract has minted minus what it has burned, as of the last `ReconcileSupply`
+   */
+  tracked: Uint128;
+}
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "PoolPairQuerySimulateDepositResponse".
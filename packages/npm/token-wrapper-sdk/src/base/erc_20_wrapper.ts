@@ -4,14 +4,23 @@
  * DO NOT MODIFY IT BY HAND.
  * The Rust definition of the associated structs is the source of truth!!
  */
-import {Addr, Binary, ERC20WrapperExecMsg, Empty, EmptyQuery, Uint128} from "./types.js";
+import {Addr, Binary, ERC20WrapperExecMsg, ERC20WrapperQueryMsg, Nullable_String, String, Uint128} from "./types.js";
 import {Coin} from "@cosmjs/amino";
 import {ExecuteInstruction, WasmExtension} from "@cosmjs/cosmwasm-stargate";
 import {QueryClient} from "@cosmjs/stargate";
 import {ContractBase} from "@crownfi/sei-utils";
 export class Erc20WrapperContract<Q extends QueryClient & WasmExtension> extends ContractBase<Q> {
-	queryEmpty(): Promise<Empty> {
-		const msg = {"empty": {}} satisfies EmptyQuery;
+	/** Returns the "0x"-prefixed address of the ERC20 token the specified denom wraps, if it was created by this contract. */
+	queryUnwrappedAddrOf(args: {
+		"denom": string
+	}): Promise<Nullable_String> {
+		const msg = {"unwrapped_addr_of": args} satisfies ERC20WrapperQueryMsg;
+		return this.query(msg);
+	}
+	queryWrappedDenomOf(args: {
+		"token_addr": string
+	}): Promise<String> {
+		const msg = {"wrapped_denom_of": args} satisfies ERC20WrapperQueryMsg;
 		return this.query(msg);
 	}
 	buildWrapIx(args: {
@@ -92,11 +92,19 @@ export type ERC20WrapperExecMsg =
     };
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "EmptyQuery".
+ * via the `definition` "ERC20WrapperQueryMsg".
  */
-export type EmptyQuery = {
-  empty: {};
-};
+export type ERC20WrapperQueryMsg =
+  | {
+      unwrapped_addr_of: {
+        denom: string;
+      };
+    }
+  | {
+      wrapped_denom_of: {
+        token_addr: string;
+      };
+    };
 /**
  * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
  * via the `definition` "Nullable_Addr".
@@ -107,6 +115,11 @@ export type Nullable_Addr = Addr | null;
  * via the `definition` "Nullable_String".
  */
 export type Nullable_String = string | null;
+/**
+ * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
+ * via the `definition` "String".
+ */
+export type String = string;
 
 export interface CrownfiSdkMakerAutogen {}
 /**
@@ -120,12 +133,3 @@ export interface Cw20ReceiveMsg {
   msg: Binary;
   sender: string;
 }
-/**
- * An empty struct that serves as a placeholder in different places, such as contracts that don't set a custom message.
- *
- * It is designed to be expressable in correct JSON and JSON Schema but contains no meaningful data. Previously we used enums without cases, but those cannot represented as valid JSON Schema (https://github.com/CosmWasm/cosmwasm/issues/451)
- *
- * This interface was referenced by `CrownfiSdkMakerAutogen`'s JSON-Schema
- * via the `definition` "Empty".
- */
-export interface Empty {}